@@ -2,12 +2,12 @@ use std::{collections::HashMap, io::Write, time::Duration};
 
 use anyhow::Result;
 use autoprat::{
-    CheckConclusion, CheckInfo, CheckName, CheckRunStatus, CheckState, DisplayMode, PrState,
-    PullRequest,
+    CheckConclusion, CheckInfo, CheckName, CheckRunStatus, CheckState, DisplayMode, MergeState,
+    Mergeable, PrState, PullRequest, ReviewDecision, ReviewState, SignatureState,
 };
 #[cfg(test)]
-use autoprat::{CheckUrl, Repo};
-use chrono::{DateTime, SecondsFormat, Utc};
+use autoprat::{CheckUrl, LinkedIssue, Repo};
+use chrono::{DateTime, SecondsFormat, TimeDelta, Utc};
 
 const LABEL_APPROVED: &str = "approved";
 const LABEL_LGTM: &str = "lgtm";
@@ -190,11 +190,115 @@ fn format_ci_status(status: &CiStatus) -> String {
     }
 }
 
+/// Required and optional checks summarised separately, once the forge
+/// says which checks the base branch requires, so an optional failure
+/// is not mistaken for a blocked merge. `None` until then.
+fn format_ci_split(checks: &[CheckInfo]) -> Option<(String, String)> {
+    if !checks.iter().any(|check| check.required) {
+        return None;
+    }
+
+    let (required, optional): (Vec<CheckInfo>, Vec<CheckInfo>) =
+        checks.iter().cloned().partition(|check| check.required);
+    let optional = if optional.is_empty() {
+        "-".to_string()
+    } else {
+        format_ci_status(&get_ci_status(&optional))
+    };
+    Some((format_ci_status(&get_ci_status(&required)), optional))
+}
+
+/// The CI column: split into required and optional checks when they
+/// are known apart, otherwise every check together.
+fn format_ci_column(checks: &[CheckInfo]) -> String {
+    match format_ci_split(checks) {
+        Some((required, optional)) => format!("req {required}, opt {optional}"),
+        None => format_ci_status(&get_ci_status(checks)),
+    }
+}
+
+/// Short form of the review decision for the table's REVIEW column.
+fn format_review_decision(decision: Option<ReviewDecision>) -> &'static str {
+    match decision {
+        Some(ReviewDecision::Approved) => "approved",
+        Some(ReviewDecision::ChangesRequested) => "changes",
+        Some(ReviewDecision::ReviewRequired) => "required",
+        None => "-",
+    }
+}
+
+/// Mergeability for the MERGEABLE column: a conflict first, then the
+/// merge state when it says why GitHub would not merge yet.
+fn format_mergeability(pr: &PullRequest) -> &'static str {
+    if pr.is_conflicting() {
+        return "conflict";
+    }
+    match (pr.mergeable, pr.merge_state) {
+        (_, MergeState::Behind) => "behind",
+        (_, MergeState::Blocked) => "blocked",
+        (_, MergeState::Unstable) => "unstable",
+        (Mergeable::Mergeable, _) => "yes",
+        _ => "?",
+    }
+}
+
+/// Lines added, lines removed, and files touched, e.g. `+12 -3 2f`,
+/// or `?` when the forge did not report them.
+fn format_size(pr: &PullRequest) -> String {
+    match (pr.additions, pr.deletions, pr.changed_files) {
+        (Some(additions), Some(deletions), Some(files)) => {
+            format!("+{additions} -{deletions} {files}f")
+        }
+        _ => "?".to_string(),
+    }
+}
+
+/// Comma-separated logins, or `-` when there are none.
+fn format_users(users: &[String]) -> String {
+    if users.is_empty() {
+        "-".to_string()
+    } else {
+        users.join(",")
+    }
+}
+
+fn format_review_state(state: ReviewState) -> &'static str {
+    match state {
+        ReviewState::Approved => "approved",
+        ReviewState::ChangesRequested => "changes requested",
+        ReviewState::Commented => "commented",
+        ReviewState::Dismissed => "dismissed",
+        ReviewState::Pending => "pending",
+    }
+}
+
 fn format_relative_time(time: DateTime<Utc>) -> String {
     use chrono_humanize::HumanTime;
     HumanTime::from(time).to_string()
 }
 
+/// Compact check duration, e.g. `42s`, `4m20s` or `3h12m`.
+fn format_duration(duration: TimeDelta) -> String {
+    let seconds = duration.num_seconds().max(0);
+    match (seconds / 3600, seconds % 3600 / 60, seconds % 60) {
+        (0, 0, s) => format!("{s}s"),
+        (0, m, s) => format!("{m}m{s}s"),
+        (h, m, _) => format!("{h}h{m}m"),
+    }
+}
+
+/// How long a check took, or has been going for one still pending.
+/// Empty when the forge gave no timings.
+fn format_check_duration(check: &CheckInfo, now: DateTime<Utc>) -> String {
+    match check.duration(now) {
+        Some(duration) if check.completed_at.is_some() => {
+            format!(" ({})", format_duration(duration))
+        }
+        Some(duration) => format!(" ({} so far)", format_duration(duration)),
+        None => String::new(),
+    }
+}
+
 fn format_error_logs<W: Write>(
     error_lines: &[String],
     log_prefix: &str,
@@ -241,11 +345,14 @@ fn display_prs_quiet<W: Write>(prs: &[PullRequest], writer: &mut W) -> Result<()
 /// Render the normal-mode columns as tab-separated values for
 /// machine consumption: no header, no separator, no truncation,
 /// boolean labels as 0/1, and timestamps in RFC3339 instead of the
-/// humanised "2 hours ago" form. Column order matches the
-/// human-facing table.
+/// humanised "2 hours ago" form. The columns the table had first keep
+/// its order; those added since follow the title, so existing scripts
+/// can go on reading fields by position.
 fn display_prs_tsv<W: Write>(prs: &[PullRequest], writer: &mut W) -> Result<()> {
     for pr in prs {
         let ci_str = format_ci_status(&get_ci_status(&pr.checks));
+        let (ci_required, ci_optional) =
+            format_ci_split(&pr.checks).unwrap_or_else(|| ("-".to_string(), "-".to_string()));
         let approved = if pr.has_label(LABEL_APPROVED) {
             "1"
         } else {
@@ -258,12 +365,14 @@ fn display_prs_tsv<W: Write>(prs: &[PullRequest], writer: &mut W) -> Result<()>
             "0"
         };
         let hold = if pr.has_label(LABEL_HOLD) { "1" } else { "0" };
+        let review = pr.review_decision.map_or("none", ReviewDecision::as_str);
+        let mergeable = format_mergeability(pr);
         let draft = if pr.is_draft { "1" } else { "0" };
         let created = pr.created_at.to_rfc3339_opts(SecondsFormat::Secs, true);
 
         writeln!(
             writer,
-            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
             pr.url,
             pr.base_branch,
             ci_str,
@@ -276,6 +385,13 @@ fn display_prs_tsv<W: Write>(prs: &[PullRequest], writer: &mut W) -> Result<()>
             pr.author_simple_name,
             created,
             pr.title,
+            review,
+            mergeable,
+            format_size(pr),
+            format_users(&pr.assignees),
+            format_users(&pr.requested_reviewers),
+            ci_required,
+            ci_optional,
         )?;
     }
     Ok(())
@@ -297,9 +413,14 @@ const TABLE_HEADERS: &[&str] = &[
     "LGTM",
     "OK2TST",
     "HOLD",
+    "REVIEW",
+    "MERGEABLE",
     "DRAFT",
     "COMMITS",
+    "SIZE",
     "AUTHOR",
+    "ASSIGNEES",
+    "REQUESTED",
     "CREATED AT",
     "TITLE",
 ];
@@ -361,8 +482,7 @@ fn get_terminal_width(width_override: Option<usize>, force_truncate: bool) -> us
 }
 
 fn pr_to_table_row(pr: &PullRequest) -> Vec<String> {
-    let ci_status = get_ci_status(&pr.checks);
-    let ci_str = format_ci_status(&ci_status);
+    let ci_str = format_ci_column(&pr.checks);
 
     let approved = if pr.has_label(LABEL_APPROVED) {
         "✓"
@@ -390,9 +510,14 @@ fn pr_to_table_row(pr: &PullRequest) -> Vec<String> {
         lgtm.to_string(),
         ok2test.to_string(),
         hold.to_string(),
+        format_review_decision(pr.review_decision).to_string(),
+        format_mergeability(pr).to_string(),
         draft.to_string(),
         pr.commit_count.to_string(),
+        format_size(pr),
         pr.author_simple_name.clone(),
+        format_users(&pr.assignees),
+        format_users(&pr.requested_reviewers),
         format_relative_time(pr.created_at),
         pr.title.clone(),
     ]
@@ -543,6 +668,8 @@ impl<'a> PrDetailFormatter<'a> {
         self.write_metadata(writer)?;
         self.write_status_section(writer)?;
         self.write_labels_section(writer)?;
+        self.write_issues_section(writer)?;
+        self.write_commits_section(writer)?;
         self.write_checks_section(writer)?;
         Ok(())
     }
@@ -571,11 +698,47 @@ impl<'a> PrDetailFormatter<'a> {
             if pr.is_draft { "Yes" } else { "No" }
         )?;
         writeln!(writer, "├─Branch: {}", pr.base_branch)?;
+        writeln!(
+            writer,
+            "├─Head: {}{}{}{}",
+            pr.head_repo_owner
+                .as_deref()
+                .map(|owner| format!("{owner}:"))
+                .unwrap_or_default(),
+            pr.head_branch,
+            if pr.is_cross_repository {
+                " (fork)"
+            } else {
+                ""
+            },
+            pr.head_sha
+                .get(..7)
+                .map(|sha| format!(" @ {sha}"))
+                .unwrap_or_default(),
+        )?;
+        if !pr.assignees.is_empty() {
+            writeln!(writer, "├─Assignees: {}", pr.assignees.join(", "))?;
+        }
         writeln!(
             writer,
             "├─Created: {}",
             pr.created_at.format("%Y-%m-%dT%H:%M:%SZ")
         )?;
+        writeln!(
+            writer,
+            "├─Updated: {}",
+            pr.updated_at.format("%Y-%m-%dT%H:%M:%SZ")
+        )?;
+        if let Some(pushed) = pr.last_pushed_at {
+            writeln!(writer, "├─Pushed: {}", pushed.format("%Y-%m-%dT%H:%M:%SZ"))?;
+        }
+        if let Some(commented) = pr.last_commented_at() {
+            writeln!(
+                writer,
+                "├─Commented: {}",
+                commented.format("%Y-%m-%dT%H:%M:%SZ")
+            )?;
+        }
         Ok(())
     }
 
@@ -593,6 +756,44 @@ impl<'a> PrDetailFormatter<'a> {
             }
         )?;
 
+        let reviews: Vec<String> = pr
+            .reviews
+            .iter()
+            .map(|review| format!("{} {}", review.reviewer, format_review_state(review.state)))
+            .collect();
+        writeln!(
+            writer,
+            "│ ├─Review: {}{}",
+            match pr.review_decision {
+                Some(ReviewDecision::Approved) => "Approved",
+                Some(ReviewDecision::ChangesRequested) => "Changes requested",
+                Some(ReviewDecision::ReviewRequired) => "Review required",
+                None => "None",
+            },
+            if reviews.is_empty() {
+                String::new()
+            } else {
+                format!(" ({})", reviews.join(", "))
+            }
+        )?;
+        if !pr.requested_reviewers.is_empty() {
+            writeln!(
+                writer,
+                "│ ├─Review requested: {}",
+                pr.requested_reviewers.join(", ")
+            )?;
+        }
+
+        writeln!(
+            writer,
+            "│ ├─Mergeable: {}",
+            match pr.mergeable {
+                Mergeable::Mergeable => "Yes",
+                Mergeable::Conflicting => "No, conflicts with base",
+                Mergeable::Unknown => "Unknown",
+            }
+        )?;
+
         let ci_status = get_ci_status(&self.pr_info.checks);
         writeln!(
             writer,
@@ -648,6 +849,71 @@ impl<'a> PrDetailFormatter<'a> {
         Ok(())
     }
 
+    /// Issues the PR closes, then the issue keys it names.
+    fn write_issues_section<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writeln!(writer, "├─Issues")?;
+
+        let pr = &self.pr_info;
+        let lines: Vec<String> = pr
+            .linked_issues
+            .iter()
+            .flatten()
+            .map(|issue| format!("{} ({})", issue.url, issue.title))
+            .chain(pr.issue_keys.iter().cloned())
+            .collect();
+        if lines.is_empty() {
+            writeln!(writer, "│ └─None")?;
+        }
+        for (i, line) in lines.iter().enumerate() {
+            let prefix = if i == lines.len() - 1 {
+                "│ └─"
+            } else {
+                "│ ├─"
+            };
+            writeln!(writer, "{prefix}{line}")?;
+        }
+
+        Ok(())
+    }
+
+    /// Only shown when a filter had the commits fetched.
+    fn write_commits_section<W: Write>(&self, writer: &mut W) -> Result<()> {
+        let Some(commits) = &self.pr_info.commits else {
+            return Ok(());
+        };
+        writeln!(writer, "├─Commits")?;
+
+        if commits.is_empty() {
+            writeln!(writer, "│ └─None")?;
+        }
+        for (i, commit) in commits.iter().enumerate() {
+            let prefix = if i == commits.len() - 1 {
+                "│ └─"
+            } else {
+                "│ ├─"
+            };
+            writeln!(
+                writer,
+                "{prefix}{} {} ({}, {}{})",
+                commit.sha.get(..7).unwrap_or(&commit.sha),
+                commit.headline,
+                commit.author.as_deref().unwrap_or("unknown"),
+                match commit.signature {
+                    SignatureState::Verified => "verified",
+                    SignatureState::Unverified => "unverified",
+                    SignatureState::Unsigned => "unsigned",
+                },
+                if commit.is_signed_off() {
+                    ", signed off"
+                } else {
+                    ""
+                },
+            )?;
+        }
+
+        Ok(())
+    }
+
     fn write_checks_section<W: Write>(&self, writer: &mut W) -> Result<()> {
         writeln!(writer, "└─Checks")?;
 
@@ -750,7 +1016,13 @@ fn display_individual_check<W: Write>(
 ) -> Result<()> {
     let (check_prefix, url_prefix, log_prefix) = get_tree_prefixes(is_last_group, is_last_check);
 
-    writeln!(writer, "{}{}", check_prefix, check.name)?;
+    writeln!(
+        writer,
+        "{}{}{}",
+        check_prefix,
+        check.name,
+        format_check_duration(check, Utc::now())
+    )?;
 
     if let Some(url) = &check.url {
         writeln!(writer, "{url_prefix}URL: {url}")?;
@@ -901,8 +1173,17 @@ mod tests {
             url: "https://github.com/owner/repo/pull/101".to_string(),
             labels: vec!["enhancement".to_string(), "approved".to_string()],
             created_at: base_time - chrono::Duration::hours(5),
+            updated_at: base_time - chrono::Duration::hours(5),
+            last_pushed_at: None,
             base_branch: "main".to_string(),
             commit_count: 1,
+            additions: None,
+            deletions: None,
+            changed_files: None,
+            changed_paths: None,
+            commits: None,
+            linked_issues: None,
+            issue_keys: vec![],
             is_draft: false,
             state: PrState::Open,
             checks: vec![
@@ -912,6 +1193,9 @@ mod tests {
                     run_status: Some(CheckRunStatus::Completed),
                     status_state: None,
                     url: CheckUrl::new("https://github.com/checks/1").ok(),
+                    required: false,
+                    started_at: None,
+                    completed_at: None,
                 },
                 CheckInfo {
                     name: CheckName::new("integration-tests").unwrap(),
@@ -919,9 +1203,23 @@ mod tests {
                     run_status: Some(CheckRunStatus::Completed),
                     status_state: None,
                     url: CheckUrl::new("https://github.com/checks/2").ok(),
+                    required: false,
+                    started_at: None,
+                    completed_at: None,
                 },
             ],
             recent_comments: vec![],
+            comment_history_complete: true,
+            review_decision: None,
+            reviews: vec![],
+            requested_reviewers: vec![],
+            assignees: vec![],
+            mergeable: Mergeable::Unknown,
+            merge_state: MergeState::Unknown,
+            head_branch: "feature".to_string(),
+            head_repo_owner: None,
+            head_sha: String::new(),
+            is_cross_repository: false,
         }]
     }
 
@@ -1010,6 +1308,71 @@ mod tests {
         assert!(result.contains("integration-tests"));
     }
 
+    #[tokio::test]
+    async fn test_display_shows_check_durations() {
+        let mut prs = create_test_pr_data();
+        let started = Utc.with_ymd_and_hms(2024, 1, 15, 9, 0, 0).unwrap();
+        prs[0].checks[1].started_at = Some(started);
+        prs[0].checks[1].completed_at = Some(started + TimeDelta::seconds(260));
+        prs[0].checks.push(CheckInfo {
+            name: CheckName::new("e2e").unwrap(),
+            conclusion: None,
+            run_status: Some(CheckRunStatus::InProgress),
+            status_state: None,
+            url: None,
+            required: false,
+            started_at: Some(Utc::now() - TimeDelta::minutes(190)),
+            completed_at: None,
+        });
+
+        let mut output = Vec::new();
+        display_pr_table(
+            &prs,
+            &create_display_mode(false, true, false),
+            false,
+            true,
+            &mut output,
+        )
+        .await
+        .unwrap();
+        let result = String::from_utf8(output).unwrap();
+
+        assert!(result.contains("integration-tests (4m20s)"));
+        assert!(result.contains("e2e (3h10m so far)"));
+        assert!(result.lines().any(|line| line.ends_with("unit-tests")));
+        assert_eq!(format_duration(TimeDelta::seconds(42)), "42s");
+    }
+
+    #[tokio::test]
+    async fn test_display_lists_linked_issues_and_keys() {
+        let mut prs = create_test_pr_data();
+        let verbose = async |prs: &[PullRequest]| {
+            let mut output = Vec::new();
+            display_pr_table(
+                prs,
+                &create_display_mode(false, true, false),
+                false,
+                true,
+                &mut output,
+            )
+            .await
+            .unwrap();
+            String::from_utf8(output).unwrap()
+        };
+
+        assert!(verbose(&prs).await.contains("├─Issues\n│ └─None\n"));
+
+        prs[0].linked_issues = Some(vec![LinkedIssue {
+            number: 7,
+            title: "Crash on start".to_string(),
+            url: "https://github.com/owner/repo/issues/7".to_string(),
+        }]);
+        prs[0].issue_keys = vec!["OCPBUGS-12345".to_string()];
+        assert!(verbose(&prs).await.contains(
+            "├─Issues\n│ ├─https://github.com/owner/repo/issues/7 (Crash on start)\n│ └─OCPBUGS-12345\n"
+        ));
+    }
+
     #[tokio::test]
     async fn test_display_verbose_with_logs_mode() {
         let prs = create_test_pr_data();
@@ -1059,7 +1422,14 @@ mod tests {
                 "1\t",
                 "alice\t",
                 "2024-01-15T05:00:00Z\t",
-                "Add authentication system\n",
+                "Add authentication system\t",
+                "none\t",
+                "?\t",
+                "?\t",
+                "-\t",
+                "-\t",
+                "-\t",
+                "-\n",
             )
         );
     }
@@ -1102,6 +1472,229 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_display_reports_size() {
+        let mut prs = create_test_pr_data();
+        prs[0].additions = Some(120);
+        prs[0].deletions = Some(7);
+        prs[0].changed_files = Some(4);
+
+        let mut tsv = Vec::new();
+        display_pr_table(
+            &prs,
+            &create_display_mode(false, false, false),
+            false,
+            false,
+            &mut tsv,
+        )
+        .await
+        .unwrap();
+        let tsv = String::from_utf8(tsv).unwrap();
+        assert_eq!(tsv.split('\t').nth(14), Some("+120 -7 4f"));
+    }
+
+    #[tokio::test]
+    async fn test_display_splits_required_and_optional_checks() {
+        let mut prs = create_test_pr_data();
+        let render = async |prs: &[PullRequest], tty: bool| {
+            let mut output = Vec::new();
+            display_pr_table(
+                prs,
+                &create_display_mode(false, false, false),
+                false,
+                tty,
+                &mut output,
+            )
+            .await
+            .unwrap();
+            String::from_utf8(output).unwrap()
+        };
+        // The CI field keeps summarising every check; the split is
+        // appended as separate required and optional fields.
+        let tsv_ci = async |prs: &[PullRequest]| {
+            let tsv = render(prs, false).await;
+            let columns: Vec<&str> = tsv.trim_end().split('\t').collect();
+            [columns[2], columns[17], columns[18]].map(str::to_string)
+        };
+
+        assert_eq!(tsv_ci(&prs).await, ["Failed: 1/2", "-", "-"]);
+
+        prs[0].checks[0].required = true;
+        assert_eq!(tsv_ci(&prs).await, ["Failed: 1/2", "Success", "Failed (1)"]);
+        assert!(
+            render(&prs, true)
+                .await
+                .contains("req Success, opt Failed (1)")
+        );
+
+        prs[0].checks[1].required = true;
+        assert_eq!(tsv_ci(&prs).await, ["Failed: 1/2", "Failed: 1/2", "-"]);
+    }
+
+    #[tokio::test]
+    async fn test_display_reports_assignees_and_requested_reviewers() {
+        let mut prs = create_test_pr_data();
+        prs[0].assignees = vec!["carol".to_string(), "dave".to_string()];
+        prs[0].requested_reviewers = vec!["openshift/network-edge".to_string()];
+
+        let mut verbose = Vec::new();
+        display_pr_table(
+            &prs,
+            &create_display_mode(false, true, false),
+            false,
+            true,
+            &mut verbose,
+        )
+        .await
+        .unwrap();
+        let verbose = String::from_utf8(verbose).unwrap();
+        assert!(verbose.contains("├─Assignees: carol, dave"));
+        assert!(verbose.contains("│ ├─Review requested: openshift/network-edge"));
+
+        let mut tsv = Vec::new();
+        display_pr_table(
+            &prs,
+            &create_display_mode(false, false, false),
+            false,
+            false,
+            &mut tsv,
+        )
+        .await
+        .unwrap();
+        let tsv = String::from_utf8(tsv).unwrap();
+        let columns: Vec<&str> = tsv.trim_end().split('\t').collect();
+        assert_eq!(columns[15], "carol,dave");
+        assert_eq!(columns[16], "openshift/network-edge");
+    }
+
+    #[tokio::test]
+    async fn test_display_lists_fetched_commits() {
+        let mut prs = create_test_pr_data();
+        let render = async |prs: &[PullRequest]| {
+            let mut output = Vec::new();
+            display_pr_table(
+                prs,
+                &create_display_mode(false, true, false),
+                false,
+                true,
+                &mut output,
+            )
+            .await
+            .unwrap();
+            String::from_utf8(output).unwrap()
+        };
+
+        assert!(!render(&prs).await.contains("├─Commits"));
+
+        prs[0].commits = Some(vec![autoprat::CommitInfo {
+            sha: "0123456789abcdef".to_string(),
+            headline: "Add authentication".to_string(),
+            message: "Add authentication\n\nSigned-off-by: Alice <alice@example.com>".to_string(),
+            author: Some("alice".to_string()),
+            committer: Some("web-flow".to_string()),
+            signature: SignatureState::Unsigned,
+        }]);
+        let verbose = render(&prs).await;
+        assert!(verbose.contains("├─Commits"));
+        assert!(verbose.contains("│ └─0123456 Add authentication (alice, unsigned, signed off)"));
+    }
+
+    #[tokio::test]
+    async fn test_display_reports_review_decision() {
+        let mut prs = create_test_pr_data();
+        prs[0].review_decision = Some(ReviewDecision::ChangesRequested);
+        prs[0].reviews = vec![autoprat::ReviewInfo {
+            reviewer: "bob".to_string(),
+            state: ReviewState::ChangesRequested,
+        }];
+
+        let render = |mode: DisplayMode, tty: bool| {
+            let prs = prs.clone();
+            async move {
+                let mut output = Vec::new();
+                display_pr_table(&prs, &mode, false, tty, &mut output)
+                    .await
+                    .unwrap();
+                String::from_utf8(output).unwrap()
+            }
+        };
+
+        let table = render(create_display_mode(false, false, false), true).await;
+        assert!(table.contains("REVIEW"));
+        assert!(table.contains("changes"));
+
+        let verbose = render(create_display_mode(false, true, false), true).await;
+        assert!(verbose.contains("├─Review: Changes requested (bob changes requested)"));
+
+        let tsv = render(create_display_mode(false, false, false), false).await;
+        assert_eq!(tsv.split('\t').nth(12), Some("changes_requested"));
+    }
+
+    #[tokio::test]
+    async fn test_display_reports_mergeability() {
+        let mut prs = create_test_pr_data();
+        prs[0].mergeable = Mergeable::Mergeable;
+        prs[0].merge_state = MergeState::Behind;
+        prs.push(prs[0].clone());
+        prs[1].mergeable = Mergeable::Conflicting;
+        prs[1].merge_state = MergeState::Dirty;
+
+        let mut tsv = Vec::new();
+        display_pr_table(
+            &prs,
+            &create_display_mode(false, false, false),
+            false,
+            false,
+            &mut tsv,
+        )
+        .await
+        .unwrap();
+        let tsv = String::from_utf8(tsv).unwrap();
+        let column: Vec<_> = tsv
+            .lines()
+            .map(|line| line.split('\t').nth(13).unwrap())
+            .collect();
+        assert_eq!(column, vec!["behind", "conflict"]);
+
+        let mut verbose = Vec::new();
+        display_pr_table(
+            &prs,
+            &create_display_mode(false, true, false),
+            false,
+            true,
+            &mut verbose,
+        )
+        .await
+        .unwrap();
+        let verbose = String::from_utf8(verbose).unwrap();
+        assert!(verbose.contains("├─Mergeable: No, conflicts with base"));
+    }
+
+    #[tokio::test]
+    async fn test_verbose_shows_head_branch_and_fork() {
+        let mut prs = create_test_pr_data();
+        prs[0].head_branch = "fix-auth".to_string();
+        prs[0].head_repo_owner = Some("alice".to_string());
+        prs[0].head_sha = "0123456789abcdef".to_string();
+        prs[0].is_cross_repository = true;
+
+        let mut output = Vec::new();
+        display_pr_table(
+            &prs,
+            &create_display_mode(false, true, false),
+            false,
+            true,
+            &mut output,
+        )
+        .await
+        .unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(
+            output.contains("├─Head: alice:fix-auth (fork) @ 0123456"),
+            "{output}"
+        );
+    }
+
     #[tokio::test]
     async fn test_verbose_state_reflects_pull_request_state() {
         for (state, expected) in [
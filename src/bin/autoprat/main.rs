@@ -3,10 +3,17 @@ mod log_fetcher;
 
 use std::io::IsTerminal;
 
+use anyhow::Context;
 use autoprat::{
-    GhCliRenderer, GitHub, fetch_pull_requests, parse_args, shell::write_shell_commands,
+    ForgeRegistry, ForgeSettings, GhCliRenderer, GitHub, GitLab, GlabCliRenderer,
+    fetch_pull_requests,
+    github::GITHUB_COM,
+    parse_args,
+    render::RendererRegistry,
+    shell::{write_incomplete_warning, write_shell_commands},
 };
 use display::display_pr_table;
+use url::Url;
 
 /// Decide whether to format output for a human-readable terminal.
 ///
@@ -28,6 +35,92 @@ fn should_use_tty_output() -> bool {
     std::io::stdout().is_terminal()
 }
 
+fn with_settings(github: GitHub, settings: &ForgeSettings) -> GitHub {
+    let github = match settings.fetch_concurrency {
+        Some(concurrency) => github.with_concurrency(concurrency),
+        None => github,
+    };
+    if settings.no_cache {
+        github.without_cache()
+    } else {
+        github
+    }
+}
+
+/// Build the forges PR URLs can be routed to, and the renderers that
+/// turn actions on each forge's PRs into commands for its CLI.
+///
+/// The default GitHub host serves `--repo` and `--query` searches: the
+/// `--hostname` flag, else GH_HOST (as gh does), else github.com.
+/// github.com and gitlab.com are always available for PR URLs, and a
+/// self-hosted GitLab can be added by setting GITLAB_HOST to its host
+/// name or base URL (as glab does). `--record` and `--replay` apply to
+/// GitHub only.
+fn forge_registry(settings: &ForgeSettings) -> anyhow::Result<(ForgeRegistry, RendererRegistry)> {
+    let github_host = settings
+        .github_host
+        .clone()
+        .or_else(|| {
+            std::env::var("GH_HOST")
+                .ok()
+                .map(|host| host.trim().to_ascii_lowercase())
+                .filter(|host| !host.is_empty())
+        })
+        .unwrap_or_else(|| GITHUB_COM.to_string());
+
+    // A replay answers from the recording alone: only GitHub was
+    // recorded, and nothing may reach the network.
+    if let Some(dir) = &settings.replay_dir {
+        let mut registry = ForgeRegistry::new(
+            &github_host,
+            with_settings(GitHub::replay_from(dir), settings),
+        );
+        if github_host != GITHUB_COM {
+            registry = registry.with_forge(GITHUB_COM, GitHub::replay_from(dir));
+        }
+        return Ok((registry, RendererRegistry::new(GhCliRenderer)));
+    }
+
+    // Only the default forge records, so a run writes one numbered
+    // sequence of files into the directory.
+    let mut default_github = with_settings(GitHub::with_host(&github_host), settings);
+    if let Some(dir) = &settings.record_dir {
+        default_github = default_github.record_to(dir);
+    }
+
+    let mut registry = ForgeRegistry::new(&github_host, default_github);
+    if github_host != GITHUB_COM {
+        registry = registry.with_forge(GITHUB_COM, with_settings(GitHub::new(), settings));
+    }
+    registry = registry.with_forge(
+        "gitlab.com",
+        GitLab::from_env(Url::parse("https://gitlab.com").expect("valid URL")),
+    );
+    let mut renderers =
+        RendererRegistry::new(GhCliRenderer).with_renderer("gitlab.com", GlabCliRenderer);
+
+    if let Ok(gitlab_host) = std::env::var("GITLAB_HOST")
+        && !gitlab_host.trim().is_empty()
+    {
+        let gitlab_host = gitlab_host.trim();
+        let base_url = if gitlab_host.contains("://") {
+            Url::parse(gitlab_host)
+        } else {
+            Url::parse(&format!("https://{gitlab_host}"))
+        }
+        .with_context(|| format!("Invalid GITLAB_HOST '{gitlab_host}'"))?;
+        let host = match (base_url.host_str(), base_url.port()) {
+            (Some(host), Some(port)) => format!("{host}:{port}"),
+            (Some(host), None) => host.to_string(),
+            (None, _) => anyhow::bail!("Invalid GITLAB_HOST '{gitlab_host}': missing host"),
+        };
+        registry = registry.with_forge(&host, GitLab::from_env(base_url));
+        renderers = renderers.with_renderer(&host, GlabCliRenderer);
+    }
+
+    Ok((registry, renderers))
+}
+
 fn handle_clap_help_version(clap_err: &clap::Error) -> ! {
     use clap::error::ErrorKind;
     match clap_err.kind() {
@@ -75,12 +168,19 @@ async fn run() -> anyhow::Result<()> {
         }
     };
 
-    let result = fetch_pull_requests(&request.query, &GitHub).await?;
-    let renderer = GhCliRenderer;
+    let (forges, renderers) = forge_registry(&request.forge)?;
+    let result = fetch_pull_requests(&request.query, &forges).await?;
+    for missing in &result.missing_prs {
+        eprintln!("Warning: {missing} was not found or is not accessible");
+    }
+    for reason in &result.incomplete {
+        eprintln!("Warning: PR list is incomplete: {reason}");
+    }
     let mut stdout = std::io::stdout();
 
     if request.query.action_policy.has_actions() {
-        write_shell_commands(&renderer, &result.executable_actions, &mut stdout)?;
+        write_incomplete_warning(&result.incomplete, &mut stdout)?;
+        write_shell_commands(&renderers, &result.executable_actions, &mut stdout)?;
     } else {
         display_pr_table(
             &result.filtered_prs,
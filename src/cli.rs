@@ -1,17 +1,24 @@
-use std::time::Duration;
+use std::{path::PathBuf, time::Duration};
 
 use anyhow::{Context, Result};
+use chrono::{DateTime, TimeDelta, Utc};
 use clap::{Args, Parser};
 
 use crate::{
     filters::{
-        AuthorPost, BaseBranchPost, CommitExpr, CommitsPost, FailingCheckPost, FailingCiPost,
-        TitlePost,
+        AuthorPost, BaseBranchPost, CommentedByPost, CommitExpr, CommitMessagePost, CommitsPost,
+        ConflictingPost, FailingCheckPost, FailingCiPost, FailingRequiredCiPost, FromForkPost,
+        HeadBranchPost, IdleSincePushPost, IssueKeyPost, LastCommentByPost, LinkedIssuePost,
+        MergeablePost, MissingSignoffPost, NoLinkedIssuePost, NotTouchesPost, ReviewDecisionPost,
+        ReviewedByPost, SameRepoPost, SizeExpr, SizePost, StuckCheckPost, TitlePost, TouchesPost,
+        UnsignedCommitsPost,
     },
     pr_selector::{PrIdentifier, parse_pr_identifiers},
+    search::OrgSweep,
     types::{
-        ActionPolicy, AppRequest, CommentAction, DisplayMode, DisplaySettings, FetchCriteria,
-        PostFilter, PrAction, QuerySpec, Repo, SearchCriterion, SelectionPolicy,
+        ActionPolicy, AppRequest, CommentAction, CommentScope, DisplayMode, DisplaySettings,
+        FetchCriteria, ForgeSettings, PostFilter, PrAction, QuerySpec, Repo, ReviewDecision,
+        SearchCriterion, SelectionPolicy,
     },
 };
 
@@ -20,6 +27,8 @@ const BUILD_INFO_HUMAN: &str = env!("BUILD_INFO_HUMAN");
 const THROTTLE_FORMAT_HELP: &str =
     "a duration such as `30s`, `5m`, or `2h`; a bare number is read as minutes";
 
+const AGE_FORMAT_HELP: &str = "an age such as `12h`, `14d`, or `2w`; a bare number is read as days";
+
 const DEFAULT_HISTORY_MAX_AGE: Duration = Duration::from_secs(60 * 60);
 const DEFAULT_HISTORY_MAX_COMMENTS: usize = 10;
 
@@ -47,7 +56,8 @@ struct ActionArgs {
 
     /// Emit a `gh pr merge` command for each selected PR.
     ///
-    /// Draft PRs are skipped, since GitHub will not merge a draft.
+    /// Drafts and PRs that conflict with their base are skipped, since
+    /// GitHub will not merge them.
     #[arg(long, help_heading = "Actions")]
     pub merge: bool,
 
@@ -74,6 +84,14 @@ struct FilterArgs {
     #[arg(long = "failing-ci", help_heading = "Filters")]
     pub failing_ci: bool,
 
+    /// Keep only PRs where a check the base branch requires is failing.
+    ///
+    /// Failures of optional checks are ignored, so
+    /// `--failing-required-ci --retest` leaves flaky informational jobs
+    /// alone.
+    #[arg(long = "failing-required-ci", help_heading = "Filters")]
+    pub failing_required_ci: bool,
+
     /// Keep only PRs opened by this user (exact login match).
     #[arg(short = 'a', long, help_heading = "Filters", value_name = "USERNAME")]
     pub author: Option<String>,
@@ -103,6 +121,23 @@ struct FilterArgs {
     #[arg(long, help_heading = "Filters", value_name = "BRANCH")]
     pub base: Option<String>,
 
+    /// Keep only PRs whose head branch matches this regular expression.
+    #[arg(long, help_heading = "Filters", value_name = "REGEX")]
+    pub head: Option<String>,
+
+    /// Keep only PRs opened from a fork.
+    #[arg(
+        long = "from-fork",
+        help_heading = "Filters",
+        conflicts_with = "same_repo"
+    )]
+    pub from_fork: bool,
+
+    /// Keep only PRs opened from a branch in the repository itself,
+    /// such as those from Dependabot.
+    #[arg(long = "same-repo", help_heading = "Filters")]
+    pub same_repo: bool,
+
     /// Keep only PRs whose commit count matches this expression.
     ///
     /// A bare number matches exactly; prefix with `=`, `!=`, `>`, `>=`,
@@ -110,6 +145,217 @@ struct FilterArgs {
     /// `--commits '<=3'`.
     #[arg(long, help_heading = "Filters", value_name = "EXPR")]
     pub commits: Option<String>,
+
+    /// Keep only PRs whose diff size matches this expression.
+    ///
+    /// Name `lines` (added plus removed), `additions`, `deletions`, or
+    /// `files`, then compare as for `--commits`, e.g. `--size
+    /// 'lines<50'` or `--size 'files<=3'`. Repeatable; a PR must match
+    /// every expression.
+    #[arg(long, help_heading = "Filters", value_name = "EXPR")]
+    pub size: Vec<String>,
+
+    /// Keep only PRs changing a file that matches this glob.
+    ///
+    /// `*` and `?` match within a path segment and `**` across them,
+    /// e.g. `--touches 'api/**'`. Repeatable; a PR matches when any
+    /// changed file matches any glob. Fetching each PR's files costs
+    /// extra queries.
+    #[arg(long, help_heading = "Filters", value_name = "GLOB")]
+    pub touches: Vec<String>,
+
+    /// Keep only PRs changing no file that matches this glob.
+    ///
+    /// Same glob syntax as `--touches`, e.g. `--not-touches
+    /// 'vendor/**'`. Repeatable.
+    #[arg(long = "not-touches", help_heading = "Filters", value_name = "GLOB")]
+    pub not_touches: Vec<String>,
+
+    /// Keep only PRs with a commit whose signature is missing or could
+    /// not be verified.
+    ///
+    /// Fetching each PR's commits costs extra queries on GitHub.
+    #[arg(long = "unsigned-commits", help_heading = "Filters")]
+    pub unsigned_commits: bool,
+
+    /// Keep only PRs with a commit lacking a `Signed-off-by:` trailer.
+    #[arg(long = "missing-signoff", help_heading = "Filters")]
+    pub missing_signoff: bool,
+
+    /// Keep only PRs with a commit whose message matches this regular
+    /// expression.
+    ///
+    /// The whole message is searched, not just the first line.
+    #[arg(
+        long = "commit-message",
+        help_heading = "Filters",
+        value_name = "REGEX"
+    )]
+    pub commit_message: Option<String>,
+
+    /// Keep only PRs that reference an issue: one they close when
+    /// merged, or, given `--issue-key`, a key it matches in the title or
+    /// description.
+    ///
+    /// GitLab does not report which issues a merge request closes, so
+    /// there only keys count, and only with `--issue-key`.
+    #[arg(
+        long = "linked-issue",
+        help_heading = "Filters",
+        conflicts_with = "no_linked_issue"
+    )]
+    pub linked_issue: bool,
+
+    /// Keep only PRs that reference no issue; see `--linked-issue`.
+    ///
+    /// A GitLab merge request naming no matching key never matches,
+    /// since the issues it closes are unknown.
+    #[arg(long = "no-linked-issue", help_heading = "Filters")]
+    pub no_linked_issue: bool,
+
+    /// Keep only PRs naming an issue key that matches this regular
+    /// expression, e.g. `^OCPBUGS-`.
+    ///
+    /// With `--linked-issue` or `--no-linked-issue` it instead decides
+    /// which keys count as referencing an issue. Without it no key
+    /// counts, since `UTF-8` or `SHA-256` in a description looks like
+    /// one.
+    #[arg(long = "issue-key", help_heading = "Filters", value_name = "REGEX")]
+    pub issue_key: Option<String>,
+
+    /// Keep only PRs that conflict with their base branch.
+    #[arg(long, help_heading = "Filters", conflicts_with = "mergeable")]
+    pub conflicting: bool,
+
+    /// Keep only PRs that merge cleanly into their base branch.
+    ///
+    /// GitHub computes mergeability in the background after a push;
+    /// PRs it has not finished with are left out until a later run.
+    #[arg(long, help_heading = "Filters")]
+    pub mergeable: bool,
+
+    /// Keep only PRs whose GitHub review decision is this.
+    ///
+    /// One of `approved`, `changes_requested`, or `review_required`.
+    /// PRs in repositories that do not require reviews have no
+    /// decision and never match.
+    #[arg(
+        long = "review-decision",
+        help_heading = "Filters",
+        value_name = "DECISION"
+    )]
+    pub review_decision: Option<String>,
+
+    /// Keep only PRs this user has submitted a review on, of any kind.
+    ///
+    /// Repeatable; a PR matches only when every named user has
+    /// reviewed it.
+    #[arg(
+        long = "reviewed-by",
+        help_heading = "Filters",
+        value_name = "USERNAME"
+    )]
+    pub reviewed_by: Vec<String>,
+
+    /// Keep only PRs this user has been asked to review and has not
+    /// yet reviewed.
+    ///
+    /// Requests to a team the user belongs to do not count.
+    #[arg(
+        long = "review-requested",
+        help_heading = "Filters",
+        value_name = "USERNAME"
+    )]
+    pub review_requested: Option<String>,
+
+    /// Keep only PRs assigned to this user.
+    #[arg(
+        long,
+        help_heading = "Filters",
+        value_name = "USERNAME",
+        conflicts_with = "unassigned"
+    )]
+    pub assignee: Option<String>,
+
+    /// Keep only PRs assigned to no one.
+    #[arg(long, help_heading = "Filters")]
+    pub unassigned: bool,
+
+    /// Keep only PRs this user has commented on recently.
+    ///
+    /// Looks at the most recent comments fetched for each PR, not the
+    /// whole conversation. Repeatable; a PR matches only when every
+    /// named user has commented.
+    #[arg(
+        long = "commented-by",
+        help_heading = "Filters",
+        value_name = "USERNAME"
+    )]
+    pub commented_by: Vec<String>,
+
+    /// Keep only PRs whose most recent comment is by this user.
+    #[arg(
+        long = "last-comment-by",
+        help_heading = "Filters",
+        value_name = "USERNAME"
+    )]
+    pub last_comment_by: Option<String>,
+
+    /// Keep only PRs with no activity of any kind within this age:
+    /// no push, comment, review, label, or edit.
+    #[arg(
+        long,
+        help_heading = "Filters",
+        value_name = "AGE",
+        long_help = format!(
+            "Keep only PRs with no activity of any kind within this age: no push, comment, review, label, or edit.\n\nAccepts {AGE_FORMAT_HELP}."
+        )
+    )]
+    pub stale: Option<String>,
+
+    /// Keep only PRs opened more than this long ago.
+    #[arg(
+        long = "older-than",
+        help_heading = "Filters",
+        value_name = "AGE",
+        long_help = format!(
+            "Keep only PRs opened more than this long ago.\n\nAccepts {AGE_FORMAT_HELP}."
+        )
+    )]
+    pub older_than: Option<String>,
+
+    /// Keep only PRs opened within this age.
+    #[arg(
+        long = "newer-than",
+        help_heading = "Filters",
+        value_name = "AGE",
+        long_help = format!(
+            "Keep only PRs opened within this age.\n\nAccepts {AGE_FORMAT_HELP}."
+        )
+    )]
+    pub newer_than: Option<String>,
+
+    /// Keep only PRs whose head branch has not been pushed within this age.
+    #[arg(
+        long = "idle-since-push",
+        help_heading = "Filters",
+        value_name = "AGE",
+        long_help = format!(
+            "Keep only PRs whose head branch has not been pushed within this age.\n\nAccepts {AGE_FORMAT_HELP}. GitHub does not report push times, so the last push is taken as the latest of the last force push, when the head commit was committed, and when the PR was opened. A plain push of commits made well before it reads as older than it was. Merge requests from GitLab never match."
+        )
+    )]
+    pub idle_since_push: Option<String>,
+
+    /// Keep only PRs with a check queued or running for longer than this age.
+    #[arg(
+        long = "stuck-check",
+        help_heading = "Filters",
+        value_name = "AGE",
+        long_help = format!(
+            "Keep only PRs with a check queued or running for longer than this age.\n\nAccepts {AGE_FORMAT_HELP}. A pending commit status counts from when it was last set. Checks whose start time the forge does not report never match."
+        )
+    )]
+    pub stuck_check: Option<String>,
 }
 
 #[derive(Parser, Default, Debug)]
@@ -126,6 +372,29 @@ struct CliArgs {
     #[arg(short = 'r', long = "repo", value_name = "OWNER/REPO")]
     pub repo: Vec<String>,
 
+    /// Search every repository of this GitHub organisation or user.
+    ///
+    /// Archived repositories are skipped. Narrow the sweep by name with
+    /// `--repo-glob` and `--exclude-repo`. Cannot be combined with
+    /// `--repo`, `--query`, or positional PRs.
+    #[arg(long, value_name = "OWNER")]
+    pub org: Option<String>,
+
+    /// With `--org`, search only repositories whose name matches GLOB.
+    ///
+    /// `*` matches any run of characters and `?` any one, ignoring
+    /// case, e.g. `*-operator`. Repeat the flag to match any of several
+    /// patterns.
+    #[arg(long = "repo-glob", value_name = "GLOB", requires = "org")]
+    pub repo_glob: Vec<String>,
+
+    /// With `--org`, skip repositories whose name matches GLOB.
+    ///
+    /// Takes the same patterns as `--repo-glob` and wins over it;
+    /// repeatable.
+    #[arg(long = "exclude-repo", value_name = "GLOB", requires = "org")]
+    pub exclude_repo: Vec<String>,
+
     /// Pull requests to act on: a number, an inclusive range, or a URL.
     ///
     /// Numbers and ranges like `123` or `123-127` need `--repo` to name
@@ -156,6 +425,48 @@ struct CliArgs {
     #[arg(long, value_name = "SEARCH-QUERY")]
     pub query: Option<String>,
 
+    /// GitHub host to query, for GitHub Enterprise Server.
+    ///
+    /// Applies to `--repo`, `--query`, and bare PR numbers; PR URLs
+    /// always go to the host they name. Defaults to `$GH_HOST`, then
+    /// github.com. The token is read from `GH_ENTERPRISE_TOKEN` or
+    /// `GITHUB_ENTERPRISE_TOKEN`, else from `gh auth token --hostname`.
+    #[arg(long, value_name = "HOST")]
+    pub hostname: Option<String>,
+
+    /// Save every GitHub GraphQL request and response to DIR.
+    ///
+    /// Each exchange is written to its own `graphql-NNNN.json` file.
+    /// The directory is created if needed and must not already hold a
+    /// recording. Replay it later with `--replay`.
+    #[arg(long, value_name = "DIR")]
+    pub record: Option<PathBuf>,
+
+    /// Answer GitHub queries from a recording made with `--record`.
+    ///
+    /// Nothing is fetched from the network, so the same recording
+    /// gives the same output every time; useful for bug reports. The
+    /// command line must ask for the same PRs as the recorded run.
+    #[arg(long, value_name = "DIR", conflicts_with = "record")]
+    pub replay: Option<PathBuf>,
+
+    /// Fetch up to NUM repositories at once.
+    ///
+    /// Applies when several `--repo` flags are given. Output order does
+    /// not depend on it. autoprat fetches fewer at once when the
+    /// remaining GraphQL rate limit is low. Defaults to 4.
+    #[arg(long, value_name = "NUM")]
+    pub concurrency: Option<usize>,
+
+    /// Always ask GitHub instead of reusing recent responses.
+    ///
+    /// By default, responses are kept under the user's cache directory
+    /// (`$XDG_CACHE_HOME/autoprat`). Searches from the last two minutes
+    /// are reused when only listing PRs, and repository checks are
+    /// revalidated with ETags.
+    #[arg(long)]
+    pub no_cache: bool,
+
     #[command(flatten)]
     pub actions: ActionArgs,
 
@@ -179,6 +490,21 @@ struct CliArgs {
     )]
     pub throttle: Option<String>,
 
+    /// Only count comments by this user when throttling and checking
+    /// history; repeatable.
+    ///
+    /// By default anyone's comments count. A user named here counts
+    /// even if it is a bot.
+    #[arg(long = "throttle-by", value_name = "USERNAME")]
+    pub throttle_by: Vec<String>,
+
+    /// Leave out comments by bots when throttling and checking history,
+    /// since bots may quote commands back in their help text.
+    ///
+    /// Bots named with --throttle-by still count.
+    #[arg(long = "ignore-bot-comments")]
+    pub ignore_bot_comments: bool,
+
     /// Maximum age for history check (e.g. 30m, 1h, 2h); defaults to
     /// `DEFAULT_HISTORY_MAX_AGE`.
     #[arg(long, value_name = "DURATION", hide = true)]
@@ -220,12 +546,33 @@ struct CliArgs {
     /// all. Raise it to allow multi-commit PRs.
     #[arg(long = "commit-limit", default_value = "1", value_name = "NUM")]
     pub commit_limit: u64,
+
+    /// Refuse to act unless every matching PR could be fetched.
+    ///
+    /// Without it, a fetch that stops early (a failed page, a search
+    /// GitHub will not page to the end of) prints a warning and acts on
+    /// the PRs it did get.
+    #[arg(long)]
+    pub strict: bool,
 }
 
 impl CliArgs {
     pub fn validate(&self) -> Result<()> {
-        if self.repo.is_empty() && self.query.is_none() && self.prs.is_empty() {
-            anyhow::bail!("Must specify one of: --repo, --query, or --prs");
+        if self.repo.is_empty() && self.org.is_none() && self.query.is_none() && self.prs.is_empty()
+        {
+            anyhow::bail!("Must specify one of: --repo, --org, --query, or --prs");
+        }
+
+        if self.org.is_some() {
+            if !self.repo.is_empty() {
+                anyhow::bail!("Cannot use --repo with --org (narrow the sweep with --repo-glob)");
+            }
+            if self.query.is_some() {
+                anyhow::bail!("Cannot use --query with --org (put `org:` in the query instead)");
+            }
+            if !self.prs.is_empty() {
+                anyhow::bail!("Cannot use --prs with --org (these are different modes)");
+            }
         }
 
         if self.query.is_some() {
@@ -259,6 +606,10 @@ impl CliArgs {
             }
         }
 
+        if self.concurrency == Some(0) {
+            anyhow::bail!("--concurrency must be at least 1");
+        }
+
         if !self.exclude.is_empty() {
             if self.repo.is_empty() {
                 let has_pr_numbers = self.exclude.iter().any(|pr| !pr.starts_with("https://"));
@@ -314,7 +665,10 @@ fn cli_to_actions(opts: &ActionArgs, custom_comments: &[String]) -> Vec<PrAction
     all_actions
 }
 
-fn cli_to_search_criteria(filter_args: &FilterArgs) -> Vec<SearchCriterion> {
+fn cli_to_search_criteria(
+    filter_args: &FilterArgs,
+    now: DateTime<Utc>,
+) -> Result<Vec<SearchCriterion>> {
     let mut out = Vec::new();
     if filter_args.needs_approve {
         out.push(SearchCriterion::MissingLabel("approved".to_string()));
@@ -340,14 +694,40 @@ fn cli_to_search_criteria(filter_args: &FilterArgs) -> Vec<SearchCriterion> {
         out.push(SearchCriterion::BaseBranch(branch.clone()));
     }
 
-    out
+    if let Some(user) = &filter_args.assignee {
+        out.push(SearchCriterion::Assignee(user.clone()));
+    }
+    if filter_args.unassigned {
+        out.push(SearchCriterion::Unassigned);
+    }
+    if let Some(user) = &filter_args.review_requested {
+        out.push(SearchCriterion::ReviewRequested(user.clone()));
+    }
+
+    if let Some(age) = &filter_args.stale {
+        out.push(SearchCriterion::UpdatedBefore(age_cutoff(now, age)?));
+    }
+    if let Some(age) = &filter_args.older_than {
+        out.push(SearchCriterion::CreatedBefore(age_cutoff(now, age)?));
+    }
+    if let Some(age) = &filter_args.newer_than {
+        out.push(SearchCriterion::CreatedAfter(age_cutoff(now, age)?));
+    }
+
+    Ok(out)
 }
 
-fn cli_to_post_filters(filter_args: &FilterArgs) -> Result<Vec<Box<dyn PostFilter + Send + Sync>>> {
+fn cli_to_post_filters(
+    filter_args: &FilterArgs,
+    now: DateTime<Utc>,
+) -> Result<Vec<Box<dyn PostFilter + Send + Sync>>> {
     let mut out: Vec<Box<dyn PostFilter + Send + Sync>> = Vec::new();
     if filter_args.failing_ci {
         out.push(Box::new(FailingCiPost));
     }
+    if filter_args.failing_required_ci {
+        out.push(Box::new(FailingRequiredCiPost));
+    }
     if let Some(name) = &filter_args.author {
         out.push(Box::new(AuthorPost::new().with_value(name.clone())));
     }
@@ -364,20 +744,147 @@ fn cli_to_post_filters(filter_args: &FilterArgs) -> Result<Vec<Box<dyn PostFilte
         out.push(Box::new(BaseBranchPost::new().with_value(branch.clone())));
     }
 
+    if let Some(pattern) = &filter_args.head {
+        regex::Regex::new(pattern)
+            .with_context(|| format!("Invalid --head regular expression '{pattern}'"))?;
+        out.push(Box::new(HeadBranchPost::new().with_value(pattern.clone())));
+    }
+
+    if filter_args.unsigned_commits {
+        out.push(Box::new(UnsignedCommitsPost));
+    }
+
+    if filter_args.missing_signoff {
+        out.push(Box::new(MissingSignoffPost));
+    }
+
+    if let Some(pattern) = &filter_args.commit_message {
+        out.push(Box::new(CommitMessagePost {
+            pattern: regex::Regex::new(pattern).with_context(|| {
+                format!("Invalid --commit-message regular expression '{pattern}'")
+            })?,
+        }));
+    }
+
+    let issue_key = filter_args
+        .issue_key
+        .as_ref()
+        .map(|pattern| {
+            regex::Regex::new(pattern)
+                .with_context(|| format!("Invalid --issue-key regular expression '{pattern}'"))
+        })
+        .transpose()?;
+
+    if filter_args.linked_issue {
+        out.push(Box::new(LinkedIssuePost {
+            key: issue_key.clone(),
+        }));
+    }
+
+    if filter_args.no_linked_issue {
+        out.push(Box::new(NoLinkedIssuePost {
+            key: issue_key.clone(),
+        }));
+    }
+
+    // Alongside --linked-issue or --no-linked-issue, --issue-key only
+    // says which keys count.
+    if let Some(pattern) = issue_key
+        && !filter_args.linked_issue
+        && !filter_args.no_linked_issue
+    {
+        out.push(Box::new(IssueKeyPost { pattern }));
+    }
+
+    if filter_args.from_fork {
+        out.push(Box::new(FromForkPost));
+    }
+
+    if filter_args.same_repo {
+        out.push(Box::new(SameRepoPost));
+    }
+
     if let Some(expr) = &filter_args.commits {
         out.push(Box::new(CommitsPost {
             expr: CommitExpr::parse(expr)?,
         }));
     }
 
+    if !filter_args.size.is_empty() {
+        out.push(Box::new(SizePost {
+            exprs: filter_args
+                .size
+                .iter()
+                .map(|expr| SizeExpr::parse(expr))
+                .collect::<Result<_>>()?,
+        }));
+    }
+
+    if !filter_args.touches.is_empty() {
+        out.push(Box::new(TouchesPost {
+            patterns: filter_args.touches.clone(),
+        }));
+    }
+
+    if !filter_args.not_touches.is_empty() {
+        out.push(Box::new(NotTouchesPost {
+            patterns: filter_args.not_touches.clone(),
+        }));
+    }
+
+    if filter_args.conflicting {
+        out.push(Box::new(ConflictingPost));
+    }
+
+    if filter_args.mergeable {
+        out.push(Box::new(MergeablePost));
+    }
+
+    if let Some(decision) = &filter_args.review_decision {
+        out.push(Box::new(ReviewDecisionPost {
+            decision: ReviewDecision::parse(decision)?,
+        }));
+    }
+
+    if !filter_args.reviewed_by.is_empty() {
+        out.push(Box::new(ReviewedByPost {
+            reviewers: filter_args.reviewed_by.clone(),
+        }));
+    }
+
+    if !filter_args.commented_by.is_empty() {
+        out.push(Box::new(CommentedByPost {
+            users: filter_args.commented_by.clone(),
+        }));
+    }
+
+    if let Some(user) = &filter_args.last_comment_by {
+        out.push(Box::new(LastCommentByPost::new().with_value(user.clone())));
+    }
+
+    if let Some(age) = &filter_args.idle_since_push {
+        out.push(Box::new(IdleSincePushPost {
+            cutoff: age_cutoff(now, age)?,
+        }));
+    }
+    if let Some(age) = &filter_args.stuck_check {
+        out.push(Box::new(StuckCheckPost {
+            cutoff: age_cutoff(now, age)?,
+        }));
+    }
+
     Ok(out)
 }
 
 fn parse_throttle_duration(throttle_str: &str) -> Result<Duration> {
     let throttle_str = throttle_str.trim();
+    let too_large = || format!("Invalid throttle '{throttle_str}': too large");
 
     if let Ok(minutes) = throttle_str.parse::<u64>() {
-        return Ok(Duration::from_secs(minutes * 60));
+        return minutes
+            .checked_mul(60)
+            .map(Duration::from_secs)
+            .with_context(too_large);
     }
 
     if let Some(seconds_str) = throttle_str.strip_suffix('s') {
@@ -391,19 +898,51 @@ fn parse_throttle_duration(throttle_str: &str) -> Result<Duration> {
         let minutes: u64 = minutes_str
             .parse()
             .with_context(|| format!("Invalid throttle minutes: '{minutes_str}'"))?;
-        return Ok(Duration::from_secs(minutes * 60));
+        return minutes
+            .checked_mul(60)
+            .map(Duration::from_secs)
+            .with_context(too_large);
     }
 
     if let Some(hours_str) = throttle_str.strip_suffix('h') {
         let hours: u64 = hours_str
             .parse()
             .with_context(|| format!("Invalid throttle hours: '{hours_str}'"))?;
-        return Ok(Duration::from_secs(hours * 3600));
+        return hours
+            .checked_mul(3600)
+            .map(Duration::from_secs)
+            .with_context(too_large);
     }
 
     anyhow::bail!("Invalid throttle format '{throttle_str}'. Accepts {THROTTLE_FORMAT_HELP}.")
 }
 
+fn parse_age(age_str: &str) -> Result<TimeDelta> {
+    let age_str = age_str.trim();
+
+    let (count, unit) = match age_str.find(|c: char| !c.is_ascii_digit()) {
+        Some(at) => age_str.split_at(at),
+        None => (age_str, "d"),
+    };
+    let count: i64 = count
+        .parse()
+        .with_context(|| format!("Invalid age '{age_str}'. Accepts {AGE_FORMAT_HELP}."))?;
+
+    let age = match unit {
+        "h" => TimeDelta::try_hours(count),
+        "d" => TimeDelta::try_days(count),
+        "w" => TimeDelta::try_weeks(count),
+        _ => anyhow::bail!("Invalid age '{age_str}'. Accepts {AGE_FORMAT_HELP}."),
+    };
+    age.with_context(|| format!("Invalid age '{age_str}': too large"))
+}
+
+/// The time `age_str` before `now`.
+fn age_cutoff(now: DateTime<Utc>, age_str: &str) -> Result<DateTime<Utc>> {
+    now.checked_sub_signed(parse_age(age_str)?)
+        .with_context(|| format!("Invalid age '{}': too large", age_str.trim()))
+}
+
 fn validate_pr_urls_against_repo(repos: &[String], prs: &[String]) -> Result<()> {
     // Only validate if there's exactly one repo specified
     if repos.len() != 1 {
@@ -466,6 +1005,11 @@ fn create_autoprat_request(cli: CliArgs) -> Result<QuerySpec> {
     let exclude_identifiers = parse_pr_args_to_identifiers(&repos, &cli.exclude)?;
 
     let query = cli.query.clone();
+    let org = cli.org.clone().map(|org| OrgSweep {
+        org,
+        include: cli.repo_glob.clone(),
+        exclude: cli.exclude_repo.clone(),
+    });
 
     let throttle = cli
         .throttle
@@ -486,24 +1030,34 @@ fn create_autoprat_request(cli: CliArgs) -> Result<QuerySpec> {
         .history_max_comments
         .unwrap_or(DEFAULT_HISTORY_MAX_COMMENTS);
 
+    // Ages are turned into fixed cutoffs once, so every repository is
+    // searched against the same point in time.
+    let now = Utc::now();
+
     Ok(QuerySpec {
         fetch: FetchCriteria {
             repos,
+            org,
             prs: pr_identifiers,
             query,
             limit: cli.limit,
-            search_criteria: cli_to_search_criteria(&cli.filters),
+            search_criteria: cli_to_search_criteria(&cli.filters, now)?,
         },
         selection: SelectionPolicy {
             exclude: exclude_identifiers,
-            post_filters: cli_to_post_filters(&cli.filters)?,
+            post_filters: cli_to_post_filters(&cli.filters, now)?,
         },
         action_policy: ActionPolicy {
             actions: cli_to_actions(&cli.actions, &cli.comment),
             throttle,
             history_max_age,
             history_max_comments,
+            comment_scope: CommentScope {
+                authors: cli.throttle_by.clone(),
+                ignore_bots: cli.ignore_bot_comments,
+            },
             commit_limit: cli.commit_limit,
+            strict: cli.strict,
         },
     })
 }
@@ -523,10 +1077,41 @@ fn transform_slash_commands(args: Vec<String>) -> Vec<String> {
         .collect()
 }
 
+fn determine_forge_settings(cli: &CliArgs) -> ForgeSettings {
+    ForgeSettings {
+        github_host: cli
+            .hostname
+            .as_deref()
+            .map(normalise_hostname)
+            .filter(|host| !host.is_empty()),
+        record_dir: cli.record.clone(),
+        replay_dir: cli.replay.clone(),
+        fetch_concurrency: cli.concurrency,
+        no_cache: cli.no_cache,
+    }
+}
+
+/// Accept `github.example.com`, `https://github.example.com`, or
+/// `https://github.example.com/`, as gh does.
+fn normalise_hostname(hostname: &str) -> String {
+    let hostname = hostname.trim();
+    hostname
+        .strip_prefix("https://")
+        .or_else(|| hostname.strip_prefix("http://"))
+        .unwrap_or(hostname)
+        .trim_end_matches('/')
+        .to_ascii_lowercase()
+}
+
 fn build_query_from_cli(cli: CliArgs) -> Result<AppRequest> {
     let display = determine_display_settings(&cli);
+    let forge = determine_forge_settings(&cli);
     let query = create_autoprat_request(cli)?;
-    Ok(AppRequest { query, display })
+    Ok(AppRequest {
+        query,
+        display,
+        forge,
+    })
 }
 
 /// Parses command-line arguments into a query specification and display mode.
@@ -596,6 +1181,214 @@ mod tests {
             .expect("post filters should combine with --query");
     }
 
+    #[test]
+    fn parse_args_review_filters_become_post_filters() {
+        let request = parse_args([
+            "autoprat",
+            "--repo",
+            "owner/repo",
+            "--review-decision",
+            "changes-requested",
+            "--reviewed-by",
+            "alice",
+        ])
+        .unwrap();
+        assert_eq!(request.query.selection.post_filters.len(), 2);
+
+        let request = parse_args(["autoprat", "--repo", "owner/repo", "--conflicting"]).unwrap();
+        assert_eq!(request.query.selection.post_filters.len(), 1);
+        assert!(
+            parse_args([
+                "autoprat",
+                "--repo",
+                "owner/repo",
+                "--conflicting",
+                "--mergeable"
+            ])
+            .is_err()
+        );
+
+        let err = parse_args([
+            "autoprat",
+            "--repo",
+            "owner/repo",
+            "--review-decision",
+            "lgtm",
+        ])
+        .unwrap_err();
+        assert!(err.to_string().contains("Invalid review decision 'lgtm'"));
+    }
+
+    #[test]
+    fn parse_args_head_filters_become_post_filters() {
+        let request = parse_args([
+            "autoprat",
+            "--repo",
+            "owner/repo",
+            "--head",
+            "^dependabot/",
+            "--same-repo",
+        ])
+        .unwrap();
+        assert_eq!(request.query.selection.post_filters.len(), 2);
+
+        let err = parse_args(["autoprat", "--repo", "owner/repo", "--head", "("]).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Invalid --head regular expression")
+        );
+
+        assert!(
+            parse_args([
+                "autoprat",
+                "--repo",
+                "owner/repo",
+                "--from-fork",
+                "--same-repo"
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn parse_args_size_expressions_become_one_post_filter() {
+        let request = parse_args([
+            "autoprat",
+            "--repo",
+            "owner/repo",
+            "--size",
+            "lines<50",
+            "--size",
+            "files<=3",
+        ])
+        .unwrap();
+        assert_eq!(request.query.selection.post_filters.len(), 1);
+
+        let err =
+            parse_args(["autoprat", "--repo", "owner/repo", "--size", "bytes<50"]).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Invalid --size expression 'bytes<50'")
+        );
+    }
+
+    #[test]
+    fn parse_args_path_filters_ask_for_changed_paths() {
+        let request =
+            parse_args(["autoprat", "--repo", "owner/repo", "--author", "alice"]).unwrap();
+        assert!(!request.query.selection.needs_changed_paths());
+
+        let request = parse_args([
+            "autoprat",
+            "--repo",
+            "owner/repo",
+            "--touches",
+            "api/**",
+            "--not-touches",
+            "vendor/**",
+        ])
+        .unwrap();
+        assert_eq!(request.query.selection.post_filters.len(), 2);
+        assert!(request.query.selection.needs_changed_paths());
+    }
+
+    #[test]
+    fn parse_args_assignment_filters_become_search_criteria() {
+        let request = parse_args([
+            "autoprat",
+            "--repo",
+            "owner/repo",
+            "--unassigned",
+            "--review-requested",
+            "bob",
+        ])
+        .unwrap();
+        assert_eq!(
+            request.query.fetch.search_criteria,
+            vec![
+                SearchCriterion::Unassigned,
+                SearchCriterion::ReviewRequested("bob".to_string()),
+            ]
+        );
+
+        assert!(
+            parse_args([
+                "autoprat",
+                "--repo",
+                "owner/repo",
+                "--assignee",
+                "alice",
+                "--unassigned"
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn parse_args_ages_become_cutoffs() {
+        let before = Utc::now();
+        let request = parse_args([
+            "autoprat",
+            "--repo",
+            "owner/repo",
+            "--stale",
+            "14d",
+            "--older-than",
+            "2w",
+            "--newer-than",
+            "30",
+            "--idle-since-push",
+            "3d",
+            "--stuck-check",
+            "3h",
+        ])
+        .unwrap();
+        let after = Utc::now();
+
+        let criteria = &request.query.fetch.search_criteria;
+        assert_eq!(criteria.len(), 3);
+        let within = |cutoff: &DateTime<Utc>, age: TimeDelta| {
+            before - age <= *cutoff && *cutoff <= after - age
+        };
+        assert!(
+            matches!(&criteria[0], SearchCriterion::UpdatedBefore(t) if within(t, TimeDelta::days(14)))
+        );
+        assert!(
+            matches!(&criteria[1], SearchCriterion::CreatedBefore(t) if within(t, TimeDelta::weeks(2)))
+        );
+        assert!(
+            matches!(&criteria[2], SearchCriterion::CreatedAfter(t) if within(t, TimeDelta::days(30)))
+        );
+        assert_eq!(request.query.selection.post_filters.len(), 2);
+
+        let err = parse_args(["autoprat", "--repo", "owner/repo", "--stale", "2y"]).unwrap_err();
+        assert!(err.to_string().contains("Invalid age '2y'"));
+
+        for flag in ["--stale", "--older-than", "--newer-than", "--stuck-check"] {
+            let err =
+                parse_args(["autoprat", "--repo", "owner/repo", flag, "99999999999w"]).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "Invalid age '99999999999w': too large",
+                "{flag}"
+            );
+        }
+        let err =
+            parse_args(["autoprat", "--repo", "owner/repo", "--stale", "99999999d"]).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid age '99999999d': too large");
+    }
+
+    #[test]
+    fn parse_age_scales_each_unit_exactly() {
+        assert_eq!(parse_age("3").unwrap(), TimeDelta::days(3));
+        assert_eq!(parse_age("12h").unwrap(), TimeDelta::hours(12));
+        assert_eq!(parse_age("14d").unwrap(), TimeDelta::days(14));
+        assert_eq!(parse_age("2w").unwrap(), TimeDelta::weeks(2));
+        assert!(parse_age("").is_err());
+        assert!(parse_age("d").is_err());
+        assert!(parse_age("5m").is_err());
+    }
+
     #[test]
     fn parse_args_maps_action_flags_to_action_policy() {
         let request = parse_args([
@@ -641,6 +1434,34 @@ mod tests {
         );
         assert_eq!(request.query.action_policy.history_max_comments, 2);
         assert_eq!(request.query.action_policy.commit_limit, 3);
+        assert_eq!(
+            request.query.action_policy.comment_scope,
+            CommentScope::default()
+        );
+    }
+
+    #[test]
+    fn parse_args_scopes_throttling_to_named_authors() {
+        let request = parse_args([
+            "autoprat",
+            "--repo",
+            "owner/repo",
+            "--retest",
+            "--throttle-by",
+            "alice",
+            "--throttle-by",
+            "ci-runner",
+            "--ignore-bot-comments",
+        ])
+        .unwrap();
+
+        assert_eq!(
+            request.query.action_policy.comment_scope,
+            CommentScope {
+                authors: vec!["alice".to_string(), "ci-runner".to_string()],
+                ignore_bots: true,
+            }
+        );
     }
 
     #[test]
@@ -666,6 +1487,89 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_args_normalises_hostname() {
+        let request = parse_args([
+            "autoprat",
+            "--repo",
+            "owner/repo",
+            "--hostname",
+            "https://GitHub.Example.com/",
+        ])
+        .unwrap();
+
+        assert_eq!(
+            request.forge.github_host.as_deref(),
+            Some("github.example.com")
+        );
+
+        let request = parse_args(["autoprat", "--repo", "owner/repo"]).unwrap();
+        assert_eq!(request.forge, ForgeSettings::default());
+    }
+
+    #[test]
+    fn parse_args_rejects_zero_concurrency() {
+        let err = parse_args(["autoprat", "--repo", "owner/repo", "--concurrency", "0"])
+            .expect_err("expected --concurrency 0 to be rejected");
+        assert!(err.to_string().contains("--concurrency"));
+
+        let request =
+            parse_args(["autoprat", "--repo", "owner/repo", "--concurrency", "8"]).unwrap();
+        assert_eq!(request.forge.fetch_concurrency, Some(8));
+    }
+
+    #[test]
+    fn parse_args_no_cache_reaches_forge_settings() {
+        let request = parse_args(["autoprat", "--repo", "owner/repo", "--no-cache"]).unwrap();
+        assert!(request.forge.no_cache);
+    }
+
+    #[test]
+    fn parse_args_org_builds_sweep_from_globs() {
+        let request = parse_args([
+            "autoprat",
+            "--org",
+            "openshift",
+            "--repo-glob",
+            "*-operator",
+            "--exclude-repo",
+            "legacy-*",
+        ])
+        .unwrap();
+
+        assert!(request.query.fetch.repos.is_empty());
+        assert_eq!(
+            request.query.fetch.org,
+            Some(OrgSweep {
+                org: "openshift".to_string(),
+                include: vec!["*-operator".to_string()],
+                exclude: vec!["legacy-*".to_string()],
+            })
+        );
+    }
+
+    #[test]
+    fn parse_args_org_conflicts_with_other_targets() {
+        for args in [
+            &["autoprat", "--org", "openshift", "--repo", "owner/repo"][..],
+            &["autoprat", "--org", "openshift", "--query", "is:pr"],
+            &[
+                "autoprat",
+                "--org",
+                "openshift",
+                "https://github.com/o/r/pull/1",
+            ],
+        ] {
+            let err = parse_args(args.iter().copied()).unwrap_err();
+            assert!(err.to_string().contains("with --org"), "{args:?}: {err}");
+        }
+    }
+
+    #[test]
+    fn parse_args_repo_glob_requires_org() {
+        assert!(parse_args(["autoprat", "--repo", "owner/repo", "--repo-glob", "*"]).is_err());
+    }
+
     #[test]
     fn parse_pr_args_to_identifiers_rejects_bare_dash_with_helpful_message() {
         let err = parse_pr_args_to_identifiers(&repos(), &["-".to_string()])
@@ -773,6 +1677,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_throttle_duration_rejects_overflowing_values() {
+        assert_eq!(
+            parse_throttle_duration("9999999999999999999h")
+                .unwrap_err()
+                .to_string(),
+            "Invalid throttle '9999999999999999999h': too large"
+        );
+        assert_eq!(
+            parse_throttle_duration("999999999999999999")
+                .unwrap_err()
+                .to_string(),
+            "Invalid throttle '999999999999999999': too large"
+        );
+    }
+
     #[test]
     fn transform_slash_commands_maps_every_alias() {
         let cases = [
@@ -1,9 +1,10 @@
 use std::time::Duration;
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeDelta, Utc};
 
 use crate::types::{
-    ActionPolicy, CommentAction, FetchCriteria, PrAction, PullRequest, SelectionPolicy, Task,
+    ActionPolicy, CommentAction, CommentScope, FetchCriteria, PrAction, PullRequest,
+    SelectionPolicy, Task,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -37,17 +38,30 @@ pub fn pull_request_matches(
         && selection.post_filters.iter().all(|pf| pf.matches(pr))
 }
 
+/// The start of the `window` running up to `now`. A window reaching
+/// back before the earliest time chrono can represent covers all of
+/// history.
+pub(crate) fn window_start(now: DateTime<Utc>, window: Duration) -> DateTime<Utc> {
+    i64::try_from(window.as_secs())
+        .ok()
+        .and_then(TimeDelta::try_seconds)
+        .and_then(|window| now.checked_sub_signed(window))
+        .unwrap_or(DateTime::<Utc>::MIN_UTC)
+}
+
 fn was_comment_posted_recently(
     pr: &PullRequest,
     comment_body: &str,
+    scope: &CommentScope,
     throttle_duration: Duration,
     now: DateTime<Utc>,
 ) -> bool {
-    let cutoff_time = now - chrono::Duration::seconds(throttle_duration.as_secs() as i64);
+    let cutoff_time = window_start(now, throttle_duration);
     let target_command = comment_body.trim();
 
     pr.recent_comments.iter().any(|comment| {
         comment.created_at > cutoff_time
+            && scope.counts(comment)
             && comment
                 .body
                 .lines()
@@ -58,12 +72,13 @@ fn was_comment_posted_recently(
 fn was_comment_posted_in_history(
     pr: &PullRequest,
     comment_body: &str,
+    scope: &CommentScope,
     max_comments_to_check: usize,
     max_age: Duration,
     now: DateTime<Utc>,
 ) -> bool {
     let target_command = comment_body.trim();
-    let cutoff_time = now - chrono::Duration::seconds(max_age.as_secs() as i64);
+    let cutoff_time = window_start(now, max_age);
 
     pr.recent_comments
         .iter()
@@ -71,6 +86,7 @@ fn was_comment_posted_in_history(
         .take(max_comments_to_check)
         .any(|comment| {
             comment.created_at > cutoff_time
+                && scope.counts(comment)
                 && comment
                     .body
                     .lines()
@@ -81,6 +97,7 @@ fn was_comment_posted_in_history(
 fn comment_should_execute(
     action: &CommentAction,
     pr: &PullRequest,
+    comment_scope: &CommentScope,
     history_max_comments: usize,
     history_max_age: Duration,
     throttle: Option<Duration>,
@@ -90,13 +107,26 @@ fn comment_should_execute(
         return false;
     }
 
+    // Older comments the checks below need were not fetched, so the
+    // same comment may already be there.
+    if !pr.comment_history_complete {
+        return false;
+    }
+
     let body = action.body();
-    if was_comment_posted_in_history(pr, body, history_max_comments, history_max_age, now) {
+    if was_comment_posted_in_history(
+        pr,
+        body,
+        comment_scope,
+        history_max_comments,
+        history_max_age,
+        now,
+    ) {
         return false;
     }
 
     if let Some(duration) = throttle
-        && was_comment_posted_recently(pr, body, duration, now)
+        && was_comment_posted_recently(pr, body, comment_scope, duration, now)
     {
         return false;
     }
@@ -107,6 +137,7 @@ fn comment_should_execute(
 pub fn plan_executable_action(
     action: &PrAction,
     pr: &PullRequest,
+    comment_scope: &CommentScope,
     history_max_comments: usize,
     history_max_age: Duration,
     throttle: Option<Duration>,
@@ -116,6 +147,7 @@ pub fn plan_executable_action(
         PrAction::Comment(action) => comment_should_execute(
             action,
             pr,
+            comment_scope,
             history_max_comments,
             history_max_age,
             throttle,
@@ -129,6 +161,7 @@ pub fn plan_executable_action(
                     comment_should_execute(
                         action,
                         pr,
+                        comment_scope,
                         history_max_comments,
                         history_max_age,
                         throttle,
@@ -141,9 +174,9 @@ pub fn plan_executable_action(
             PrAction::comments(executable_actions)
         }
         PrAction::Close => Some(action.clone()),
-        // A draft cannot be merged; GitHub rejects it, so never plan
-        // the action in the first place.
-        PrAction::Merge => (!pr.is_draft).then(|| action.clone()),
+        // A draft or conflicting PR cannot be merged; GitHub rejects
+        // it, so never plan the action in the first place.
+        PrAction::Merge => (!pr.is_draft && !pr.is_conflicting()).then(|| action.clone()),
     }
 }
 
@@ -159,6 +192,7 @@ pub fn generate_executable_actions(
             if let Some(action) = plan_executable_action(
                 action,
                 pr,
+                &policy.comment_scope,
                 policy.history_max_comments,
                 policy.history_max_age,
                 policy.throttle,
@@ -175,6 +209,39 @@ pub fn generate_executable_actions(
     executable_actions
 }
 
+/// URLs of PRs that would have been sent a comment, had their comment
+/// history been complete enough to rule out a repeat.
+pub fn unverified_comment_prs(
+    filtered_prs: &[PullRequest],
+    policy: &ActionPolicy,
+    now: DateTime<Utc>,
+) -> Vec<String> {
+    filtered_prs
+        .iter()
+        .filter(|pr| !pr.comment_history_complete)
+        .filter(|pr| {
+            let assumed = PullRequest {
+                comment_history_complete: true,
+                ..(*pr).clone()
+            };
+            policy.actions.iter().any(|action| {
+                matches!(action, PrAction::Comment(_) | PrAction::GroupedComment(_))
+                    && plan_executable_action(
+                        action,
+                        &assumed,
+                        &policy.comment_scope,
+                        policy.history_max_comments,
+                        policy.history_max_age,
+                        policy.throttle,
+                        now,
+                    )
+                    .is_some()
+            })
+        })
+        .map(|pr| pr.url.clone())
+        .collect()
+}
+
 pub fn commit_limit_offenders(tasks: &[Task], limit: u64) -> Vec<CommitLimitOffender> {
     let mut offenders = tasks
         .iter()
@@ -199,7 +266,10 @@ mod tests {
     use crate::{
         filters::AuthorPost,
         pr_selector::PrIdentifier,
-        types::{CommentInfo, PrState, PullRequest, Repo, SearchCriterion},
+        types::{
+            CommentAuthor, CommentInfo, MergeState, Mergeable, PrState, PullRequest, Repo,
+            SearchCriterion,
+        },
     };
 
     fn pr_with_comments(recent_comments: Vec<CommentInfo>) -> PullRequest {
@@ -212,12 +282,32 @@ mod tests {
             url: "https://github.com/owner/repo/pull/123".to_string(),
             labels: vec![],
             created_at: Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap(),
+            updated_at: Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap(),
+            last_pushed_at: None,
             base_branch: "main".to_string(),
             commit_count: 1,
+            additions: None,
+            deletions: None,
+            changed_files: None,
+            changed_paths: None,
+            commits: None,
+            linked_issues: None,
+            issue_keys: vec![],
             is_draft: false,
             state: PrState::Open,
             checks: vec![],
             recent_comments,
+            comment_history_complete: true,
+            review_decision: None,
+            reviews: vec![],
+            requested_reviewers: vec![],
+            assignees: vec![],
+            mergeable: Mergeable::Unknown,
+            merge_state: MergeState::Unknown,
+            head_branch: "feature".to_string(),
+            head_repo_owner: None,
+            head_sha: String::new(),
+            is_cross_repository: false,
         }
     }
 
@@ -246,11 +336,19 @@ mod tests {
         let pr = pr_with_comments(vec![CommentInfo {
             body: "/lgtm".to_string(),
             created_at: now - chrono::Duration::minutes(5),
+            author: None,
         }]);
         let action = PrAction::comments(vec![CommentAction::Approve, CommentAction::Lgtm]).unwrap();
 
-        let planned =
-            plan_executable_action(&action, &pr, 10, Duration::from_secs(3600), None, now);
+        let planned = plan_executable_action(
+            &action,
+            &pr,
+            &CommentScope::default(),
+            10,
+            Duration::from_secs(3600),
+            None,
+            now,
+        );
 
         assert_eq!(planned, Some(PrAction::comment(CommentAction::Approve)));
     }
@@ -261,21 +359,68 @@ mod tests {
         let pr = pr_with_comments(vec![CommentInfo {
             body: "/lgtm".to_string(),
             created_at: now - chrono::Duration::minutes(5),
+            author: None,
         }]);
         let action = PrAction::comment(CommentAction::Lgtm);
 
-        let planned =
-            plan_executable_action(&action, &pr, 10, Duration::from_secs(3600), None, now);
+        let planned = plan_executable_action(
+            &action,
+            &pr,
+            &CommentScope::default(),
+            10,
+            Duration::from_secs(3600),
+            None,
+            now,
+        );
 
         assert_eq!(planned, None);
     }
 
+    #[test]
+    fn incomplete_comment_history_holds_back_comments_and_reports_them() {
+        let now = Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap();
+        let mut pr = pr_with_comments(vec![]);
+        pr.comment_history_complete = false;
+        let mut posted = pr_with_state(
+            124,
+            &[],
+            vec![CommentInfo {
+                body: "/retest".to_string(),
+                created_at: now - chrono::Duration::minutes(5),
+                author: None,
+            }],
+        );
+        posted.comment_history_complete = false;
+        let policy = ActionPolicy {
+            actions: vec![PrAction::comment(CommentAction::Retest), PrAction::Close],
+            throttle: None,
+            history_max_age: Duration::from_secs(3600),
+            history_max_comments: 10,
+            comment_scope: CommentScope::default(),
+            commit_limit: 100,
+            strict: false,
+        };
+
+        let tasks = generate_executable_actions(&[pr.clone(), posted.clone()], &policy, now);
+
+        assert_eq!(
+            planned_actions(&tasks),
+            vec![(123, PrAction::Close), (124, PrAction::Close)]
+        );
+        assert_eq!(
+            unverified_comment_prs(&[pr, posted], &policy, now),
+            vec!["https://github.com/owner/repo/pull/123".to_string()],
+            "a repeat already found in the partial history is not in doubt"
+        );
+    }
+
     #[test]
     fn grouped_comment_prunes_throttled_custom_sub_actions() {
         let now = Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap();
         let pr = pr_with_comments(vec![CommentInfo {
             body: "Needs attention".to_string(),
             created_at: now - chrono::Duration::minutes(2),
+            author: None,
         }]);
         let action = PrAction::comments(vec![
             CommentAction::Custom("Needs attention".to_string()),
@@ -286,6 +431,7 @@ mod tests {
         let planned = plan_executable_action(
             &action,
             &pr,
+            &CommentScope::default(),
             10,
             Duration::from_secs(3600),
             Some(Duration::from_secs(300)),
@@ -300,6 +446,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ignored_bot_echoes_and_out_of_scope_authors_do_not_suppress_comments() {
+        let now = Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap();
+        let comment_by = |login: &str, is_bot: bool| CommentInfo {
+            body: "Available commands:\n/retest\n/lgtm".to_string(),
+            created_at: now - chrono::Duration::minutes(2),
+            author: Some(CommentAuthor {
+                login: login.to_string(),
+                is_bot,
+            }),
+        };
+        let action = PrAction::comment(CommentAction::Retest);
+        let plan = |pr: &PullRequest, scope: &CommentScope| {
+            plan_executable_action(
+                &action,
+                pr,
+                scope,
+                10,
+                Duration::from_secs(3600),
+                Some(Duration::from_secs(300)),
+                now,
+            )
+        };
+
+        let echoed = pr_with_comments(vec![comment_by("openshift-ci", true)]);
+        assert_eq!(plan(&echoed, &CommentScope::default()), None);
+        let without_bots = CommentScope {
+            ignore_bots: true,
+            ..CommentScope::default()
+        };
+        assert!(plan(&echoed, &without_bots).is_some());
+        let named_bot = CommentScope {
+            authors: vec!["openshift-ci".to_string()],
+            ignore_bots: true,
+        };
+        assert_eq!(plan(&echoed, &named_bot), None);
+
+        let retested_by_bob = pr_with_comments(vec![comment_by("bob", false)]);
+        let only_mine = CommentScope {
+            authors: vec!["alice".to_string()],
+            ignore_bots: false,
+        };
+        assert_eq!(plan(&retested_by_bob, &CommentScope::default()), None);
+        assert!(plan(&retested_by_bob, &only_mine).is_some());
+    }
+
     #[test]
     fn merge_is_rejected_for_a_draft_pull_request() {
         let now = Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap();
@@ -310,6 +502,7 @@ mod tests {
             plan_executable_action(
                 &PrAction::Merge,
                 &draft,
+                &CommentScope::default(),
                 10,
                 Duration::from_secs(3600),
                 None,
@@ -324,6 +517,7 @@ mod tests {
             plan_executable_action(
                 &PrAction::Merge,
                 &ready,
+                &CommentScope::default(),
                 10,
                 Duration::from_secs(3600),
                 None,
@@ -334,6 +528,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn merge_is_rejected_for_a_conflicting_pull_request() {
+        let now = Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap();
+        let plan = |pr: &PullRequest| {
+            plan_executable_action(
+                &PrAction::Merge,
+                pr,
+                &CommentScope::default(),
+                10,
+                Duration::from_secs(3600),
+                None,
+                now,
+            )
+        };
+
+        let mut conflicting = pr_with_comments(vec![]);
+        conflicting.mergeable = Mergeable::Conflicting;
+        assert_eq!(plan(&conflicting), None, "a conflict must not merge");
+
+        let mut dirty = pr_with_comments(vec![]);
+        dirty.merge_state = MergeState::Dirty;
+        assert_eq!(plan(&dirty), None, "a dirty merge state must not merge");
+
+        let mut unknown = pr_with_comments(vec![]);
+        unknown.mergeable = Mergeable::Unknown;
+        assert_eq!(
+            plan(&unknown),
+            Some(PrAction::Merge),
+            "unknown mergeability is left to the forge"
+        );
+    }
+
     #[test]
     fn pull_request_matches_combines_targets_excludes_and_filters() {
         let mut pr = pr_with_comments(vec![]);
@@ -342,9 +568,11 @@ mod tests {
 
         let fetch = FetchCriteria {
             repos: vec![],
+            org: None,
             prs: vec![PrIdentifier {
                 repo: pr.repo.clone(),
                 number: 124,
+                host: None,
             }],
             query: None,
             limit: 100,
@@ -361,6 +589,7 @@ mod tests {
             exclude: vec![PrIdentifier {
                 repo: pr.repo.clone(),
                 number: 124,
+                host: None,
             }],
             post_filters: vec![Box::new(AuthorPost::new().with_value("alice"))],
         };
@@ -382,7 +611,9 @@ mod tests {
             throttle: Some(Duration::from_secs(300)),
             history_max_age: Duration::from_secs(3600),
             history_max_comments: 10,
+            comment_scope: CommentScope::default(),
             commit_limit: 1,
+            strict: false,
         };
         let prs = vec![
             pr_with_state(1, &[], vec![]),
@@ -392,6 +623,7 @@ mod tests {
                 vec![CommentInfo {
                     body: "Needs attention".to_string(),
                     created_at: now - chrono::Duration::minutes(1),
+                    author: None,
                 }],
             ),
             pr_with_state(
@@ -400,6 +632,7 @@ mod tests {
                 vec![CommentInfo {
                     body: "Needs attention".to_string(),
                     created_at: now - chrono::Duration::minutes(1),
+                    author: None,
                 }],
             ),
         ];
@@ -468,7 +701,8 @@ mod tests {
         };
         let fetch_for = |repo: Repo, number: u64| FetchCriteria {
             repos: vec![],
-            prs: vec![PrIdentifier { repo, number }],
+            org: None,
+            prs: vec![PrIdentifier::new(repo, number)],
             query: None,
             limit: 100,
             search_criteria: vec![],
@@ -508,19 +742,26 @@ mod tests {
         let at_cutoff = pr_with_comments(vec![CommentInfo {
             body: "/lgtm".to_string(),
             created_at: cutoff,
+            author: None,
         }]);
         assert!(!was_comment_posted_recently(
-            &at_cutoff, "/lgtm", throttle, now
+            &at_cutoff,
+            "/lgtm",
+            &CommentScope::default(),
+            throttle,
+            now
         ));
 
         // One second inside the window is still recent.
         let just_inside = pr_with_comments(vec![CommentInfo {
             body: "/lgtm".to_string(),
             created_at: cutoff + chrono::Duration::seconds(1),
+            author: None,
         }]);
         assert!(was_comment_posted_recently(
             &just_inside,
             "/lgtm",
+            &CommentScope::default(),
             throttle,
             now
         ));
@@ -537,18 +778,26 @@ mod tests {
         let at_cutoff = pr_with_comments(vec![CommentInfo {
             body: "/lgtm".to_string(),
             created_at: cutoff,
+            author: None,
         }]);
         assert!(!was_comment_posted_in_history(
-            &at_cutoff, "/lgtm", 10, max_age, now
+            &at_cutoff,
+            "/lgtm",
+            &CommentScope::default(),
+            10,
+            max_age,
+            now
         ));
 
         let just_inside = pr_with_comments(vec![CommentInfo {
             body: "/lgtm".to_string(),
             created_at: cutoff + chrono::Duration::seconds(1),
+            author: None,
         }]);
         assert!(was_comment_posted_in_history(
             &just_inside,
             "/lgtm",
+            &CommentScope::default(),
             10,
             max_age,
             now
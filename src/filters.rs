@@ -1,6 +1,7 @@
 use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
 
-use crate::types::{PostFilter, PullRequest};
+use crate::types::{Mergeable, PostFilter, PullRequest, ReviewDecision, SignatureState};
 
 macro_rules! simple_post_filter {
     ($vis:vis $ty:ident, $pred:expr) => {
@@ -70,6 +71,27 @@ simple_post_filter!(pub FailingCiPost, |pr: &PullRequest| {
     pr.has_failing_ci()
 });
 
+#[derive(Debug)]
+pub struct FailingRequiredCiPost;
+
+impl PostFilter for FailingRequiredCiPost {
+    fn matches(&self, pr: &PullRequest) -> bool {
+        pr.has_failing_required_ci()
+    }
+
+    fn needs_required_checks(&self) -> bool {
+        true
+    }
+}
+
+simple_post_filter!(pub ConflictingPost, |pr: &PullRequest| {
+    pr.is_conflicting()
+});
+
+simple_post_filter!(pub MergeablePost, |pr: &PullRequest| {
+    pr.mergeable == Mergeable::Mergeable
+});
+
 #[derive(Debug, Clone, Default)]
 pub struct AuthorPost {
     author: Option<String>,
@@ -116,10 +138,248 @@ single_post_filter!(pub TitlePost, title, |pr: &PullRequest, pattern: &str| {
         .unwrap_or(false)
 });
 
+single_post_filter!(pub HeadBranchPost, head, |pr: &PullRequest, pattern: &str| {
+    regex::Regex::new(pattern)
+        .map(|re| re.is_match(&pr.head_branch))
+        .unwrap_or(false)
+});
+
+simple_post_filter!(pub FromForkPost, |pr: &PullRequest| {
+    pr.is_cross_repository
+});
+
+simple_post_filter!(pub SameRepoPost, |pr: &PullRequest| {
+    !pr.is_cross_repository
+});
+
 single_post_filter!(pub BaseBranchPost, base, |pr: &PullRequest, branch: &str| {
     pr.matches_base_branch(branch)
 });
 
+#[derive(Debug, Clone, Copy)]
+pub struct ReviewDecisionPost {
+    pub decision: ReviewDecision,
+}
+
+impl PostFilter for ReviewDecisionPost {
+    fn matches(&self, pr: &PullRequest) -> bool {
+        pr.review_decision == Some(self.decision)
+    }
+}
+
+multi_post_filter!(
+    pub ReviewedByPost,
+    reviewers,
+    |reviewers: &[String], pr: &PullRequest| { reviewers.iter().all(|r| pr.reviewed_by(r)) }
+);
+
+multi_post_filter!(
+    pub CommentedByPost,
+    users,
+    |users: &[String], pr: &PullRequest| { users.iter().all(|u| pr.commented_by(u)) }
+);
+
+single_post_filter!(pub LastCommentByPost, user, |pr: &PullRequest, user: &str| {
+    pr.recent_comments
+        .last()
+        .is_some_and(|comment| comment.is_by(user))
+});
+
+/// Matches PRs changing at least one path that matches one of
+/// `patterns`. A PR whose paths were not fetched never matches.
+#[derive(Debug, Clone)]
+pub struct TouchesPost {
+    pub patterns: Vec<String>,
+}
+
+impl PostFilter for TouchesPost {
+    fn matches(&self, pr: &PullRequest) -> bool {
+        pr.changed_paths.as_ref().is_some_and(|paths| {
+            paths
+                .iter()
+                .any(|path| self.patterns.iter().any(|p| path_glob_matches(p, path)))
+        })
+    }
+
+    fn needs_changed_paths(&self) -> bool {
+        true
+    }
+}
+
+/// Matches PRs changing no path that matches any of `patterns`. A PR
+/// whose paths were not fetched never matches.
+#[derive(Debug, Clone)]
+pub struct NotTouchesPost {
+    pub patterns: Vec<String>,
+}
+
+impl PostFilter for NotTouchesPost {
+    fn matches(&self, pr: &PullRequest) -> bool {
+        pr.changed_paths.as_ref().is_some_and(|paths| {
+            !paths
+                .iter()
+                .any(|path| self.patterns.iter().any(|p| path_glob_matches(p, path)))
+        })
+    }
+
+    fn needs_changed_paths(&self) -> bool {
+        true
+    }
+}
+
+/// Matches PRs with at least one commit whose signature GitHub or
+/// GitLab could not verify, signed or not. A PR whose commits were not
+/// fetched never matches.
+#[derive(Debug, Clone, Copy)]
+pub struct UnsignedCommitsPost;
+
+impl PostFilter for UnsignedCommitsPost {
+    fn matches(&self, pr: &PullRequest) -> bool {
+        pr.commits.as_ref().is_some_and(|commits| {
+            commits
+                .iter()
+                .any(|commit| commit.signature != SignatureState::Verified)
+        })
+    }
+
+    fn needs_commits(&self) -> bool {
+        true
+    }
+}
+
+/// Matches PRs with at least one commit lacking a `Signed-off-by:`
+/// trailer. A PR whose commits were not fetched never matches.
+#[derive(Debug, Clone, Copy)]
+pub struct MissingSignoffPost;
+
+impl PostFilter for MissingSignoffPost {
+    fn matches(&self, pr: &PullRequest) -> bool {
+        pr.commits
+            .as_ref()
+            .is_some_and(|commits| commits.iter().any(|commit| !commit.is_signed_off()))
+    }
+
+    fn needs_commits(&self) -> bool {
+        true
+    }
+}
+
+/// Matches PRs that close an issue or name an issue key matching `key`;
+/// with no `key`, only closed issues count. A PR naming no such key,
+/// whose closed issues the forge does not report, never matches.
+#[derive(Debug, Clone, Default)]
+pub struct LinkedIssuePost {
+    pub key: Option<regex::Regex>,
+}
+
+impl PostFilter for LinkedIssuePost {
+    fn matches(&self, pr: &PullRequest) -> bool {
+        pr.references_issue(self.key.as_ref()) == Some(true)
+    }
+}
+
+/// Matches PRs that neither close an issue nor name an issue key,
+/// counting keys as [`LinkedIssuePost`] does. Likewise never matches
+/// when the forge does not report closed issues.
+#[derive(Debug, Clone, Default)]
+pub struct NoLinkedIssuePost {
+    pub key: Option<regex::Regex>,
+}
+
+impl PostFilter for NoLinkedIssuePost {
+    fn matches(&self, pr: &PullRequest) -> bool {
+        pr.references_issue(self.key.as_ref()) == Some(false)
+    }
+}
+
+/// Matches PRs naming an issue key that matches `pattern`.
+#[derive(Debug, Clone)]
+pub struct IssueKeyPost {
+    pub pattern: regex::Regex,
+}
+
+impl PostFilter for IssueKeyPost {
+    fn matches(&self, pr: &PullRequest) -> bool {
+        pr.issue_keys.iter().any(|key| self.pattern.is_match(key))
+    }
+}
+
+/// Matches PRs with at least one commit whose full message matches
+/// `pattern`. A PR whose commits were not fetched never matches.
+#[derive(Debug, Clone)]
+pub struct CommitMessagePost {
+    pub pattern: regex::Regex,
+}
+
+impl PostFilter for CommitMessagePost {
+    fn matches(&self, pr: &PullRequest) -> bool {
+        pr.commits.as_ref().is_some_and(|commits| {
+            commits
+                .iter()
+                .any(|commit| self.pattern.is_match(&commit.message))
+        })
+    }
+
+    fn needs_commits(&self) -> bool {
+        true
+    }
+}
+
+/// Matches a repository path against a glob. Within one path segment
+/// `*` matches any run of characters and `?` any one; a `**` segment
+/// matches any number of whole segments, including none.
+fn path_glob_matches(pattern: &str, path: &str) -> bool {
+    fn segments(pattern: &[&str], path: &[&str]) -> bool {
+        match pattern.split_first() {
+            None => path.is_empty(),
+            Some((&"**", rest)) => (0..=path.len()).any(|skip| segments(rest, &path[skip..])),
+            Some((first, rest)) => path.split_first().is_some_and(|(name, path)| {
+                segment(first.as_bytes(), name.as_bytes()) && segments(rest, path)
+            }),
+        }
+    }
+
+    fn segment(pattern: &[u8], name: &[u8]) -> bool {
+        match pattern.split_first() {
+            None => name.is_empty(),
+            Some((b'*', rest)) => (0..=name.len()).any(|skip| segment(rest, &name[skip..])),
+            Some((&c, rest)) => name
+                .split_first()
+                .is_some_and(|(&n, name)| (c == b'?' || c == n) && segment(rest, name)),
+        }
+    }
+
+    let pattern: Vec<&str> = pattern.trim_matches('/').split('/').collect();
+    let path: Vec<&str> = path.split('/').collect();
+    segments(&pattern, &path)
+}
+
+/// Matches PRs whose head commit was pushed before `cutoff`. A PR
+/// whose push time the forge does not report never matches.
+#[derive(Debug, Clone, Copy)]
+pub struct IdleSincePushPost {
+    pub cutoff: DateTime<Utc>,
+}
+
+impl PostFilter for IdleSincePushPost {
+    fn matches(&self, pr: &PullRequest) -> bool {
+        pr.last_pushed_at.is_some_and(|pushed| pushed < self.cutoff)
+    }
+}
+
+/// Matches PRs with a check that started before `cutoff` and is still
+/// queued or running. Checks without a start time never match.
+#[derive(Debug, Clone, Copy)]
+pub struct StuckCheckPost {
+    pub cutoff: DateTime<Utc>,
+}
+
+impl PostFilter for StuckCheckPost {
+    fn matches(&self, pr: &PullRequest) -> bool {
+        pr.has_check_pending_since(self.cutoff)
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 enum CommitOp {
     Eq,
@@ -138,9 +398,14 @@ pub struct CommitExpr {
 
 impl CommitExpr {
     pub fn parse(s: &str) -> Result<Self> {
+        Self::parse_for("--commits", s)
+    }
+
+    /// Parse a comparison, naming `flag` in any error.
+    fn parse_for(flag: &str, s: &str) -> Result<Self> {
         let s = s.trim();
         if s.is_empty() {
-            anyhow::bail!("Empty --commits expression");
+            anyhow::bail!("Empty {flag} expression");
         }
 
         let (op, rest) = if let Some(r) = s.strip_prefix(">=") {
@@ -162,7 +427,7 @@ impl CommitExpr {
         let value: u64 = rest
             .trim()
             .parse()
-            .with_context(|| format!("Invalid --commits expression '{s}'"))?;
+            .with_context(|| format!("Invalid {flag} expression '{s}'"))?;
 
         Ok(Self { op, value })
     }
@@ -190,12 +455,81 @@ impl PostFilter for CommitsPost {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SizeMetric {
+    Lines,
+    Files,
+    Additions,
+    Deletions,
+}
+
+/// A `--size` expression: a metric name followed by a comparison in
+/// the [`CommitExpr`] grammar, such as `lines<50` or `files<=3`.
+#[derive(Debug, Clone, Copy)]
+pub struct SizeExpr {
+    metric: SizeMetric,
+    expr: CommitExpr,
+}
+
+impl SizeExpr {
+    pub fn parse(s: &str) -> Result<Self> {
+        let s = s.trim();
+        let (name, comparison) = s.split_at(
+            s.find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(s.len()),
+        );
+
+        let metric = match name {
+            "lines" => SizeMetric::Lines,
+            "files" => SizeMetric::Files,
+            "additions" => SizeMetric::Additions,
+            "deletions" => SizeMetric::Deletions,
+            _ => anyhow::bail!(
+                "Invalid --size expression '{s}'. Start with lines, files, additions, or deletions, e.g. 'lines<50'"
+            ),
+        };
+
+        Ok(Self {
+            metric,
+            expr: CommitExpr::parse_for("--size", comparison)?,
+        })
+    }
+
+    /// False when the forge did not report the size, whatever the
+    /// comparison.
+    fn matches(self, pr: &PullRequest) -> bool {
+        let value = match self.metric {
+            SizeMetric::Lines => pr.changed_lines(),
+            SizeMetric::Files => pr.changed_files,
+            SizeMetric::Additions => pr.additions,
+            SizeMetric::Deletions => pr.deletions,
+        };
+        value.is_some_and(|value| self.expr.matches(value))
+    }
+}
+
+/// Matches PRs satisfying every `--size` expression.
+#[derive(Debug)]
+pub struct SizePost {
+    pub exprs: Vec<SizeExpr>,
+}
+
+impl PostFilter for SizePost {
+    fn matches(&self, pr: &PullRequest) -> bool {
+        self.exprs.iter().all(|expr| expr.matches(pr))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::{TimeZone, Utc};
 
     use super::*;
-    use crate::types::{PrState, PullRequest, Repo};
+    use crate::types::{
+        CheckConclusion, CheckInfo, CheckName, CheckRunStatus, CheckState, CommentAuthor,
+        CommentInfo, CommitInfo, LinkedIssue, MergeState, PrState, PullRequest, Repo, ReviewInfo,
+        ReviewState,
+    };
 
     fn pr(labels: &[&str], base_branch: &str, commit_count: u64) -> PullRequest {
         PullRequest {
@@ -207,15 +541,337 @@ mod tests {
             url: "https://github.com/owner/repo/pull/123".to_string(),
             labels: labels.iter().map(|label| label.to_string()).collect(),
             created_at: Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap(),
+            updated_at: Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap(),
+            last_pushed_at: None,
             base_branch: base_branch.to_string(),
             commit_count,
+            additions: None,
+            deletions: None,
+            changed_files: None,
+            changed_paths: None,
+            commits: None,
+            linked_issues: None,
+            issue_keys: vec![],
             is_draft: false,
             state: PrState::Open,
             checks: vec![],
             recent_comments: vec![],
+            comment_history_complete: true,
+            review_decision: None,
+            reviews: vec![],
+            requested_reviewers: vec![],
+            assignees: vec![],
+            mergeable: Mergeable::Unknown,
+            merge_state: MergeState::Unknown,
+            head_branch: "feature".to_string(),
+            head_repo_owner: None,
+            head_sha: String::new(),
+            is_cross_repository: false,
         }
     }
 
+    #[test]
+    fn head_filters_match_branch_and_origin() {
+        let mut dependabot = pr(&[], "main", 1);
+        dependabot.head_branch = "dependabot/cargo/serde-1.0.200".to_string();
+        let mut contributor = pr(&[], "main", 1);
+        contributor.head_branch = "fix-leak".to_string();
+        contributor.is_cross_repository = true;
+
+        let head = HeadBranchPost::new().with_value("^dependabot/");
+        assert!(head.matches(&dependabot));
+        assert!(!head.matches(&contributor));
+
+        assert!(FromForkPost.matches(&contributor));
+        assert!(!FromForkPost.matches(&dependabot));
+        assert!(SameRepoPost.matches(&dependabot));
+        assert!(!SameRepoPost.matches(&contributor));
+    }
+
+    #[test]
+    fn mergeability_filters_leave_unknown_prs_out() {
+        let with = |mergeable: Mergeable, merge_state: MergeState| {
+            let mut pr = pr(&[], "main", 1);
+            pr.mergeable = mergeable;
+            pr.merge_state = merge_state;
+            pr
+        };
+        let clean = with(Mergeable::Mergeable, MergeState::Clean);
+        let conflicting = with(Mergeable::Conflicting, MergeState::Dirty);
+        let unknown = with(Mergeable::Unknown, MergeState::Unknown);
+
+        assert!(ConflictingPost.matches(&conflicting));
+        assert!(!ConflictingPost.matches(&clean));
+        assert!(!ConflictingPost.matches(&unknown));
+        assert!(MergeablePost.matches(&clean));
+        assert!(!MergeablePost.matches(&conflicting));
+        assert!(!MergeablePost.matches(&unknown));
+    }
+
+    #[test]
+    fn review_filters_match_decision_and_submitted_reviews() {
+        let mut reviewed = pr(&[], "main", 1);
+        reviewed.review_decision = Some(ReviewDecision::Approved);
+        reviewed.reviews = vec![
+            ReviewInfo {
+                reviewer: "bob".to_string(),
+                state: ReviewState::Approved,
+            },
+            ReviewInfo {
+                reviewer: "carol".to_string(),
+                state: ReviewState::Pending,
+            },
+        ];
+        let unreviewed = pr(&[], "main", 1);
+
+        let approved = ReviewDecisionPost {
+            decision: ReviewDecision::Approved,
+        };
+        assert!(approved.matches(&reviewed));
+        assert!(!approved.matches(&unreviewed));
+
+        let by = |names: &[&str]| ReviewedByPost {
+            reviewers: names.iter().map(|name| name.to_string()).collect(),
+        };
+        assert!(by(&["bob"]).matches(&reviewed));
+        assert!(
+            !by(&["carol"]).matches(&reviewed),
+            "pending reviews count for nothing"
+        );
+        assert!(!by(&["bob", "dave"]).matches(&reviewed));
+        assert!(!by(&["bob"]).matches(&unreviewed));
+    }
+
+    #[test]
+    fn comment_author_filters_look_at_fetched_comments() {
+        let comment = |login: &str| CommentInfo {
+            body: "ping".to_string(),
+            created_at: Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap(),
+            author: Some(CommentAuthor {
+                login: login.to_string(),
+                is_bot: false,
+            }),
+        };
+        let mut discussed = pr(&[], "main", 1);
+        discussed.recent_comments = vec![comment("bob"), comment("carol")];
+        let quiet = pr(&[], "main", 1);
+
+        let by = |names: &[&str]| CommentedByPost {
+            users: names.iter().map(|name| name.to_string()).collect(),
+        };
+        assert!(by(&["bob"]).matches(&discussed));
+        assert!(by(&["bob", "carol"]).matches(&discussed));
+        assert!(!by(&["bob", "dave"]).matches(&discussed));
+        assert!(!by(&["bob"]).matches(&quiet));
+
+        let last = |name: &str| LastCommentByPost::new().with_value(name);
+        assert!(last("carol").matches(&discussed));
+        assert!(!last("bob").matches(&discussed));
+        assert!(!last("carol").matches(&quiet));
+    }
+
+    #[test]
+    fn commit_filters_look_at_every_fetched_commit() {
+        let commit = |message: &str, signature: SignatureState| CommitInfo {
+            sha: "0123456".to_string(),
+            headline: message.lines().next().unwrap_or_default().to_string(),
+            message: message.to_string(),
+            author: Some("alice".to_string()),
+            committer: Some("alice".to_string()),
+            signature,
+        };
+        let with_commits = |commits: Vec<CommitInfo>| {
+            let mut pr = pr(&[], "main", commits.len() as u64);
+            pr.commits = Some(commits);
+            pr
+        };
+        let clean = with_commits(vec![commit(
+            "Fix leak\n\nSigned-off-by: Alice <alice@example.com>",
+            SignatureState::Verified,
+        )]);
+        let sloppy = with_commits(vec![
+            commit(
+                "Fix leak\n\nsigned-off-by: Alice <alice@example.com>",
+                SignatureState::Verified,
+            ),
+            commit("WIP", SignatureState::Unverified),
+        ]);
+        let unfetched = pr(&[], "main", 1);
+
+        assert!(!UnsignedCommitsPost.matches(&clean));
+        assert!(UnsignedCommitsPost.matches(&sloppy));
+        assert!(!UnsignedCommitsPost.matches(&unfetched));
+
+        assert!(!MissingSignoffPost.matches(&clean));
+        assert!(MissingSignoffPost.matches(&sloppy));
+        assert!(!MissingSignoffPost.matches(&unfetched));
+
+        let message = CommitMessagePost {
+            pattern: regex::Regex::new("^WIP").unwrap(),
+        };
+        assert!(message.matches(&sloppy));
+        assert!(!message.matches(&clean));
+        assert!(!message.matches(&unfetched));
+
+        assert!(UnsignedCommitsPost.needs_commits() && MissingSignoffPost.needs_commits());
+        assert!(message.needs_commits() && !message.needs_changed_paths());
+        assert!(!FailingCiPost.needs_commits());
+    }
+
+    #[test]
+    fn issue_filters_count_closed_issues_and_keys() {
+        let with_issues = |linked: Option<Vec<LinkedIssue>>, keys: &[&str]| {
+            let mut pr = pr(&[], "release-4.16", 1);
+            pr.linked_issues = linked;
+            pr.issue_keys = keys.iter().map(|key| key.to_string()).collect();
+            pr
+        };
+        let closes = with_issues(
+            Some(vec![LinkedIssue {
+                number: 7,
+                title: "Crash on start".to_string(),
+                url: "https://github.com/owner/repo/issues/7".to_string(),
+            }]),
+            &[],
+        );
+        let keyed = with_issues(None, &["OCPBUGS-12345"]);
+        let bare = with_issues(Some(vec![]), &[]);
+        let unknown = with_issues(None, &[]);
+        let linked = LinkedIssuePost::default();
+        let unlinked = NoLinkedIssuePost::default();
+
+        // Without --issue-key no key counts, only closed issues.
+        assert!(linked.matches(&closes) && !unlinked.matches(&closes));
+        assert!(!linked.matches(&bare) && unlinked.matches(&bare));
+        for pr in [&keyed, &unknown] {
+            assert!(!linked.matches(pr) && !unlinked.matches(pr));
+        }
+
+        let ocpbugs = IssueKeyPost {
+            pattern: regex::Regex::new("^OCPBUGS-").unwrap(),
+        };
+        assert!(ocpbugs.matches(&keyed));
+        assert!(!ocpbugs.matches(&with_issues(None, &["JIRA-1"])));
+        assert!(!ocpbugs.matches(&closes));
+
+        // Only keys matching --issue-key count, so an encoding name in
+        // the description does not pass for a bug reference.
+        let encoding = with_issues(Some(vec![]), &["UTF-8"]);
+        let linked = LinkedIssuePost {
+            key: Some(ocpbugs.pattern.clone()),
+        };
+        let unlinked = NoLinkedIssuePost {
+            key: Some(ocpbugs.pattern.clone()),
+        };
+        assert!(!linked.matches(&encoding) && unlinked.matches(&encoding));
+        assert!(linked.matches(&keyed) && linked.matches(&closes));
+    }
+
+    #[test]
+    fn path_globs_match_within_and_across_segments() {
+        assert!(path_glob_matches("api/**", "api/v1/types.go"));
+        assert!(path_glob_matches("api/**", "api/doc.go"));
+        assert!(!path_glob_matches("api/**", "apis/doc.go"));
+        assert!(!path_glob_matches("api/**", "pkg/api/doc.go"));
+        assert!(path_glob_matches("**/*.md", "README.md"));
+        assert!(path_glob_matches("**/*.md", "docs/dev/guide.md"));
+        assert!(path_glob_matches(
+            "pkg/*/zz_generated.*",
+            "pkg/apis/zz_generated.deepcopy.go"
+        ));
+        assert!(!path_glob_matches(
+            "pkg/*/zz_generated.*",
+            "pkg/a/b/zz_generated.go"
+        ));
+        assert!(path_glob_matches("go.???", "go.mod"));
+        assert!(path_glob_matches("Makefile", "Makefile"));
+        assert!(!path_glob_matches("Makefile", "hack/Makefile"));
+    }
+
+    #[test]
+    fn touches_filters_need_fetched_paths() {
+        let with_paths = |paths: Option<&[&str]>| {
+            let mut pr = pr(&[], "main", 1);
+            pr.changed_paths = paths.map(|paths| paths.iter().map(|p| p.to_string()).collect());
+            pr
+        };
+        let api = with_paths(Some(&["api/v1/types.go", "README.md"]));
+        let vendored = with_paths(Some(&["vendor/modules.txt", "go.mod"]));
+        let unfetched = with_paths(None);
+
+        let touches = TouchesPost {
+            patterns: vec!["api/**".to_string()],
+        };
+        assert!(touches.matches(&api));
+        assert!(!touches.matches(&vendored));
+        assert!(!touches.matches(&unfetched));
+
+        let not_touches = NotTouchesPost {
+            patterns: vec!["vendor/**".to_string()],
+        };
+        assert!(not_touches.matches(&api));
+        assert!(!not_touches.matches(&vendored));
+        assert!(!not_touches.matches(&unfetched));
+
+        assert!(touches.needs_changed_paths() && not_touches.needs_changed_paths());
+        assert!(!FailingCiPost.needs_changed_paths());
+    }
+
+    #[test]
+    fn idle_since_push_needs_a_push_before_the_cutoff() {
+        let cutoff = Utc.with_ymd_and_hms(2026, 6, 1, 0, 0, 0).unwrap();
+        let filter = IdleSincePushPost { cutoff };
+        let pushed_at = |pushed| {
+            let mut pr = pr(&[], "main", 1);
+            pr.last_pushed_at = pushed;
+            pr
+        };
+
+        assert!(filter.matches(&pushed_at(Some(cutoff - chrono::TimeDelta::days(1)))));
+        assert!(!filter.matches(&pushed_at(Some(cutoff))));
+        assert!(!filter.matches(&pushed_at(None)));
+    }
+
+    #[test]
+    fn stuck_check_needs_a_pending_check_started_before_the_cutoff() {
+        let cutoff = Utc.with_ymd_and_hms(2026, 6, 1, 0, 0, 0).unwrap();
+        let filter = StuckCheckPost { cutoff };
+        let early = cutoff - chrono::TimeDelta::hours(6);
+        let with_check = |run_status, status_state, started_at| {
+            let mut pr = pr(&[], "main", 1);
+            pr.checks = vec![CheckInfo {
+                name: CheckName::new("e2e").unwrap(),
+                conclusion: None,
+                run_status,
+                status_state,
+                url: None,
+                required: false,
+                started_at,
+                completed_at: None,
+            }];
+            pr
+        };
+
+        assert!(filter.matches(&with_check(
+            Some(CheckRunStatus::InProgress),
+            None,
+            Some(early)
+        )));
+        assert!(filter.matches(&with_check(Some(CheckRunStatus::Queued), None, Some(early))));
+        assert!(filter.matches(&with_check(None, Some(CheckState::Pending), Some(early))));
+        assert!(!filter.matches(&with_check(
+            Some(CheckRunStatus::InProgress),
+            None,
+            Some(cutoff)
+        )));
+        assert!(!filter.matches(&with_check(Some(CheckRunStatus::InProgress), None, None)));
+
+        let mut finished = with_check(Some(CheckRunStatus::Completed), None, Some(early));
+        finished.checks[0].conclusion = Some(CheckConclusion::Success);
+        finished.checks[0].completed_at = Some(early + chrono::TimeDelta::minutes(20));
+        assert!(!filter.matches(&finished));
+    }
+
     #[test]
     fn commits_post_filter_matches_parsed_expression() {
         let filter = CommitsPost {
@@ -226,6 +882,44 @@ mod tests {
         assert!(!filter.matches(&pr(&[], "main", 1)));
     }
 
+    #[test]
+    fn size_post_filter_matches_every_expression() {
+        let mut small = pr(&[], "main", 1);
+        small.additions = Some(30);
+        small.deletions = Some(10);
+        small.changed_files = Some(2);
+        let mut large = small.clone();
+        large.additions = Some(300);
+        large.changed_files = Some(12);
+        let unknown = pr(&[], "main", 1);
+
+        let size = |exprs: &[&str]| SizePost {
+            exprs: exprs
+                .iter()
+                .map(|expr| SizeExpr::parse(expr).unwrap())
+                .collect(),
+        };
+        assert!(size(&["lines<50"]).matches(&small));
+        assert!(!size(&["lines<40"]).matches(&small));
+        assert!(!size(&["lines<50"]).matches(&large));
+        assert!(size(&["files<=3", "deletions=10"]).matches(&small));
+        assert!(!size(&["files<=3", "additions>100"]).matches(&large));
+        assert!(size(&["additions>100"]).matches(&large));
+        assert!(!size(&["lines<50"]).matches(&unknown));
+        assert!(!size(&["lines>=0"]).matches(&unknown));
+    }
+
+    #[test]
+    fn size_expr_rejects_unknown_metrics_and_bad_comparisons() {
+        for bad in ["", "50", "<50", "size<50", "lines", "lines<", "lines<x"] {
+            let err = SizeExpr::parse(bad).unwrap_err();
+            assert!(
+                format!("{err:#}").contains("--size"),
+                "{bad:?} gave: {err:#}"
+            );
+        }
+    }
+
     #[test]
     fn commit_expr_matches_pins_every_operator_at_its_boundary() {
         // Around the value 3, exercise below/at/above for each operator
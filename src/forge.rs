@@ -0,0 +1,403 @@
+//! Routing of fetch plans to the forge that hosts each pull request.
+//!
+//! PR URLs name their host, so a single invocation can mix github.com,
+//! GitLab, and GitHub Enterprise pull requests. [`ForgeRegistry`] maps
+//! hosts to [`Forge`] implementations and is itself a [`Forge`], so the
+//! query pipeline does not need to know how many forges are in play.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use tracing::debug;
+
+use crate::{
+    pr_selector::PrIdentifier,
+    search::{FetchOptions, FetchPlan},
+    types::{FetchOutcome, Forge, PullRequest},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ForgeRegistryError {
+    UnknownHost {
+        host: String,
+        identifier: String,
+        configured: Vec<String>,
+    },
+}
+
+impl std::fmt::Display for ForgeRegistryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ForgeRegistryError::UnknownHost {
+                host,
+                identifier,
+                configured,
+            } => write!(
+                f,
+                "No forge is configured for host '{host}' (needed for {identifier}); configured hosts: {}",
+                configured.join(", ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ForgeRegistryError {}
+
+/// A set of forges keyed by host, with one default.
+///
+/// Specific pull requests are split by the host in their URL and each
+/// group is fetched from the matching forge; identifiers without a host
+/// (bare PR numbers) go to the default forge. Repository and free-text
+/// searches carry no host and always go to the default forge.
+pub struct ForgeRegistry {
+    default_host: String,
+    forges: Vec<(String, Box<dyn Forge + Send + Sync>)>,
+}
+
+impl ForgeRegistry {
+    /// Creates a registry whose default forge serves `default_host`.
+    pub fn new(default_host: &str, forge: impl Forge + Send + Sync + 'static) -> Self {
+        Self {
+            default_host: normalise_host(default_host),
+            forges: Vec::new(),
+        }
+        .with_forge(default_host, forge)
+    }
+
+    /// Registers `forge` for `host`, replacing any earlier registration.
+    pub fn with_forge(mut self, host: &str, forge: impl Forge + Send + Sync + 'static) -> Self {
+        let host = normalise_host(host);
+        self.forges.retain(|(registered, _)| *registered != host);
+        self.forges.push((host, Box::new(forge)));
+        self
+    }
+
+    /// Hosts with a registered forge, in registration order.
+    pub fn hosts(&self) -> impl Iterator<Item = &str> {
+        self.forges.iter().map(|(host, _)| host.as_str())
+    }
+
+    fn forge_for(&self, host: &str) -> Option<&(dyn Forge + Send + Sync)> {
+        self.forges
+            .iter()
+            .find(|(registered, _)| registered == host)
+            .map(|(_, forge)| forge.as_ref())
+    }
+
+    fn host_of(&self, identifier: &PrIdentifier) -> String {
+        identifier
+            .host
+            .as_deref()
+            .map_or_else(|| self.default_host.clone(), normalise_host)
+    }
+
+    /// Groups identifiers by host, in order of each host's first
+    /// appearance, rejecting any host without a registered forge.
+    fn group_by_host(
+        &self,
+        identifiers: &[PrIdentifier],
+    ) -> std::result::Result<Vec<(String, Vec<PrIdentifier>)>, ForgeRegistryError> {
+        let mut groups: Vec<(String, Vec<PrIdentifier>)> = Vec::new();
+
+        for identifier in identifiers {
+            let host = self.host_of(identifier);
+            if self.forge_for(&host).is_none() {
+                return Err(ForgeRegistryError::UnknownHost {
+                    host,
+                    identifier: format!("{}#{}", identifier.repo, identifier.number),
+                    configured: self.hosts().map(str::to_string).collect(),
+                });
+            }
+
+            match groups
+                .iter_mut()
+                .find(|(group_host, _)| *group_host == host)
+            {
+                Some((_, group)) => group.push(identifier.clone()),
+                None => groups.push((host, vec![identifier.clone()])),
+            }
+        }
+
+        Ok(groups)
+    }
+
+    async fn fetch_specific(
+        &self,
+        identifiers: &[PrIdentifier],
+        options: &FetchOptions,
+    ) -> Result<FetchOutcome> {
+        let groups = self.group_by_host(identifiers)?;
+
+        let mut fetched: Vec<(String, Vec<PullRequest>)> = Vec::with_capacity(groups.len());
+        let mut incomplete = Vec::new();
+        for (host, group) in groups {
+            debug!(host = %host, pr_count = group.len(), "Fetching PRs from forge");
+            let forge = self
+                .forge_for(&host)
+                .expect("group_by_host only yields registered hosts");
+            let outcome = forge
+                .fetch_pull_requests(&FetchPlan::SpecificPullRequests(group), options)
+                .await?;
+            incomplete.extend(outcome.incomplete);
+            fetched.push((host, outcome.pull_requests));
+        }
+
+        // Reassemble in the order the PRs were requested rather than
+        // grouped by forge. PRs a forge could not find are skipped, as
+        // each forge already does for a single host.
+        let mut all_prs = Vec::with_capacity(identifiers.len());
+        for identifier in identifiers {
+            let host = self.host_of(identifier);
+            let Some((_, prs)) = fetched
+                .iter_mut()
+                .find(|(fetched_host, _)| *fetched_host == host)
+            else {
+                continue;
+            };
+            if let Some(index) = prs
+                .iter()
+                .position(|pr| pr.repo == identifier.repo && pr.number == identifier.number)
+            {
+                all_prs.push(prs.remove(index));
+            }
+        }
+
+        Ok(FetchOutcome {
+            pull_requests: all_prs,
+            incomplete,
+        })
+    }
+}
+
+pub(crate) fn normalise_host(host: &str) -> String {
+    host.trim().trim_end_matches('/').to_ascii_lowercase()
+}
+
+#[async_trait]
+impl Forge for ForgeRegistry {
+    async fn fetch_pull_requests(
+        &self,
+        plan: &FetchPlan,
+        options: &FetchOptions,
+    ) -> Result<FetchOutcome> {
+        match plan {
+            FetchPlan::SpecificPullRequests(identifiers) => {
+                self.fetch_specific(identifiers, options).await
+            }
+            FetchPlan::UserSearch { .. }
+            | FetchPlan::RepositorySearches(_)
+            | FetchPlan::OrganisationSweep { .. } => {
+                let forge = self
+                    .forge_for(&self.default_host)
+                    .expect("the default forge is registered on construction");
+                forge.fetch_pull_requests(plan, options).await
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+    use crate::types::{MergeState, Mergeable, PrState, Repo};
+
+    /// Forge that records the plans it is given and answers specific
+    /// PR requests with every PR it was asked for except `missing`.
+    struct RecordingForge {
+        label: &'static str,
+        missing: Vec<u64>,
+        plans: Arc<Mutex<Vec<FetchPlan>>>,
+    }
+
+    impl RecordingForge {
+        fn new(label: &'static str) -> (Self, Arc<Mutex<Vec<FetchPlan>>>) {
+            let plans = Arc::new(Mutex::new(Vec::new()));
+            (
+                Self {
+                    label,
+                    missing: vec![],
+                    plans: Arc::clone(&plans),
+                },
+                plans,
+            )
+        }
+    }
+
+    #[async_trait]
+    impl Forge for RecordingForge {
+        async fn fetch_pull_requests(
+            &self,
+            plan: &FetchPlan,
+            _options: &FetchOptions,
+        ) -> Result<FetchOutcome> {
+            self.plans.lock().unwrap().push(plan.clone());
+            Ok(FetchOutcome::complete(match plan {
+                FetchPlan::SpecificPullRequests(identifiers) => identifiers
+                    .iter()
+                    .filter(|id| !self.missing.contains(&id.number))
+                    .map(|id| pr(&id.repo, id.number, self.label))
+                    .collect(),
+                FetchPlan::UserSearch { .. }
+                | FetchPlan::RepositorySearches(_)
+                | FetchPlan::OrganisationSweep { .. } => vec![],
+            }))
+        }
+    }
+
+    fn pr(repo: &Repo, number: u64, title: &str) -> PullRequest {
+        PullRequest {
+            repo: repo.clone(),
+            number,
+            title: title.to_string(),
+            author_login: "alice".to_string(),
+            author_simple_name: "alice".to_string(),
+            url: format!("https://example.com/{repo}/pull/{number}"),
+            labels: vec![],
+            created_at: Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap(),
+            updated_at: Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap(),
+            last_pushed_at: None,
+            base_branch: "main".to_string(),
+            commit_count: 1,
+            additions: None,
+            deletions: None,
+            changed_files: None,
+            changed_paths: None,
+            commits: None,
+            linked_issues: None,
+            issue_keys: vec![],
+            is_draft: false,
+            state: PrState::Open,
+            checks: vec![],
+            recent_comments: vec![],
+            comment_history_complete: true,
+            review_decision: None,
+            reviews: vec![],
+            requested_reviewers: vec![],
+            assignees: vec![],
+            mergeable: Mergeable::Unknown,
+            merge_state: MergeState::Unknown,
+            head_branch: "feature".to_string(),
+            head_repo_owner: None,
+            head_sha: String::new(),
+            is_cross_repository: false,
+        }
+    }
+
+    fn repo(name: &str) -> Repo {
+        Repo::new("owner", name).unwrap()
+    }
+
+    #[tokio::test]
+    async fn specific_prs_are_split_by_host_and_merged_in_request_order() {
+        let (github, github_plans) = RecordingForge::new("github");
+        let (gitlab, gitlab_plans) = RecordingForge::new("gitlab");
+        let registry = ForgeRegistry::new("github.com", github).with_forge("gitlab.com", gitlab);
+
+        let identifiers = vec![
+            PrIdentifier::new(repo("a"), 1).with_host("gitlab.com"),
+            PrIdentifier::new(repo("b"), 2),
+            PrIdentifier::new(repo("c"), 3).with_host("GitHub.com"),
+            PrIdentifier::new(repo("d"), 4).with_host("gitlab.com"),
+        ];
+
+        let prs = registry
+            .fetch_pull_requests(
+                &FetchPlan::SpecificPullRequests(identifiers.clone()),
+                &FetchOptions::default(),
+            )
+            .await
+            .unwrap()
+            .pull_requests;
+
+        assert_eq!(
+            prs.iter()
+                .map(|pr| (pr.number, pr.title.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(1, "gitlab"), (2, "github"), (3, "github"), (4, "gitlab")]
+        );
+        assert_eq!(
+            *github_plans.lock().unwrap(),
+            vec![FetchPlan::SpecificPullRequests(vec![
+                identifiers[1].clone(),
+                identifiers[2].clone(),
+            ])]
+        );
+        assert_eq!(
+            *gitlab_plans.lock().unwrap(),
+            vec![FetchPlan::SpecificPullRequests(vec![
+                identifiers[0].clone(),
+                identifiers[3].clone(),
+            ])]
+        );
+    }
+
+    #[tokio::test]
+    async fn prs_a_forge_cannot_find_are_skipped() {
+        let (mut github, _) = RecordingForge::new("github");
+        github.missing = vec![2];
+        let registry = ForgeRegistry::new("github.com", github);
+
+        let prs = registry
+            .fetch_pull_requests(
+                &FetchPlan::SpecificPullRequests(vec![
+                    PrIdentifier::new(repo("a"), 1),
+                    PrIdentifier::new(repo("a"), 2),
+                    PrIdentifier::new(repo("a"), 3),
+                ]),
+                &FetchOptions::default(),
+            )
+            .await
+            .unwrap()
+            .pull_requests;
+
+        assert_eq!(
+            prs.iter().map(|pr| pr.number).collect::<Vec<_>>(),
+            vec![1, 3]
+        );
+    }
+
+    #[tokio::test]
+    async fn unknown_host_is_rejected_before_any_fetch() {
+        let (github, github_plans) = RecordingForge::new("github");
+        let registry = ForgeRegistry::new("github.com", github);
+
+        let err = registry
+            .fetch_pull_requests(
+                &FetchPlan::SpecificPullRequests(vec![
+                    PrIdentifier::new(repo("a"), 1),
+                    PrIdentifier::new(repo("b"), 2).with_host("gitlab.example.com"),
+                ]),
+                &FetchOptions::default(),
+            )
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "No forge is configured for host 'gitlab.example.com' (needed for owner/b#2); configured hosts: github.com"
+        );
+        assert!(github_plans.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn searches_go_to_the_default_forge() {
+        let (github, github_plans) = RecordingForge::new("github");
+        let (gitlab, gitlab_plans) = RecordingForge::new("gitlab");
+        let registry = ForgeRegistry::new("github.com", github).with_forge("gitlab.com", gitlab);
+        let plan = FetchPlan::UserSearch {
+            query: "author:alice".to_string(),
+            limit: 10,
+        };
+
+        registry
+            .fetch_pull_requests(&plan, &FetchOptions::default())
+            .await
+            .unwrap();
+
+        assert_eq!(*github_plans.lock().unwrap(), vec![plan]);
+        assert!(gitlab_plans.lock().unwrap().is_empty());
+    }
+}
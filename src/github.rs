@@ -6,26 +6,50 @@
 //! pure pieces it drives live in submodules: [`graphql`] (the wire
 //! protocol and query builder) and [`convert`] (wire -> domain). All
 //! three together are the GitHub adapter; only the domain model they
-//! produce is shared with other forges.
+//! produce is shared with other forges. [`recording`] lets GraphQL
+//! traffic be saved to disk and served back later without a network,
+//! and [`cache`] lets repeated runs reuse recent responses.
 
+mod cache;
 mod convert;
 mod graphql;
+mod recording;
 mod render;
 mod search;
 
+use std::{
+    collections::{HashSet, VecDeque},
+    path::PathBuf,
+};
+
 use anyhow::{Context, Result};
 use async_trait::async_trait;
-use convert::{convert_graphql_pr_to_pr_info, convert_graphql_pr_to_pr_info_with_url_parsing};
-use graphql::{GraphQLQueryBuilder, GraphQLResponse};
-use octocrab::Octocrab;
+use cache::{CACHE_TTL, Cache};
+use chrono::{DateTime, TimeDelta, Utc};
+use convert::{
+    convert_comments, convert_commits, convert_graphql_pr_to_pr_info,
+    convert_graphql_pr_to_pr_info_with_url_parsing, convert_labels, convert_status_checks,
+};
+use futures::{StreamExt, TryStreamExt, stream};
+use graphql::{
+    BatchResponse, CommentsPage, CommitsPage, CountResponse, FilesPage, GraphQLQueryBuilder,
+    GraphQLResponse, LabelsPage, OwnerRepositoriesResponse, PullRequestPageResponse,
+    StatusContextsPage,
+};
+use octocrab::{
+    DefaultOctocrabBuilderConfig, NoAuth, NoSvc, NotLayerReady, Octocrab, OctocrabBuilder,
+    models::{AppId, InstallationId, InstallationToken},
+};
+use recording::{Recorder, Replay};
 pub use render::GhCliRenderer;
-use serde::Deserialize;
-use tracing::{debug, error, info, instrument, warn};
+use search::{CreatedWindow, SEARCH_RESULT_CAP, SearchQuery};
+use serde::{Deserialize, de::DeserializeOwned};
+use tracing::{Level, debug, error, info, instrument, warn};
 
 use crate::{
     pr_selector::PrIdentifier,
-    search::FetchPlan,
-    types::{PullRequest, Repo},
+    search::{FetchOptions, FetchPlan, RepoSearch},
+    types::{CommitInfo, FetchOutcome, PullRequest, Repo},
 };
 
 #[derive(Debug, Deserialize)]
@@ -51,9 +75,10 @@ struct RateLimitResponse {
 /// Checks GitHub API rate limit status and logs the results.
 ///
 /// Queries all rate limit categories (core, search, GraphQL) and warns
-/// when limits are low. Returns core rate limit info for compatibility.
+/// when limits are low. Returns every category so callers can budget
+/// against the one they are about to spend.
 #[instrument(skip(octocrab), target = "autoprat::rate_limit")]
-async fn check_rate_limit(octocrab: &Octocrab, context: &str) -> Result<RateLimitInfo> {
+async fn check_rate_limit(octocrab: &Octocrab, context: &str) -> Result<RateLimitResources> {
     debug!(target: "autoprat::rate_limit", "Checking GitHub API rate limit");
 
     let rate_limit: RateLimitResponse =
@@ -148,77 +173,188 @@ async fn check_rate_limit(octocrab: &Octocrab, context: &str) -> Result<RateLimi
         );
     }
 
-    Ok(rate_limit.resources.core)
+    Ok(rate_limit.resources)
+}
+
+/// Where GraphQL queries are sent: to GitHub, optionally recording
+/// each exchange, or to a recording made earlier.
+///
+/// A live transport with a cache stores every response in it, but only
+/// serves from it when `serve_cached` is set.
+enum GraphQLTransport {
+    Live {
+        client: GraphQLClient,
+        recorder: Option<Recorder>,
+        cache: Option<Cache>,
+        serve_cached: bool,
+    },
+    Replay(Replay),
+}
+
+impl GraphQLTransport {
+    fn cache(&self) -> Option<&Cache> {
+        match self {
+            Self::Live { cache, .. } => cache.as_ref(),
+            Self::Replay(_) => None,
+        }
+    }
 }
 
 /// Helper function to execute GraphQL queries with enhanced error reporting
-#[instrument(skip(octocrab, query), fields(query_type = "search_prs"))]
-async fn execute_graphql_query(
-    octocrab: &Octocrab,
+#[instrument(skip(transport, query))]
+async fn execute_graphql_query<T: DeserializeOwned>(
+    transport: &GraphQLTransport,
     query: serde_json::Value,
     context: &str,
-) -> Result<GraphQLResponse> {
+) -> Result<T> {
     debug!("Executing GraphQL query");
 
-    octocrab.graphql(&query).await.map_err(|e| {
-        // Try to extract more specific error information.
-        let error_msg = match &e {
-            octocrab::Error::GitHub { source, .. } => {
-                format!("GitHub API error: {source}")
-            }
-            octocrab::Error::Serde { source, .. } => {
-                format!("JSON parsing error (likely rate limiting): {source}")
-            }
-            octocrab::Error::Http { source, .. } => {
-                format!("HTTP error: {source}")
-            }
-            _ => format!("Unknown error: {e}"),
-        };
+    // The cache a live response should be kept in, once it is known
+    // to be worth keeping.
+    let (response, cache_in): (serde_json::Value, _) = match transport {
+        GraphQLTransport::Live {
+            cache: Some(cache),
+            serve_cached: true,
+            ..
+        } if let Some(response) = cache.fresh(&graphql_cache_key(&query), Utc::now()) => {
+            debug!(context = context, "Serving GraphQL response from cache");
+            (response, None)
+        }
+        GraphQLTransport::Live {
+            client,
+            recorder,
+            cache,
+            ..
+        } => {
+            let response = client.graphql(&query).await.map_err(|e| {
+                // Try to extract more specific error information.
+                let error_msg = match &e {
+                    octocrab::Error::GitHub { source, .. } => {
+                        format!("GitHub API error: {source}")
+                    }
+                    octocrab::Error::Serde { source, .. } => {
+                        format!("JSON parsing error (likely rate limiting): {source}")
+                    }
+                    octocrab::Error::Http { source, .. } => {
+                        format!("HTTP error: {source}")
+                    }
+                    _ => format!("Unknown error: {e}"),
+                };
 
-        error!(
-            context = context,
-            error = %error_msg,
-            "GraphQL query execution failed"
-        );
+                error!(
+                    context = context,
+                    error = %error_msg,
+                    "GraphQL query execution failed"
+                );
+
+                // For JSON parsing errors, suggest checking rate limits.
+                if matches!(&e, octocrab::Error::Serde { .. }) {
+                    warn!("JSON parsing errors often indicate GitHub API rate limiting - check rate limit status above");
+                }
+
+                anyhow::anyhow!("{context}: {error_msg}")
+            })?;
 
-        // For JSON parsing errors, suggest checking rate limits.
-        if matches!(&e, octocrab::Error::Serde { .. }) {
-            warn!("JSON parsing errors often indicate GitHub API rate limiting - check rate limit status above");
+            if let Some(recorder) = recorder {
+                recorder.record(&query, &response)?;
+            }
+            (response, cache.as_ref())
         }
+        GraphQLTransport::Replay(replay) => (
+            replay
+                .respond(&query)
+                .with_context(|| format!("{context}: replay failed"))?,
+            None,
+        ),
+    };
 
-        anyhow::anyhow!("{context}: {error_msg}")
-    })
+    let parsed = T::deserialize(&response)
+        .map_err(|e| anyhow::anyhow!("{context}: JSON parsing error: {e}"))?;
+    // A rate limit or partial failure comes back as an `errors` array;
+    // serving that from disk would repeat it until the entry expired.
+    if let Some(cache) = cache_in
+        && response
+            .get("errors")
+            .and_then(|errors| errors.as_array())
+            .is_none_or(Vec::is_empty)
+    {
+        cache.put(&graphql_cache_key(&query), None, &response, Utc::now());
+    }
+    Ok(parsed)
+}
+
+/// Cache key for a GraphQL request: its query text and variables,
+/// which serialise in a fixed order.
+fn graphql_cache_key(query: &serde_json::Value) -> String {
+    format!("POST /graphql {query}")
+}
+
+/// The public GitHub host; every other host is GitHub Enterprise Server.
+pub const GITHUB_COM: &str = "github.com";
+
+/// REST and GraphQL API base URLs for a GitHub host.
+///
+/// github.com serves both APIs from api.github.com. GitHub Enterprise
+/// Server serves REST under `/api/v3` and GraphQL at `/api/graphql`;
+/// Octocrab appends `/graphql` itself, so the GraphQL base is `/api`.
+fn api_base_urls(host: &str) -> (String, String) {
+    if host == GITHUB_COM {
+        let api = "https://api.github.com".to_string();
+        (api.clone(), api)
+    } else {
+        (
+            format!("https://{host}/api/v3"),
+            format!("https://{host}/api"),
+        )
+    }
 }
 
 /// Obtains a GitHub authentication token from multiple sources.
 ///
-/// Attempts to retrieve a token in the following order:
+/// For github.com, attempts to retrieve a token in the following order:
 /// 1. GITHUB_TOKEN environment variable
 /// 2. GH_TOKEN environment variable
 /// 3. GitHub CLI (`gh auth token`)
 ///
+/// For a GitHub Enterprise Server host the order follows gh's own:
+/// 1. GH_ENTERPRISE_TOKEN environment variable
+/// 2. GITHUB_ENTERPRISE_TOKEN environment variable
+/// 3. GitHub CLI (`gh auth token --hostname HOST`)
+///
 /// Returns an error if no valid token can be obtained.
 #[instrument]
-async fn get_github_token() -> Result<String> {
-    if let Ok(token) = std::env::var("GITHUB_TOKEN") {
-        debug!("Using GITHUB_TOKEN environment variable");
-        return Ok(token);
-    }
+async fn get_github_token(host: &str) -> Result<String> {
+    let env_vars: &[&str] = if host == GITHUB_COM {
+        &["GITHUB_TOKEN", "GH_TOKEN"]
+    } else {
+        &["GH_ENTERPRISE_TOKEN", "GITHUB_ENTERPRISE_TOKEN"]
+    };
 
-    if let Ok(token) = std::env::var("GH_TOKEN") {
-        debug!("Using GH_TOKEN environment variable");
-        return Ok(token);
+    for var in env_vars {
+        if let Ok(token) = std::env::var(var) {
+            debug!("Using {var} environment variable");
+            return Ok(token);
+        }
     }
 
     debug!("Fetching token from gh CLI");
-    let output = tokio::process::Command::new("gh")
-        .args(["auth", "token"])
-        .output()
-        .await?;
+    let mut command = tokio::process::Command::new("gh");
+    command.args(["auth", "token"]);
+    if host != GITHUB_COM {
+        command.args(["--hostname", host]);
+    }
+    let output = command.output().await?;
 
     if !output.status.success() {
         error!("gh CLI authentication failed");
-        anyhow::bail!("Failed to get GitHub token from gh CLI. Please run 'gh auth login' first");
+        if host == GITHUB_COM {
+            anyhow::bail!(
+                "Failed to get GitHub token from gh CLI. Please run 'gh auth login' first"
+            );
+        }
+        anyhow::bail!(
+            "Failed to get GitHub token for {host} from gh CLI. Please run 'gh auth login --hostname {host}' or set GH_ENTERPRISE_TOKEN"
+        );
     }
 
     let token = String::from_utf8(output.stdout)?.trim().to_string();
@@ -232,97 +368,663 @@ async fn get_github_token() -> Result<String> {
     Ok(token)
 }
 
-/// Creates an authenticated GitHub API client.
+/// Environment variables naming a GitHub App installation to act as.
+const APP_ID_VAR: &str = "GITHUB_APP_ID";
+const APP_PRIVATE_KEY_VAR: &str = "GITHUB_APP_PRIVATE_KEY_PATH";
+const APP_INSTALLATION_ID_VAR: &str = "GITHUB_APP_INSTALLATION_ID";
+
+/// A GitHub App installation to authenticate as, for unattended runs
+/// where there is no user token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct AppCredentials {
+    app_id: u64,
+    private_key_path: PathBuf,
+    installation_id: u64,
+}
+
+impl AppCredentials {
+    /// Reads the credentials from `var`, normally the environment.
+    ///
+    /// Returns `None` when none of the variables is set. Setting only
+    /// some is an error rather than a fallback to a user token, which
+    /// would quietly act as someone else.
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Result<Option<Self>> {
+        let names = [APP_ID_VAR, APP_PRIVATE_KEY_VAR, APP_INSTALLATION_ID_VAR];
+        let values = names.map(|name| var(name).filter(|value| !value.trim().is_empty()));
+        if values.iter().all(Option::is_none) {
+            return Ok(None);
+        }
+
+        let [Some(app_id), Some(private_key_path), Some(installation_id)] = values else {
+            let missing = names
+                .iter()
+                .zip(&values)
+                .filter(|(_, value)| value.is_none())
+                .map(|(name, _)| *name)
+                .collect::<Vec<_>>();
+            anyhow::bail!(
+                "GitHub App authentication needs {}; {} not set",
+                names.join(", "),
+                missing.join(", ")
+            );
+        };
+
+        Ok(Some(Self {
+            app_id: parse_app_number(APP_ID_VAR, &app_id)?,
+            private_key_path: PathBuf::from(private_key_path),
+            installation_id: parse_app_number(APP_INSTALLATION_ID_VAR, &installation_id)?,
+        }))
+    }
+
+    fn load_key(&self) -> Result<jsonwebtoken::EncodingKey> {
+        let pem = std::fs::read(&self.private_key_path).with_context(|| {
+            format!(
+                "Failed to read GitHub App private key '{}'",
+                self.private_key_path.display()
+            )
+        })?;
+        jsonwebtoken::EncodingKey::from_rsa_pem(&pem).with_context(|| {
+            format!(
+                "Invalid GitHub App private key '{}'",
+                self.private_key_path.display()
+            )
+        })
+    }
+}
+
+fn parse_app_number(name: &str, value: &str) -> Result<u64> {
+    value
+        .trim()
+        .parse()
+        .with_context(|| format!("{name} must be a number, got '{value}'"))
+}
+
+/// How the clients for a host authenticate.
+enum ClientAuth {
+    Token(String),
+    /// Octocrab mints a JWT from the key, exchanges it for an
+    /// installation token, and exchanges a new one shortly before that
+    /// token expires.
+    App {
+        credentials: AppCredentials,
+        key: jsonwebtoken::EncodingKey,
+    },
+}
+
+impl ClientAuth {
+    /// Who the clients act as, as a key for per-identity state such as
+    /// the response cache. Only ever stored hashed.
+    fn identity(&self) -> String {
+        match self {
+            Self::Token(token) => token.clone(),
+            Self::App { credentials, .. } => format!(
+                "app {} installation {}",
+                credentials.app_id, credentials.installation_id
+            ),
+        }
+    }
+}
+
+/// Authenticated clients for one GitHub host.
+///
+/// Octocrab resolves GraphQL requests relative to its base URI, and on
+/// GitHub Enterprise Server the REST and GraphQL endpoints do not share
+/// a base, so each API gets its own client; see [`build_clients`]. On
+/// github.com they are the same client.
+struct GitHubClients {
+    rest: Octocrab,
+    graphql: GraphQLClient,
+    /// See [`ClientAuth::identity`].
+    identity: String,
+}
+
+/// Creates authenticated GitHub API clients for `host`.
 ///
-/// Retrieves a GitHub token and initialises an Octocrab client
-/// configured for API access.
+/// Authenticates as a GitHub App installation when its variables are
+/// set, and otherwise with a token for the host, then initialises
+/// Octocrab clients configured for its REST and GraphQL APIs.
 #[instrument]
-async fn setup_github_client() -> Result<Octocrab> {
-    let token = get_github_token()
-        .await
-        .context("Failed to obtain GitHub authentication token")?;
+async fn setup_github_client(host: &str) -> Result<GitHubClients> {
+    let auth = match AppCredentials::from_vars(|name| std::env::var(name).ok())? {
+        Some(credentials) => {
+            debug!(
+                app_id = credentials.app_id,
+                installation_id = credentials.installation_id,
+                "Authenticating as GitHub App installation"
+            );
+            ClientAuth::App {
+                key: credentials.load_key()?,
+                credentials,
+            }
+        }
+        None => ClientAuth::Token(
+            get_github_token(host)
+                .await
+                .context("Failed to obtain GitHub authentication token")?,
+        ),
+    };
     debug!("Creating GitHub client");
-    Octocrab::builder()
-        .personal_token(token)
+
+    let (rest_base, graphql_base) = api_base_urls(host);
+    let (rest, graphql) = build_clients(&rest_base, &graphql_base, &auth).await?;
+
+    Ok(GitHubClients {
+        rest,
+        graphql,
+        identity: auth.identity(),
+    })
+}
+
+/// Builds the REST and GraphQL clients for `auth`.
+///
+/// GitHub serves the installation token exchange under the REST base,
+/// so an App installation is only ever set up there. Octocrab's
+/// installation client exchanges a new token shortly before the last
+/// one expires, but only for requests under that base; on GitHub
+/// Enterprise Server, where GraphQL is not, an
+/// [`InstallationGraphQL`] client exchanges its own.
+async fn build_clients(
+    rest_base: &str,
+    graphql_base: &str,
+    auth: &ClientAuth,
+) -> Result<(Octocrab, GraphQLClient)> {
+    let shared = graphql_base == rest_base;
+    match auth {
+        ClientAuth::Token(token) => {
+            let rest = build_client(rest_base, Octocrab::builder().personal_token(token.clone()))?;
+            let graphql = if shared {
+                rest.clone()
+            } else {
+                build_client(
+                    graphql_base,
+                    Octocrab::builder().personal_token(token.clone()),
+                )?
+            };
+            Ok((rest, GraphQLClient::Octocrab(graphql)))
+        }
+        ClientAuth::App { credentials, key } => {
+            let app = build_client(
+                rest_base,
+                Octocrab::builder().app(AppId(credentials.app_id), key.clone()),
+            )?;
+            let installation = InstallationId(credentials.installation_id);
+            let rest = app
+                .installation(installation)
+                .context("Failed to create GitHub App installation client")?;
+            if shared {
+                return Ok((rest.clone(), GraphQLClient::Octocrab(rest)));
+            }
+
+            let graphql = InstallationGraphQL {
+                app,
+                installation,
+                graphql_base: graphql_base.to_string(),
+                current: tokio::sync::Mutex::new(None),
+            };
+            // Exchange the first token now, so bad credentials fail the
+            // setup rather than the first query.
+            graphql
+                .client()
+                .await
+                .context("Failed to obtain GitHub App installation token")?;
+            Ok((rest, GraphQLClient::Installation(Box::new(graphql))))
+        }
+    }
+}
+
+/// How long before an installation token expires a new one is
+/// exchanged for it.
+const INSTALLATION_TOKEN_MARGIN: TimeDelta = TimeDelta::minutes(5);
+
+/// Where GraphQL requests go for a live transport.
+enum GraphQLClient {
+    /// A client that authenticates every request itself.
+    Octocrab(Octocrab),
+    /// An App installation on a host whose GraphQL API is not under
+    /// its REST base.
+    Installation(Box<InstallationGraphQL>),
+}
+
+impl GraphQLClient {
+    async fn graphql(&self, query: &serde_json::Value) -> octocrab::Result<serde_json::Value> {
+        match self {
+            Self::Octocrab(octocrab) => octocrab.graphql(query).await,
+            Self::Installation(installation) => installation.client().await?.graphql(query).await,
+        }
+    }
+}
+
+/// GraphQL client for an App installation, holding a token exchanged
+/// on the REST base and exchanging a new one as it nears expiry, so a
+/// run that outlasts one token goes on working.
+struct InstallationGraphQL {
+    /// Authenticated as the App itself, on the REST base.
+    app: Octocrab,
+    installation: InstallationId,
+    graphql_base: String,
+    /// A client with the current token, and when that token expires.
+    current: tokio::sync::Mutex<Option<(Octocrab, Option<DateTime<Utc>>)>>,
+}
+
+impl InstallationGraphQL {
+    /// A client whose token has at least [`INSTALLATION_TOKEN_MARGIN`]
+    /// left, exchanging a new token when the current one does not.
+    async fn client(&self) -> octocrab::Result<Octocrab> {
+        let mut current = self.current.lock().await;
+        if let Some((client, expires_at)) = current.as_ref()
+            && expires_at
+                .is_none_or(|expires_at| expires_at - Utc::now() > INSTALLATION_TOKEN_MARGIN)
+        {
+            return Ok(client.clone());
+        }
+
+        debug!(installation = %self.installation, "Exchanging GitHub App installation token");
+        let token: InstallationToken = self
+            .app
+            .post(
+                format!("/app/installations/{}/access_tokens", self.installation),
+                None::<&()>,
+            )
+            .await?;
+        let expires_at = token
+            .expires_at
+            .as_deref()
+            .and_then(|expires_at| DateTime::parse_from_rfc3339(expires_at).ok())
+            .map(|expires_at| expires_at.with_timezone(&Utc));
+        let client = Octocrab::builder()
+            .personal_token(token.token)
+            .base_uri(&self.graphql_base)?
+            .build()?;
+        *current = Some((client.clone(), expires_at));
+        Ok(client)
+    }
+}
+
+/// An Octocrab builder with its authentication chosen.
+type ClientBuilder = OctocrabBuilder<NoSvc, DefaultOctocrabBuilderConfig, NoAuth, NotLayerReady>;
+
+fn build_client(base: &str, builder: ClientBuilder) -> Result<Octocrab> {
+    builder
+        .base_uri(base)
+        .with_context(|| format!("Invalid GitHub API URL '{base}'"))?
         .build()
         .context("Failed to create GitHub client")
 }
 
-/// Fetches a single pull request using a search query.
+/// Most pull requests requested in one batched query. Each brings its
+/// checks, labels, and comments, and GitHub bounds the nodes a single
+/// query may return.
+const PRS_PER_BATCH: usize = 50;
+
+/// Groups identifiers by repository, in first-seen order, and splits
+/// the groups into batches of at most [`PRS_PER_BATCH`] pull requests.
+/// Duplicates are requested once.
+fn plan_pr_batches(pr_identifiers: &[PrIdentifier]) -> Vec<Vec<(Repo, Vec<u64>)>> {
+    let mut by_repo: Vec<(Repo, Vec<u64>)> = Vec::new();
+    for identifier in pr_identifiers {
+        match by_repo
+            .iter_mut()
+            .find(|(repo, _)| *repo == identifier.repo)
+        {
+            Some((_, numbers)) if numbers.contains(&identifier.number) => {}
+            Some((_, numbers)) => numbers.push(identifier.number),
+            None => by_repo.push((identifier.repo.clone(), vec![identifier.number])),
+        }
+    }
+
+    let mut batches = Vec::new();
+    let mut batch: Vec<(Repo, Vec<u64>)> = Vec::new();
+    let mut batch_len = 0;
+    for (repo, numbers) in by_repo {
+        for chunk in numbers.chunks(PRS_PER_BATCH) {
+            if batch_len + chunk.len() > PRS_PER_BATCH {
+                batches.push(std::mem::take(&mut batch));
+                batch_len = 0;
+            }
+            batch_len += chunk.len();
+            batch.push((repo.clone(), chunk.to_vec()));
+        }
+    }
+    if !batch.is_empty() {
+        batches.push(batch);
+    }
+    batches
+}
+
+/// Fetches one batch of specific pull requests in a single query.
 ///
-/// Executes a GraphQL search expecting at most one result. Used for
-/// fetching specific PRs by number when the repo context is known.
-#[instrument(skip(octocrab), fields(query = %search_query, repo = %repo))]
-async fn fetch_single_pr_by_query(
-    octocrab: &Octocrab,
-    search_query: &str,
-    repo: Repo,
-) -> Result<Option<PullRequest>> {
-    debug!("Fetching single PR by query");
-    let query = GraphQLQueryBuilder::search_pull_requests()
-        .with_search_query(search_query)
-        .with_after_cursor(None)
-        .build();
+/// Pull requests GitHub cannot resolve, or that come back in a shape
+/// that cannot be read, are logged with the reason and left out; the
+/// caller works out which were missing.
+#[instrument(skip(transport, batch), fields(repo_count = batch.len()))]
+async fn fetch_pr_batch(
+    transport: &GraphQLTransport,
+    batch: &[(Repo, Vec<u64>)],
+    options: &FetchOptions,
+) -> Result<Vec<PullRequest>> {
+    let query = GraphQLQueryBuilder::pull_requests_by_number(batch).build();
+    debug!(query_variables = ?query.get("variables"), "Executing batched PR GraphQL query");
+
+    let context = format!(
+        "Batched PR query for {}",
+        batch
+            .iter()
+            .map(|(repo, numbers)| format!("{repo} ({} PRs)", numbers.len()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    let response: BatchResponse = execute_graphql_query(transport, query, &context).await?;
+
+    let Some(mut data) = response.data else {
+        let messages = response
+            .errors
+            .iter()
+            .map(|error| error.message.as_str())
+            .collect::<Vec<_>>()
+            .join("; ");
+        anyhow::bail!("{context}: GitHub API error: {messages}");
+    };
+
+    let mut prs = Vec::new();
+    for (i, (repo, numbers)) in batch.iter().enumerate() {
+        let repo_alias = graphql::repo_alias(i);
+        let reason = |path: &[&str]| {
+            response
+                .errors
+                .iter()
+                .find(|error| error.concerns(path))
+                .map_or("not returned", |error| error.message.as_str())
+                .to_string()
+        };
 
-    debug!(query_variables = ?query.get("variables"), "Executing single PR GraphQL query");
+        let Some(mut repo_prs) = data.remove(&repo_alias).flatten() else {
+            warn!(repo = %repo, reason = %reason(&[&repo_alias]), "Repository not found or not accessible");
+            continue;
+        };
 
-    let context = format!("Single PR query for repo {repo} with '{search_query}'");
-    let response = execute_graphql_query(octocrab, query, &context).await?;
+        for number in numbers {
+            let pr_alias = graphql::pr_alias(*number);
+            match repo_prs.remove(&pr_alias).flatten() {
+                Some(graphql_pr) => {
+                    let pending = PendingPages::of(&graphql_pr);
+                    // One malformed PR is reported like a missing one
+                    // rather than losing the rest of the batch.
+                    let mut pr = match convert_graphql_pr_to_pr_info(graphql_pr, repo.clone()) {
+                        Ok(pr) => pr,
+                        Err(error) => {
+                            warn!(repo = %repo, number = number, error = %error, "PR could not be read");
+                            continue;
+                        }
+                    };
+                    complete_pull_request(transport, &mut pr, pending, options).await?;
+                    prs.push(pr);
+                }
+                None => warn!(
+                    repo = %repo,
+                    number = number,
+                    reason = %reason(&[&repo_alias, &pr_alias]),
+                    "PR not found or not accessible"
+                ),
+            }
+        }
+    }
 
-    if let Some(graphql_pr) = response.data.search.nodes.into_iter().next() {
-        debug!(pr_number = graphql_pr.number, "Found PR");
-        Ok(Some(convert_graphql_pr_to_pr_info(graphql_pr, repo)?))
-    } else {
-        debug!("No PR found for query");
-        Ok(None)
+    Ok(prs)
+}
+
+/// Cursors for the connections a pull request's own query cut short.
+#[derive(Debug, Default)]
+struct PendingPages {
+    labels: Option<String>,
+    status_contexts: Option<String>,
+    older_comments: Option<String>,
+    /// What the base branch requires, to mark the checks on the status
+    /// context pages still to come.
+    required_checks: Vec<String>,
+}
+
+impl PendingPages {
+    fn of(graphql_pr: &graphql::GraphQLPullRequest) -> Self {
+        Self {
+            labels: graphql_pr.labels.next_cursor(),
+            status_contexts: graphql_pr
+                .status_check_rollup
+                .as_ref()
+                .and_then(|rollup| rollup.contexts.next_cursor()),
+            older_comments: graphql_pr.comments.older_cursor(),
+            required_checks: graphql_pr.required_checks(),
+        }
+    }
+}
+
+/// Fetches the rest of a pull request's truncated connections.
+///
+/// Labels and status contexts are always paged to the end: a missing
+/// `approved` label or failing check would silently change decisions,
+/// so failing to fetch one is an error. Changed paths are fetched in
+/// full when `options` asks for them, on the same terms. Comments are
+/// paged only as far as `options` needs.
+async fn complete_pull_request(
+    transport: &GraphQLTransport,
+    pr: &mut PullRequest,
+    pending: PendingPages,
+    options: &FetchOptions,
+) -> Result<()> {
+    let mut cursor = pending.labels;
+    while cursor.is_some() {
+        let query = GraphQLQueryBuilder::pull_request_labels(&pr.repo, pr.number)
+            .with_after_cursor(cursor.take())
+            .build();
+        let context = format!("Label query for {}#{}", pr.repo, pr.number);
+        let response: PullRequestPageResponse<LabelsPage> =
+            execute_graphql_query(transport, query, &context).await?;
+        let labels = response
+            .into_page()
+            .with_context(|| format!("{context}: pull request not found"))?
+            .labels;
+        cursor = labels.next_cursor();
+        pr.labels.extend(convert_labels(labels));
+    }
+
+    let mut cursor = pending.status_contexts;
+    while cursor.is_some() {
+        let query = GraphQLQueryBuilder::pull_request_status_contexts(&pr.repo, pr.number)
+            .with_after_cursor(cursor.take())
+            .build();
+        let context = format!("Status context query for {}#{}", pr.repo, pr.number);
+        let response: PullRequestPageResponse<StatusContextsPage> =
+            execute_graphql_query(transport, query, &context).await?;
+        let Some(rollup) = response
+            .into_page()
+            .with_context(|| format!("{context}: pull request not found"))?
+            .status_check_rollup
+        else {
+            // The head commit moved and its rollup has gone; what we
+            // have is for a commit the PR no longer points at.
+            anyhow::bail!("{context}: status checks changed while paging");
+        };
+        cursor = rollup.contexts.next_cursor();
+        pr.checks.extend(convert_status_checks(
+            Some(rollup),
+            &pending.required_checks,
+        ));
+    }
+
+    if options.changed_paths {
+        pr.changed_paths = fetch_changed_paths(transport, pr).await?;
+    }
+
+    if options.commits {
+        pr.commits = fetch_commits(transport, pr).await?;
+    }
+
+    complete_comment_history(transport, pr, pending.older_comments, options).await
+}
+
+/// Pages through every path a pull request changes. `None` when GitHub
+/// will not list them.
+async fn fetch_changed_paths(
+    transport: &GraphQLTransport,
+    pr: &PullRequest,
+) -> Result<Option<Vec<String>>> {
+    let mut paths = Vec::with_capacity(pr.changed_files.unwrap_or_default() as usize);
+    let mut cursor = None;
+    loop {
+        let query = GraphQLQueryBuilder::pull_request_files(&pr.repo, pr.number)
+            .with_after_cursor(cursor)
+            .build();
+        let context = format!("Changed file query for {}#{}", pr.repo, pr.number);
+        let response: PullRequestPageResponse<FilesPage> =
+            execute_graphql_query(transport, query, &context).await?;
+        let Some(files) = response
+            .into_page()
+            .with_context(|| format!("{context}: pull request not found"))?
+            .files
+        else {
+            warn!(repo = %pr.repo, number = pr.number, "GitHub did not list the changed files");
+            return Ok(None);
+        };
+        cursor = files.next_cursor();
+        paths.extend(files.nodes.into_iter().map(|file| file.path));
+        if cursor.is_none() {
+            return Ok(Some(paths));
+        }
+    }
+}
+
+/// Pages through a pull request's commits. `None` when GitHub lists
+/// fewer than the PR has, as it does past 250: a filter must not pass
+/// judgement on commits it has not seen.
+async fn fetch_commits(
+    transport: &GraphQLTransport,
+    pr: &PullRequest,
+) -> Result<Option<Vec<CommitInfo>>> {
+    let mut commits = Vec::with_capacity(pr.commit_count as usize);
+    let mut cursor = None;
+    loop {
+        let query = GraphQLQueryBuilder::pull_request_commits(&pr.repo, pr.number)
+            .with_after_cursor(cursor)
+            .build();
+        let context = format!("Commit query for {}#{}", pr.repo, pr.number);
+        let response: PullRequestPageResponse<CommitsPage> =
+            execute_graphql_query(transport, query, &context).await?;
+        let page = response
+            .into_page()
+            .with_context(|| format!("{context}: pull request not found"))?
+            .commits;
+        cursor = page.next_cursor();
+        commits.extend(convert_commits(page));
+        if cursor.is_none() {
+            break;
+        }
+    }
+
+    if (commits.len() as u64) < pr.commit_count {
+        warn!(
+            repo = %pr.repo,
+            number = pr.number,
+            listed = commits.len(),
+            commit_count = pr.commit_count,
+            "GitHub did not list every commit"
+        );
+        return Ok(None);
+    }
+    Ok(Some(commits))
+}
+
+/// Pages back through a pull request's comments until they cover what
+/// `options` asks for, or there are no older ones.
+///
+/// `older` is the cursor before the comments fetched with the PR. Sets
+/// `comment_history_complete` to say whether the history now covers
+/// the window; a failed page leaves it incomplete rather than failing
+/// the fetch, as search pagination does.
+async fn complete_comment_history(
+    transport: &GraphQLTransport,
+    pr: &mut PullRequest,
+    mut older: Option<String>,
+    options: &FetchOptions,
+) -> Result<()> {
+    while older.is_some() && options.needs_older_comments(&pr.recent_comments) {
+        let query = GraphQLQueryBuilder::pull_request_comments(&pr.repo, pr.number)
+            .with_before_cursor(older.take())
+            .build();
+        let context = format!("Comment history query for {}#{}", pr.repo, pr.number);
+        let response: PullRequestPageResponse<CommentsPage> =
+            match execute_graphql_query(transport, query, &context).await {
+                Ok(response) => response,
+                Err(e) if matches!(transport, GraphQLTransport::Replay(_)) => return Err(e),
+                Err(e) => {
+                    warn!(
+                        repo = %pr.repo,
+                        number = pr.number,
+                        error = %e,
+                        "Failed to fetch older comments, history is incomplete"
+                    );
+                    pr.comment_history_complete = false;
+                    return Ok(());
+                }
+            };
+
+        let Some(CommentsPage { comments }) = response.into_page() else {
+            pr.comment_history_complete = false;
+            return Ok(());
+        };
+
+        older = comments.older_cursor();
+        let mut history = convert_comments(comments);
+        history.append(&mut pr.recent_comments);
+        pr.recent_comments = history;
+        debug!(
+            repo = %pr.repo,
+            number = pr.number,
+            comment_count = pr.recent_comments.len(),
+            "Fetched older comments"
+        );
     }
+
+    pr.comment_history_complete =
+        older.is_none() || !options.needs_older_comments(&pr.recent_comments);
+    Ok(())
 }
 
 /// Collects multiple specific pull requests by their identifiers.
 ///
-/// Fetches each PR individually using search queries. Validates that
-/// returned PR numbers match the requested ones.
-#[instrument(skip(octocrab), fields(pr_count = pr_identifiers.len()))]
+/// Fetches them in batches of aliased `repository { pullRequest }`
+/// lookups rather than one search per PR, and returns them in request
+/// order. PRs that do not exist or cannot be seen are omitted.
+#[instrument(skip(transport), fields(pr_count = pr_identifiers.len()))]
 async fn collect_specific_prs(
-    octocrab: &Octocrab,
+    transport: &GraphQLTransport,
     pr_identifiers: &[PrIdentifier],
+    options: &FetchOptions,
 ) -> Result<Vec<PullRequest>> {
     info!("Collecting specific PRs");
-    let mut all_prs = Vec::with_capacity(pr_identifiers.len());
-
-    for identifier in pr_identifiers {
-        let repo = &identifier.repo;
-        let number = identifier.number;
-        let search_query = search::build_specific_pr_search_query(repo, number);
+    let mut fetched = Vec::with_capacity(pr_identifiers.len());
 
-        if let Some(pr_info) =
-            fetch_single_pr_by_query(octocrab, &search_query, repo.clone()).await?
-        {
-            if is_requested_pr_number(&pr_info, number) {
-                all_prs.push(pr_info);
-            } else {
-                warn!(
-                    expected = number,
-                    actual = pr_info.number,
-                    "PR number mismatch"
-                );
-            }
-        }
+    for batch in plan_pr_batches(pr_identifiers) {
+        fetched.extend(fetch_pr_batch(transport, &batch, options).await?);
     }
 
+    let all_prs = pr_identifiers
+        .iter()
+        .filter_map(|identifier| {
+            fetched
+                .iter()
+                .find(|pr| pr.repo == identifier.repo && pr.number == identifier.number)
+                .cloned()
+        })
+        .collect::<Vec<_>>();
+
     info!(found_count = all_prs.len(), "Collected specific PRs");
     Ok(all_prs)
 }
 
-fn is_requested_pr_number(pr: &PullRequest, requested: u64) -> bool {
-    pr.number == requested
-}
-
-fn take_until_limit(
-    page: impl IntoIterator<Item = PullRequest>,
+fn take_until_limit<T>(
+    page: impl IntoIterator<Item = T>,
     already: usize,
     limit: usize,
-) -> (Vec<PullRequest>, bool) {
+) -> (Vec<T>, bool) {
     let remaining = limit.saturating_sub(already);
     if remaining == 0 {
         return (Vec::new(), true);
@@ -334,20 +1036,165 @@ fn take_until_limit(
     (taken, reached_limit)
 }
 
+/// Fetches pull requests for a search, working around GitHub's cap on
+/// search results.
+///
+/// A search can only page through its first [`SEARCH_RESULT_CAP`]
+/// results. When more than that are wanted and exist, the search is
+/// split into `created:` windows, each halved until it fits under the
+/// cap, and their results are joined in window order without duplicates.
+/// The windows stay within the range the search criteria narrow the
+/// query to, replacing the `created:` terms they put in it.
+async fn fetch_prs_with_pagination(
+    transport: &GraphQLTransport,
+    query: &SearchQuery,
+    limit: usize,
+    repo: Option<Repo>,
+    options: &FetchOptions,
+) -> Result<FetchOutcome> {
+    search_within_cap(
+        transport,
+        query,
+        limit,
+        repo,
+        options,
+        SEARCH_RESULT_CAP,
+        Utc::now(),
+    )
+    .await
+}
+
+#[instrument(skip(transport, options, now), fields(query = %query.text, limit = limit))]
+async fn search_within_cap(
+    transport: &GraphQLTransport,
+    query: &SearchQuery,
+    limit: usize,
+    repo: Option<Repo>,
+    options: &FetchOptions,
+    cap: u64,
+    now: DateTime<Utc>,
+) -> Result<FetchOutcome> {
+    let search_query = query.text.as_str();
+    // Within the cap pagination reaches every result it is asked for,
+    // so only large limits pay for the extra count query.
+    if limit as u64 <= cap {
+        return paginate_search(transport, search_query, limit, repo, options).await;
+    }
+
+    let total = count_search_results(transport, search_query).await?;
+    if total <= cap {
+        return paginate_search(transport, search_query, limit, repo, options).await;
+    }
+    let unbounded_query = query.created.strip(search_query);
+    if search::has_created_qualifier(&unbounded_query) {
+        warn!(
+            total = total,
+            cap = cap,
+            "Search exceeds GitHub's result cap but already filters on created:, results will be incomplete"
+        );
+        let mut outcome = paginate_search(transport, search_query, limit, repo, options).await?;
+        outcome.mark_incomplete(format!(
+            "'{search_query}' matches {total} PRs but GitHub search returns at most {cap}, and a query with its own created: range cannot be split"
+        ));
+        return Ok(outcome);
+    }
+
+    info!(
+        total = total,
+        cap = cap,
+        "Search exceeds GitHub's result cap, splitting by creation date"
+    );
+    let mut pending = VecDeque::new();
+    if let Some(window) = CreatedWindow::within(&query.created, now) {
+        match window.split() {
+            Some((first, second)) => pending.extend([first, second]),
+            None => pending.push_back(window),
+        }
+    }
+
+    let mut seen = HashSet::new();
+    let mut outcome = FetchOutcome::default();
+    while let Some(window) = pending.pop_front() {
+        if outcome.pull_requests.len() >= limit {
+            break;
+        }
+
+        let windowed_query = search::with_created_window(&unbounded_query, &window);
+        let count = count_search_results(transport, &windowed_query).await?;
+        if count == 0 {
+            continue;
+        }
+        if count > cap {
+            if let Some((first, second)) = window.split() {
+                pending.push_front(second);
+                pending.push_front(first);
+                continue;
+            }
+            warn!(
+                query = %windowed_query,
+                count = count,
+                "Creation-date window cannot be split further, results will be incomplete"
+            );
+            outcome.mark_incomplete(format!(
+                "'{windowed_query}' matches {count} PRs but GitHub search returns at most {cap}"
+            ));
+        }
+
+        let window_outcome = paginate_search(
+            transport,
+            &windowed_query,
+            limit - outcome.pull_requests.len(),
+            repo.clone(),
+            options,
+        )
+        .await?;
+        outcome.incomplete.extend(window_outcome.incomplete);
+        outcome.pull_requests.extend(
+            window_outcome
+                .pull_requests
+                .into_iter()
+                .filter(|pr| seen.insert(pr.url.clone())),
+        );
+    }
+
+    info!(
+        final_count = outcome.pull_requests.len(),
+        "Completed search split by creation date"
+    );
+    Ok(outcome)
+}
+
+/// Asks GitHub how many results a search has, without fetching them.
+async fn count_search_results(transport: &GraphQLTransport, search_query: &str) -> Result<u64> {
+    let query = GraphQLQueryBuilder::count_search_results()
+        .with_search_query(search_query)
+        .build();
+    let context = format!("Result count for '{search_query}'");
+    let response: CountResponse = execute_graphql_query(transport, query, &context).await?;
+    debug!(
+        issue_count = response.data.search.issue_count,
+        "Counted search results"
+    );
+    Ok(response.data.search.issue_count)
+}
+
 /// Fetches pull requests using paginated GraphQL search.
 ///
 /// Handles GitHub's pagination limits by making multiple requests.
 /// Continues until the limit is reached or no more results exist.
-/// Returns partial results on pagination errors rather than failing.
-#[instrument(skip(octocrab), fields(query = %search_query, limit = limit, has_repo_context = repo.is_some()))]
-async fn fetch_prs_with_pagination(
-    octocrab: &Octocrab,
+/// Returns partial results, marked incomplete, on pagination errors
+/// rather than failing.
+#[instrument(skip(transport, options), fields(query = %search_query, limit = limit, has_repo_context = repo.is_some()))]
+async fn paginate_search(
+    transport: &GraphQLTransport,
     search_query: &str,
     limit: usize,
     repo: Option<Repo>,
-) -> Result<Vec<PullRequest>> {
+    options: &FetchOptions,
+) -> Result<FetchOutcome> {
     info!("Fetching PRs with pagination");
     let mut all_prs = Vec::with_capacity(limit.min(100)); // GitHub returns max 100 per page.
+    let mut incomplete = Vec::new();
     let mut after_cursor: Option<String> = None;
     let mut page_count = 0;
 
@@ -363,20 +1210,29 @@ async fn fetch_prs_with_pagination(
         debug!(query_variables = ?query.get("variables"), "Executing GraphQL query");
 
         let context = format!("Pagination query page {page_count} for '{search_query}'");
-        let response = match execute_graphql_query(octocrab, query, &context).await {
-            Ok(response) => response,
-            Err(e) => {
-                warn!(
-                    page = page_count,
-                    cursor = ?after_cursor,
-                    error = %e,
-                    current_pr_count = all_prs.len(),
-                    "GraphQL pagination failed, returning partial results"
-                );
-                // Return what we have so far rather than failing completely.
-                break;
-            }
-        };
+        let response: GraphQLResponse =
+            match execute_graphql_query(transport, query, &context).await {
+                Ok(response) => response,
+                // A recording either has the page or it does not; partial
+                // results would only hide a replay that does not match.
+                Err(e) if matches!(transport, GraphQLTransport::Replay(_)) => return Err(e),
+                Err(e) => {
+                    warn!(
+                        page = page_count,
+                        cursor = ?after_cursor,
+                        error = %e,
+                        current_pr_count = all_prs.len(),
+                        "GraphQL pagination failed, returning partial results"
+                    );
+                    // Return what we have so far rather than failing
+                    // completely, but say that it is not everything.
+                    incomplete.push(format!(
+                        "'{search_query}': page {page_count} failed after {} PRs: {e}",
+                        all_prs.len()
+                    ));
+                    break;
+                }
+            };
 
         let search_results = response.data.search;
 
@@ -389,6 +1245,7 @@ async fn fetch_prs_with_pagination(
             .nodes
             .into_iter()
             .filter_map(|graphql_pr| {
+                let pending = PendingPages::of(&graphql_pr);
                 let pr_info = if let Some(ref repo) = repo {
                     convert_graphql_pr_to_pr_info(graphql_pr, repo.clone())
                 } else {
@@ -396,7 +1253,7 @@ async fn fetch_prs_with_pagination(
                 };
 
                 match pr_info {
-                    Ok(pr_info) => Some(pr_info),
+                    Ok(pr_info) => Some((pr_info, pending)),
                     Err(e) => {
                         warn!(error = %e, "Failed to convert GraphQL PR");
                         None
@@ -406,7 +1263,10 @@ async fn fetch_prs_with_pagination(
             .collect::<Vec<_>>();
 
         let (page_prs, reached_limit) = take_until_limit(page_prs, all_prs.len(), limit);
-        all_prs.extend(page_prs);
+        for (mut pr, pending) in page_prs {
+            complete_pull_request(transport, &mut pr, pending, options).await?;
+            all_prs.push(pr);
+        }
 
         if reached_limit {
             info!(
@@ -414,7 +1274,10 @@ async fn fetch_prs_with_pagination(
                 pages = page_count,
                 "Reached limit"
             );
-            return Ok(all_prs);
+            return Ok(FetchOutcome {
+                pull_requests: all_prs,
+                incomplete,
+            });
         }
 
         if search_results.page_info.has_next_page {
@@ -435,94 +1298,326 @@ async fn fetch_prs_with_pagination(
         requested_limit = limit,
         "Pagination completed"
     );
-    Ok(all_prs)
+    Ok(FetchOutcome {
+        pull_requests: all_prs,
+        incomplete,
+    })
 }
 
 /// Verifies that a repository exists on GitHub.
 ///
 /// Makes a REST API call to check if the repository is accessible.
 /// Returns an error if the repository doesn't exist or isn't accessible.
-#[instrument(skip(octocrab), fields(repo = %repo))]
-async fn verify_repository_exists(octocrab: &Octocrab, repo: &Repo) -> Result<()> {
+/// With a cache, a recent success is reused without asking, and an
+/// older one is revalidated with its ETag.
+#[instrument(skip(octocrab, cache), fields(repo = %repo))]
+async fn verify_repository_exists(
+    octocrab: &Octocrab,
+    repo: &Repo,
+    cache: Option<&Cache>,
+) -> Result<()> {
     debug!("Verifying repository exists");
 
-    let result = octocrab.repos(repo.owner(), repo.name()).get().await;
+    let route = format!("/repos/{}/{}", repo.owner(), repo.name());
+    let key = format!("GET {route}");
+    let now = Utc::now();
+    let cached = cache.and_then(|cache| cache.get(&key));
+    if let (Some(cache), Some(entry)) = (cache, &cached)
+        && cache.is_fresh(entry, now)
+    {
+        debug!("Repository verified from cache");
+        return Ok(());
+    }
 
-    match result {
-        Ok(_) => {
-            debug!("Repository verified");
-            Ok(())
-        }
-        Err(octocrab::Error::GitHub { source, .. }) => {
-            if source.message.contains("Not Found") {
-                anyhow::bail!("Repository '{}' does not exist or is not accessible", repo)
-            } else {
-                anyhow::bail!("Failed to verify repository '{}': {}", repo, source.message)
-            }
-        }
-        Err(e) => {
-            anyhow::bail!("Failed to verify repository '{}': {}", repo, e)
-        }
+    let mut headers = http::HeaderMap::new();
+    if let Some(etag) = cached
+        .as_ref()
+        .and_then(|entry| entry.etag.as_deref())
+        .and_then(|etag| http::HeaderValue::from_str(etag).ok())
+    {
+        headers.insert(http::header::IF_NONE_MATCH, etag);
     }
+
+    let response = octocrab
+        ._get_with_headers(route.as_str(), Some(headers))
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to verify repository '{}': {}", repo, e))?;
+
+    let status = response.status();
+    if status == http::StatusCode::NOT_MODIFIED || status.is_success() {
+        debug!(revalidated = !status.is_success(), "Repository verified");
+        if let Some(cache) = cache {
+            let etag = response
+                .headers()
+                .get(http::header::ETAG)
+                .and_then(|etag| etag.to_str().ok())
+                .map(str::to_string)
+                .or_else(|| cached.and_then(|entry| entry.etag));
+            cache.put(&key, etag, &serde_json::Value::Null, now);
+        }
+        return Ok(());
+    }
+
+    if status == http::StatusCode::NOT_FOUND {
+        anyhow::bail!("Repository '{}' does not exist or is not accessible", repo)
+    }
+    let body = octocrab.body_to_string(response).await.unwrap_or_default();
+    let message = serde_json::from_str::<serde_json::Value>(&body)
+        .ok()
+        .and_then(|body| body.get("message")?.as_str().map(str::to_string))
+        .unwrap_or_else(|| status.to_string());
+    anyhow::bail!("Failed to verify repository '{}': {}", repo, message)
 }
 
-/// Fetches pull request data from GitHub according to a fetch plan.
-///
-/// Handles both specific PR queries and search-based queries. Monitors
-/// rate limits and provides detailed instrumentation of the operation.
-#[instrument(skip(plan), fields(plan = ?plan))]
-async fn fetch_github_data(plan: &FetchPlan) -> Result<Vec<PullRequest>> {
-    info!("Starting GitHub data fetch");
-    let octocrab = setup_github_client().await?;
+/// Opens the response cache for `host` and `identity`, or returns
+/// `None` if there is nowhere to keep one; a run works the same without
+/// it.
+fn open_cache(host: &str, identity: &str) -> Option<Cache> {
+    let root = cache::default_dir()?;
+    Cache::open(&root, host, identity, CACHE_TTL)
+        .inspect_err(|e| warn!(error = %e, "Response cache unavailable"))
+        .ok()
+}
 
-    // Check rate limit before starting (in debug mode).
-    let rate_limit_before = check_rate_limit(&octocrab, "before GraphQL operations").await;
-    if let Err(e) = &rate_limit_before {
-        debug!("Rate limit check failed, continuing anyway: {}", e);
+/// Default number of repositories fetched at once.
+pub const DEFAULT_FETCH_CONCURRENCY: usize = 4;
+
+/// GraphQL points each concurrent repository fetch should have in
+/// reserve. A search page costs one point, but a worker may page
+/// several times, and GitHub's secondary limits punish bursts more
+/// than steady use.
+const GRAPHQL_POINTS_PER_WORKER: u32 = 50;
+
+/// Bounds repository fetch concurrency by the remaining GraphQL budget.
+///
+/// Never drops below one worker, which is no worse than fetching
+/// sequentially; with an exhausted budget that request fails the same
+/// way either way.
+fn budget_concurrency(requested: usize, graphql_remaining: Option<u32>) -> usize {
+    let requested = requested.max(1);
+    match graphql_remaining {
+        Some(remaining) => {
+            let affordable = (remaining / GRAPHQL_POINTS_PER_WORKER) as usize;
+            requested.min(affordable.max(1))
+        }
+        None => requested,
     }
+}
 
-    let result = match plan {
+/// Runs a fetch plan over a GraphQL transport.
+///
+/// `rest` is used to verify repositories before searching them; it is
+/// `None` when replaying, where there is no network to ask. Up to
+/// `concurrency` repositories are verified and fetched at once; results
+/// keep the order of the plan's searches.
+async fn fetch_with_transport(
+    transport: &GraphQLTransport,
+    rest: Option<&Octocrab>,
+    plan: &FetchPlan,
+    options: &FetchOptions,
+    concurrency: usize,
+) -> Result<FetchOutcome> {
+    match plan {
         FetchPlan::SpecificPullRequests(identifiers) => {
             debug!("Fetching specific PRs");
-            collect_specific_prs(&octocrab, identifiers).await
+            collect_specific_prs(transport, identifiers, options)
+                .await
+                .map(FetchOutcome::complete)
         }
         FetchPlan::UserSearch { query, limit } => {
             debug!("Using custom query");
-            let search_query = search::format_user_query(query);
-            fetch_prs_with_pagination(&octocrab, &search_query, *limit, None).await
+            let search_query = SearchQuery::unbounded(search::format_user_query(query));
+            fetch_prs_with_pagination(transport, &search_query, *limit, None, options).await
         }
         FetchPlan::RepositorySearches(searches) => {
-            debug!("Fetching PRs from {} repo(s)", searches.len());
-
-            for search in searches {
-                verify_repository_exists(&octocrab, &search.repo).await?;
+            fetch_repository_searches(transport, rest, searches, options, concurrency).await
+        }
+        FetchPlan::OrganisationSweep {
+            sweep,
+            criteria,
+            limit,
+        } => {
+            let repos = list_owner_repositories(transport, &sweep.org).await?;
+            let searches = sweep.searches(repos, criteria, *limit);
+            if searches.is_empty() {
+                anyhow::bail!(
+                    "No unarchived repositories in '{}' match --repo-glob/--exclude-repo",
+                    sweep.org
+                );
             }
+            info!(
+                org = %sweep.org,
+                repos = searches.len(),
+                "Sweeping organisation repositories"
+            );
+            // The listing has just shown every repository exists.
+            fetch_repository_searches(transport, None, &searches, options, concurrency).await
+        }
+    }
+}
 
-            let mut all_prs = Vec::new();
-            for search in searches {
-                let search_query = search::build_repo_search_query(&search.repo, &search.criteria);
-                let prs = fetch_prs_with_pagination(
-                    &octocrab,
-                    &search_query,
-                    search.limit,
-                    Some(search.repo.clone()),
-                )
-                .await?;
-                all_prs.extend(prs);
+/// Lists the unarchived repositories `owner` owns, by name.
+async fn list_owner_repositories(transport: &GraphQLTransport, owner: &str) -> Result<Vec<Repo>> {
+    let mut repos = Vec::new();
+    let mut cursor = None;
+    loop {
+        let query = GraphQLQueryBuilder::owner_repositories(owner)
+            .with_after_cursor(cursor.take())
+            .build();
+        let context = format!("Repository list for '{owner}'");
+        let response: OwnerRepositoriesResponse =
+            execute_graphql_query(transport, query, &context).await?;
+        let page = response.into_page().with_context(|| {
+            format!("Organisation or user '{owner}' does not exist or is not accessible")
+        })?;
+
+        for repository in page.nodes {
+            if repository.is_archived {
+                debug!(repo = %repository.name, "Skipping archived repository");
+                continue;
             }
-            Ok(all_prs)
+            repos.push(Repo::new(owner, &repository.name)?);
         }
+
+        if !page.page_info.has_next_page {
+            break;
+        }
+        cursor = page.page_info.end_cursor;
+    }
+    Ok(repos)
+}
+
+/// Runs one search per repository, checking first that each exists
+/// when `rest` is given.
+async fn fetch_repository_searches(
+    transport: &GraphQLTransport,
+    rest: Option<&Octocrab>,
+    searches: &[RepoSearch],
+    options: &FetchOptions,
+    concurrency: usize,
+) -> Result<FetchOutcome> {
+    debug!(
+        concurrency = concurrency,
+        "Fetching PRs from {} repo(s)",
+        searches.len()
+    );
+
+    // The futures are built up front because closures borrowing the
+    // searches do not satisfy the `Send` bound the `Forge` trait puts
+    // on this future. They do nothing until polled.
+    if let Some(rest) = rest {
+        let verifications: Vec<_> = searches
+            .iter()
+            .map(|search| verify_repository_exists(rest, &search.repo, transport.cache()))
+            .collect();
+        stream::iter(verifications)
+            .buffered(concurrency)
+            .try_collect::<Vec<()>>()
+            .await?;
+    }
+
+    let fetches: Vec<_> = searches
+        .iter()
+        .map(|search| async move {
+            let search_query = SearchQuery::for_repo(&search.repo, &search.criteria);
+            fetch_prs_with_pagination(
+                transport,
+                &search_query,
+                search.limit,
+                Some(search.repo.clone()),
+                options,
+            )
+            .await
+        })
+        .collect();
+    // `buffered` yields in input order, so the output does not
+    // depend on which repository answers first.
+    let per_repo = stream::iter(fetches)
+        .buffered(concurrency)
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    let mut outcome = FetchOutcome::default();
+    for repo_outcome in per_repo {
+        outcome.merge(repo_outcome);
+    }
+    Ok(outcome)
+}
+
+/// Fetches pull request data from GitHub according to a fetch plan.
+///
+/// Handles both specific PR queries and search-based queries. Monitors
+/// rate limits and provides detailed instrumentation of the operation.
+/// When replaying, the plan is served from the recording and nothing
+/// touches the network.
+#[instrument(skip(github, plan), fields(host = %github.host, plan = ?plan))]
+async fn fetch_github_data(
+    github: &GitHub,
+    plan: &FetchPlan,
+    options: &FetchOptions,
+) -> Result<FetchOutcome> {
+    let record_dir = match &github.source {
+        Source::Replay { dir } => {
+            info!(dir = %dir.display(), "Replaying GitHub data from recording");
+            let transport = GraphQLTransport::Replay(Replay::load(dir)?);
+            return fetch_with_transport(&transport, None, plan, options, github.concurrency).await;
+        }
+        Source::Live { record_dir } => record_dir.as_deref(),
     };
 
-    // Check rate limit after operations complete.
-    let rate_limit_after = check_rate_limit(&octocrab, "after GraphQL operations").await;
+    info!("Starting GitHub data fetch");
+    let clients = setup_github_client(&github.host).await?;
+
+    // A recording must capture what GitHub says now, not what it said
+    // a minute ago.
+    let cache = if github.cache && record_dir.is_none() {
+        open_cache(&github.host, &clients.identity)
+    } else {
+        None
+    };
+    let transport = GraphQLTransport::Live {
+        client: clients.graphql,
+        recorder: record_dir.map(Recorder::create).transpose()?,
+        cache,
+        serve_cached: !options.fresh,
+    };
+
+    // Check rate limit before starting (in debug mode).
+    let rate_limit_before = check_rate_limit(&clients.rest, "before GraphQL operations").await;
+    if let Err(e) = &rate_limit_before {
+        debug!("Rate limit check failed, continuing anyway: {}", e);
+    }
+
+    let concurrency = budget_concurrency(
+        github.concurrency,
+        rate_limit_before
+            .as_ref()
+            .ok()
+            .map(|limits| limits.graphql.remaining),
+    );
+    if concurrency < github.concurrency {
+        warn!(
+            requested = github.concurrency,
+            concurrency = concurrency,
+            "Reducing fetch concurrency to fit the remaining GraphQL rate limit"
+        );
+    }
+
+    let result =
+        fetch_with_transport(&transport, Some(&clients.rest), plan, options, concurrency).await;
+
+    // Check rate limit after operations complete, only to report usage.
+    if !tracing::enabled!(Level::INFO) {
+        return result;
+    }
+    let rate_limit_after = check_rate_limit(&clients.rest, "after GraphQL operations").await;
     if let (Ok(before), Ok(after)) = (&rate_limit_before, &rate_limit_after) {
-        let used_during_operation = before.remaining.saturating_sub(after.remaining);
+        let used_during_operation = before.core.remaining.saturating_sub(after.core.remaining);
         if used_during_operation > 0 {
             info!(
                 rate_limit_used = used_during_operation,
-                remaining_before = before.remaining,
-                remaining_after = after.remaining,
+                remaining_before = before.core.remaining,
+                remaining_after = after.core.remaining,
                 "GitHub API rate limit usage during operation"
             );
         }
@@ -535,22 +1630,105 @@ async fn fetch_github_data(plan: &FetchPlan) -> Result<Vec<PullRequest>> {
 ///
 /// Provides access to GitHub's GraphQL API for querying pull requests,
 /// their status checks, labels, and comments. Handles authentication
-/// via environment variables or the GitHub CLI.
-pub struct GitHub;
+/// via environment variables or the GitHub CLI. Talks to github.com by
+/// default, or to a GitHub Enterprise Server host via [`GitHub::with_host`].
+#[derive(Debug, Clone)]
+pub struct GitHub {
+    host: String,
+    source: Source,
+    concurrency: usize,
+    cache: bool,
+}
+
+/// Where a [`GitHub`] forge gets its GraphQL responses.
+#[derive(Debug, Clone)]
+enum Source {
+    Live { record_dir: Option<PathBuf> },
+    Replay { dir: PathBuf },
+}
+
+impl GitHub {
+    pub fn new() -> Self {
+        Self::with_host(GITHUB_COM)
+    }
+
+    /// Targets the GitHub instance at `host` (e.g. `github.example.com`).
+    pub fn with_host(host: impl Into<String>) -> Self {
+        Self {
+            host: host.into(),
+            source: Source::Live { record_dir: None },
+            concurrency: DEFAULT_FETCH_CONCURRENCY,
+            cache: true,
+        }
+    }
+
+    /// Serves every fetch from a recording made with
+    /// [`GitHub::record_to`], without touching the network.
+    pub fn replay_from(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            host: GITHUB_COM.to_string(),
+            source: Source::Replay { dir: dir.into() },
+            concurrency: DEFAULT_FETCH_CONCURRENCY,
+            cache: true,
+        }
+    }
+
+    /// Fetches up to `concurrency` repositories at once (at least one).
+    /// The GraphQL rate limit may lower this for a given run.
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Always asks GitHub, and keeps nothing for later runs. Otherwise
+    /// recent responses are reused from the user's cache directory.
+    pub fn without_cache(mut self) -> Self {
+        self.cache = false;
+        self
+    }
+
+    /// Saves every GraphQL request and response to `dir`, which must
+    /// not already hold a recording.
+    pub fn record_to(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.source = Source::Live {
+            record_dir: Some(dir.into()),
+        };
+        self
+    }
+
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+}
+
+impl Default for GitHub {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 #[async_trait]
 impl crate::types::Forge for GitHub {
-    async fn fetch_pull_requests(&self, plan: &FetchPlan) -> Result<Vec<PullRequest>> {
-        fetch_github_data(plan).await
+    async fn fetch_pull_requests(
+        &self,
+        plan: &FetchPlan,
+        options: &FetchOptions,
+    ) -> Result<FetchOutcome> {
+        fetch_github_data(self, plan, options).await
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use chrono::{TimeZone, Utc};
+    use std::time::Duration;
+
+    use chrono::{Datelike, TimeZone, Utc};
 
     use super::*;
-    use crate::types::PrState;
+    use crate::{
+        search::OrgSweep,
+        types::{MergeState, Mergeable, PrState, SearchCriterion, SignatureState},
+    };
 
     fn pr(number: u64) -> PullRequest {
         PullRequest {
@@ -562,12 +1740,32 @@ mod tests {
             url: format!("https://github.com/owner/repo/pull/{number}"),
             labels: vec![],
             created_at: Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap(),
+            updated_at: Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap(),
+            last_pushed_at: None,
             base_branch: "main".to_string(),
             commit_count: 1,
+            additions: None,
+            deletions: None,
+            changed_files: None,
+            changed_paths: None,
+            commits: None,
+            linked_issues: None,
+            issue_keys: vec![],
             is_draft: false,
             state: PrState::Open,
             checks: vec![],
             recent_comments: vec![],
+            comment_history_complete: true,
+            review_decision: None,
+            reviews: vec![],
+            requested_reviewers: vec![],
+            assignees: vec![],
+            mergeable: Mergeable::Unknown,
+            merge_state: MergeState::Unknown,
+            head_branch: "feature".to_string(),
+            head_repo_owner: None,
+            head_sha: String::new(),
+            is_cross_repository: false,
         }
     }
 
@@ -575,10 +1773,1021 @@ mod tests {
         prs.iter().map(|pr| pr.number).collect()
     }
 
+    fn ids(repo: &str, numbers: impl IntoIterator<Item = u64>) -> Vec<PrIdentifier> {
+        numbers
+            .into_iter()
+            .map(|number| PrIdentifier::new(Repo::new("owner", repo).unwrap(), number))
+            .collect()
+    }
+
+    #[test]
+    fn plan_pr_batches_groups_by_repo_and_drops_duplicates() {
+        let mut identifiers = ids("a", [3, 1]);
+        identifiers.extend(ids("b", [2]));
+        identifiers.extend(ids("a", [3, 4]));
+
+        let batches = plan_pr_batches(&identifiers);
+
+        assert_eq!(
+            batches,
+            vec![vec![
+                (Repo::new("owner", "a").unwrap(), vec![3, 1, 4]),
+                (Repo::new("owner", "b").unwrap(), vec![2]),
+            ]]
+        );
+    }
+
     #[test]
-    fn requested_pr_number_keeps_only_exact_matches() {
-        assert!(is_requested_pr_number(&pr(123), 123));
-        assert!(!is_requested_pr_number(&pr(124), 123));
+    fn plan_pr_batches_splits_large_ranges() {
+        let mut identifiers = ids("a", 100..=199);
+        identifiers.extend(ids("b", [1]));
+
+        let batches = plan_pr_batches(&identifiers);
+
+        assert_eq!(
+            batches
+                .iter()
+                .map(|batch| batch
+                    .iter()
+                    .map(|(repo, numbers)| (repo.name().to_string(), numbers.len()))
+                    .collect::<Vec<_>>())
+                .collect::<Vec<_>>(),
+            vec![
+                vec![("a".to_string(), 50)],
+                vec![("a".to_string(), 50)],
+                vec![("b".to_string(), 1)],
+            ]
+        );
+    }
+
+    fn search_node(number: u64) -> serde_json::Value {
+        serde_json::json!({
+            "number": number,
+            "title": format!("PR {number}"),
+            "body": "",
+            "baseRefName": "main",
+            "baseRef": { "refUpdateRule": null },
+            "headRefName": format!("feature-{number}"),
+            "headRefOid": "0123456789abcdef0123456789abcdef01234567",
+            "isCrossRepository": false,
+            "headRepositoryOwner": { "login": "owner" },
+            "url": format!("https://github.com/owner/repo/pull/{number}"),
+            "state": "OPEN",
+            "isDraft": false,
+            "createdAt": "2026-05-29T12:00:00Z",
+            "updatedAt": "2026-05-29T12:00:00Z",
+            "additions": 1,
+            "deletions": 1,
+            "changedFiles": 1,
+            "reviewDecision": null,
+            "mergeable": "MERGEABLE",
+            "mergeStateStatus": "CLEAN",
+            "commits": {
+                "totalCount": 1,
+                "nodes": [{ "commit": { "committedDate": "2026-05-29T12:00:00Z" } }]
+            },
+            "timelineItems": { "nodes": [] },
+            "author": { "login": "alice", "__typename": "User" },
+            "labels": { "nodes": [], "pageInfo": { "hasNextPage": false, "endCursor": null } },
+            "reviewRequests": { "nodes": [] },
+            "assignees": { "nodes": [] },
+            "latestReviews": { "nodes": [] },
+            "statusCheckRollup": null,
+            "closingIssuesReferences": { "nodes": [] },
+            "comments": {
+                "nodes": [],
+                "pageInfo": { "hasPreviousPage": false, "startCursor": null }
+            }
+        })
+    }
+
+    /// Replays a session in which `counts` answers each count query and
+    /// `pages` each (single-page) search.
+    fn replay_searches(
+        dir: &std::path::Path,
+        counts: &[(&str, u64)],
+        pages: &[(&str, &[u64])],
+    ) -> GraphQLTransport {
+        let recorder = Recorder::create(dir).unwrap();
+        for (query, count) in counts {
+            let request = GraphQLQueryBuilder::count_search_results()
+                .with_search_query(query)
+                .build();
+            let response = serde_json::json!({ "data": { "search": { "issueCount": count } } });
+            recorder.record(&request, &response).unwrap();
+        }
+        for (query, numbers) in pages {
+            let request = GraphQLQueryBuilder::search_pull_requests()
+                .with_search_query(query)
+                .with_after_cursor(None)
+                .build();
+            let nodes = numbers.iter().map(|n| search_node(*n)).collect::<Vec<_>>();
+            let response = serde_json::json!({ "data": { "search": {
+                "nodes": nodes,
+                "pageInfo": { "hasNextPage": false, "endCursor": null }
+            } } });
+            recorder.record(&request, &response).unwrap();
+        }
+        GraphQLTransport::Replay(Replay::load(dir).unwrap())
+    }
+
+    #[tokio::test]
+    async fn search_over_cap_is_split_by_creation_date_and_deduplicated() {
+        let now = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let (older, newer) = CreatedWindow::up_to(now).split().unwrap();
+        let (newer_first, newer_second) = newer.split().unwrap();
+        let q = |window: &CreatedWindow| search::with_created_window("is:pr", window);
+
+        let dir = tempfile::tempdir().unwrap();
+        let transport = replay_searches(
+            dir.path(),
+            &[
+                ("is:pr", 4),
+                (&q(&older), 0),
+                (&q(&newer), 4),
+                (&q(&newer_first), 2),
+                (&q(&newer_second), 2),
+            ],
+            &[(&q(&newer_first), &[1, 2]), (&q(&newer_second), &[2, 3])],
+        );
+
+        let outcome = search_within_cap(
+            &transport,
+            &SearchQuery::unbounded("is:pr"),
+            10,
+            None,
+            &FetchOptions::default(),
+            2,
+            now,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(pr_numbers(&outcome.pull_requests), vec![1, 2, 3]);
+        assert!(outcome.is_complete());
+    }
+
+    #[tokio::test]
+    async fn search_over_cap_is_split_within_its_created_bounds() {
+        let now = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let after = Utc.with_ymd_and_hms(2025, 12, 1, 0, 0, 0).unwrap();
+        let repo = Repo::new("owner", "repo").unwrap();
+        let query = SearchQuery::for_repo(&repo, &[SearchCriterion::CreatedAfter(after)]);
+        let window = CreatedWindow::within(&query.created, now).unwrap();
+        assert_eq!(window.start, after + chrono::TimeDelta::seconds(1));
+        let (older, newer) = window.split().unwrap();
+        let unbounded = search::build_repo_search_query(&repo, &[]);
+        let q = |window: &CreatedWindow| search::with_created_window(&unbounded, window);
+
+        let dir = tempfile::tempdir().unwrap();
+        let transport = replay_searches(
+            dir.path(),
+            &[(&query.text, 4), (&q(&older), 2), (&q(&newer), 2)],
+            &[(&q(&older), &[1, 2]), (&q(&newer), &[3, 4])],
+        );
+
+        let outcome = search_within_cap(
+            &transport,
+            &query,
+            10,
+            Some(repo),
+            &FetchOptions::default(),
+            2,
+            now,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(pr_numbers(&outcome.pull_requests), vec![1, 2, 3, 4]);
+        assert!(outcome.is_complete());
+    }
+
+    #[tokio::test]
+    async fn search_under_cap_is_not_split() {
+        let now = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let transport = replay_searches(dir.path(), &[("is:pr", 2)], &[("is:pr", &[1, 2])]);
+
+        let outcome = search_within_cap(
+            &transport,
+            &SearchQuery::unbounded("is:pr"),
+            10,
+            None,
+            &FetchOptions::default(),
+            2,
+            now,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(pr_numbers(&outcome.pull_requests), vec![1, 2]);
+        assert!(outcome.is_complete());
+    }
+
+    #[tokio::test]
+    async fn unreadable_pr_in_a_batch_is_left_out_without_losing_the_rest() {
+        let repo = Repo::new("owner", "repo").unwrap();
+        let mut unreadable = search_node(2);
+        unreadable["baseRefName"] = serde_json::Value::Null;
+
+        let dir = tempfile::tempdir().unwrap();
+        let recorder = Recorder::create(dir.path()).unwrap();
+        let request =
+            GraphQLQueryBuilder::pull_requests_by_number(&[(repo.clone(), vec![1, 2, 3])]).build();
+        let response = serde_json::json!({ "data": { "repo0": {
+            "pr1": search_node(1),
+            "pr2": unreadable,
+            "pr3": search_node(3)
+        } } });
+        recorder.record(&request, &response).unwrap();
+        let transport = GraphQLTransport::Replay(Replay::load(dir.path()).unwrap());
+
+        let prs = collect_specific_prs(
+            &transport,
+            &ids("repo", [1, 2, 3]),
+            &FetchOptions::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(pr_numbers(&prs), vec![1, 3]);
+    }
+
+    #[tokio::test]
+    async fn older_comments_are_paged_when_the_window_needs_them() {
+        let comment = |body: &str, at: &str| serde_json::json!({ "body": body, "createdAt": at, "author": null });
+        let mut node = search_node(1);
+        node["comments"] = serde_json::json!({
+            "nodes": [comment("/retest", "2026-05-29T12:00:00Z")],
+            "pageInfo": { "hasPreviousPage": true, "startCursor": "c2" }
+        });
+
+        let dir = tempfile::tempdir().unwrap();
+        let recorder = Recorder::create(dir.path()).unwrap();
+        let search = GraphQLQueryBuilder::search_pull_requests()
+            .with_search_query("is:pr")
+            .with_after_cursor(None)
+            .build();
+        let response = serde_json::json!({ "data": { "search": {
+            "nodes": [node],
+            "pageInfo": { "hasNextPage": false, "endCursor": null }
+        } } });
+        recorder.record(&search, &response).unwrap();
+        let older = |cursor: &str, at: &str, previous: Option<&str>| {
+            let request =
+                GraphQLQueryBuilder::pull_request_comments(&Repo::new("owner", "repo").unwrap(), 1)
+                    .with_before_cursor(Some(cursor.to_string()))
+                    .build();
+            let response = serde_json::json!({ "data": { "repository": { "pullRequest": {
+                "comments": {
+                    "nodes": [comment("/hold", at)],
+                    "pageInfo": { "hasPreviousPage": previous.is_some(), "startCursor": previous }
+                }
+            } } } });
+            recorder.record(&request, &response).unwrap();
+        };
+        older("c2", "2026-05-28T12:00:00Z", Some("c1"));
+        older("c1", "2026-05-27T12:00:00Z", None);
+        let transport = GraphQLTransport::Replay(Replay::load(dir.path()).unwrap());
+
+        let options = FetchOptions {
+            comments_since: Some(Utc.with_ymd_and_hms(2026, 5, 28, 18, 0, 0).unwrap()),
+            min_comments: 0,
+            ..FetchOptions::default()
+        };
+        let outcome = paginate_search(&transport, "is:pr", 10, None, &options)
+            .await
+            .unwrap();
+
+        let pr = &outcome.pull_requests[0];
+        let days: Vec<u32> = pr
+            .recent_comments
+            .iter()
+            .map(|comment| comment.created_at.day())
+            .collect();
+        assert_eq!(
+            days,
+            vec![28, 29],
+            "paging stops once the window is covered"
+        );
+        assert!(pr.comment_history_complete);
+
+        let outcome = paginate_search(
+            &transport,
+            "is:pr",
+            10,
+            None,
+            &FetchOptions {
+                comments_since: None,
+                min_comments: 5,
+                ..FetchOptions::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(outcome.pull_requests[0].recent_comments.len(), 3);
+        assert!(outcome.pull_requests[0].comment_history_complete);
+    }
+
+    #[tokio::test]
+    async fn truncated_labels_and_status_contexts_are_paged_to_the_end() {
+        let label = |name: &str| serde_json::json!({ "name": name });
+        let status = |context: &str, state: &str| serde_json::json!({ "__typename": "StatusContext", "context": context, "state": state, "targetUrl": null, "createdAt": "2026-05-29T12:00:00Z" });
+        let more = |cursor: Option<&str>| serde_json::json!({ "hasNextPage": cursor.is_some(), "endCursor": cursor });
+        let mut node = search_node(1);
+        node["labels"] =
+            serde_json::json!({ "nodes": [label("lgtm")], "pageInfo": more(Some("l1")) });
+        node["statusCheckRollup"] = serde_json::json!({ "contexts": {
+            "nodes": [status("ci/unit", "SUCCESS")],
+            "pageInfo": more(Some("s1"))
+        } });
+
+        let dir = tempfile::tempdir().unwrap();
+        let recorder = Recorder::create(dir.path()).unwrap();
+        let repo = Repo::new("owner", "repo").unwrap();
+        let search = GraphQLQueryBuilder::search_pull_requests()
+            .with_search_query("is:pr")
+            .with_after_cursor(None)
+            .build();
+        let response = serde_json::json!({ "data": { "search": {
+            "nodes": [node],
+            "pageInfo": { "hasNextPage": false, "endCursor": null }
+        } } });
+        recorder.record(&search, &response).unwrap();
+        let pages = [
+            (
+                GraphQLQueryBuilder::pull_request_labels(&repo, 1)
+                    .with_after_cursor(Some("l1".to_string())),
+                serde_json::json!({ "labels": { "nodes": [label("approved")], "pageInfo": more(Some("l2")) } }),
+            ),
+            (
+                GraphQLQueryBuilder::pull_request_labels(&repo, 1)
+                    .with_after_cursor(Some("l2".to_string())),
+                serde_json::json!({ "labels": { "nodes": [label("do-not-merge/hold")], "pageInfo": more(None) } }),
+            ),
+            (
+                GraphQLQueryBuilder::pull_request_status_contexts(&repo, 1)
+                    .with_after_cursor(Some("s1".to_string())),
+                serde_json::json!({ "statusCheckRollup": { "contexts": {
+                    "nodes": [status("ci/e2e", "FAILURE")],
+                    "pageInfo": more(None)
+                } } }),
+            ),
+        ];
+        for (request, pull_request) in pages {
+            let response =
+                serde_json::json!({ "data": { "repository": { "pullRequest": pull_request } } });
+            recorder.record(&request.build(), &response).unwrap();
+        }
+        let transport = GraphQLTransport::Replay(Replay::load(dir.path()).unwrap());
+
+        let outcome = paginate_search(&transport, "is:pr", 10, None, &FetchOptions::default())
+            .await
+            .unwrap();
+
+        let pr = &outcome.pull_requests[0];
+        assert_eq!(pr.labels, vec!["lgtm", "approved", "do-not-merge/hold"]);
+        assert!(pr.has_label("approved"));
+        assert_eq!(pr.checks.len(), 2);
+        assert!(pr.has_failing_ci());
+    }
+
+    #[tokio::test]
+    async fn changed_paths_are_paged_only_when_asked_for() {
+        let file = |path: &str| serde_json::json!({ "path": path });
+        let more = |cursor: Option<&str>| serde_json::json!({ "hasNextPage": cursor.is_some(), "endCursor": cursor });
+        let dir = tempfile::tempdir().unwrap();
+        let recorder = Recorder::create(dir.path()).unwrap();
+        let repo = Repo::new("owner", "repo").unwrap();
+        let search = GraphQLQueryBuilder::search_pull_requests()
+            .with_search_query("is:pr")
+            .with_after_cursor(None)
+            .build();
+        let response = serde_json::json!({ "data": { "search": {
+            "nodes": [search_node(1)],
+            "pageInfo": { "hasNextPage": false, "endCursor": null }
+        } } });
+        recorder.record(&search, &response).unwrap();
+        let pages = [
+            (None, vec![file("api/v1/types.go")], Some("f1")),
+            (
+                Some("f1".to_string()),
+                vec![file("vendor/modules.txt")],
+                None,
+            ),
+        ];
+        for (after, nodes, next) in pages {
+            let request = GraphQLQueryBuilder::pull_request_files(&repo, 1)
+                .with_after_cursor(after)
+                .build();
+            let response = serde_json::json!({ "data": { "repository": { "pullRequest": {
+                "files": { "nodes": nodes, "pageInfo": more(next) }
+            } } } });
+            recorder.record(&request, &response).unwrap();
+        }
+        let transport = GraphQLTransport::Replay(Replay::load(dir.path()).unwrap());
+
+        let outcome = paginate_search(&transport, "is:pr", 10, None, &FetchOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(outcome.pull_requests[0].changed_paths, None);
+
+        let options = FetchOptions {
+            changed_paths: true,
+            ..FetchOptions::default()
+        };
+        let outcome = paginate_search(&transport, "is:pr", 10, None, &options)
+            .await
+            .unwrap();
+        assert_eq!(
+            outcome.pull_requests[0].changed_paths,
+            Some(vec![
+                "api/v1/types.go".to_string(),
+                "vendor/modules.txt".to_string()
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn commits_are_fetched_only_when_asked_for() {
+        let dir = tempfile::tempdir().unwrap();
+        let recorder = Recorder::create(dir.path()).unwrap();
+        let repo = Repo::new("owner", "repo").unwrap();
+        let search = GraphQLQueryBuilder::search_pull_requests()
+            .with_search_query("is:pr")
+            .with_after_cursor(None)
+            .build();
+        let response = serde_json::json!({ "data": { "search": {
+            "nodes": [search_node(1)],
+            "pageInfo": { "hasNextPage": false, "endCursor": null }
+        } } });
+        recorder.record(&search, &response).unwrap();
+        let request = GraphQLQueryBuilder::pull_request_commits(&repo, 1)
+            .with_after_cursor(None)
+            .build();
+        let response = serde_json::json!({ "data": { "repository": { "pullRequest": {
+            "commits": {
+                "nodes": [{ "commit": {
+                    "oid": "0123456789abcdef0123456789abcdef01234567",
+                    "messageHeadline": "Bump serde",
+                    "message": "Bump serde\n\nSigned-off-by: dependabot[bot] <support@github.com>",
+                    "author": { "name": "dependabot[bot]", "user": null },
+                    "committer": { "name": "GitHub", "user": { "login": "web-flow" } },
+                    "signature": { "isValid": true }
+                } }],
+                "pageInfo": { "hasNextPage": false, "endCursor": null }
+            }
+        } } } });
+        recorder.record(&request, &response).unwrap();
+        let transport = GraphQLTransport::Replay(Replay::load(dir.path()).unwrap());
+
+        let outcome = paginate_search(&transport, "is:pr", 10, None, &FetchOptions::default())
+            .await
+            .unwrap();
+        assert!(outcome.pull_requests[0].commits.is_none());
+
+        let options = FetchOptions {
+            commits: true,
+            ..FetchOptions::default()
+        };
+        let outcome = paginate_search(&transport, "is:pr", 10, None, &options)
+            .await
+            .unwrap();
+        let commits = outcome.pull_requests[0].commits.as_ref().unwrap();
+        assert_eq!(commits.len(), 1);
+        assert_eq!(commits[0].headline, "Bump serde");
+        assert_eq!(commits[0].author.as_deref(), Some("dependabot[bot]"));
+        assert_eq!(commits[0].committer.as_deref(), Some("web-flow"));
+        assert_eq!(commits[0].signature, SignatureState::Verified);
+        assert!(commits[0].is_signed_off());
+    }
+
+    #[tokio::test]
+    async fn organisation_sweep_searches_matching_unarchived_repositories() {
+        let repository = |name: &str, archived: bool| serde_json::json!({ "name": name, "isArchived": archived });
+        let dir = tempfile::tempdir().unwrap();
+        let recorder = Recorder::create(dir.path()).unwrap();
+        let pages = [
+            (
+                None,
+                vec![
+                    repository("api-operator", false),
+                    repository("dns-operator", true),
+                ],
+                Some("r1"),
+            ),
+            (
+                Some("r1".to_string()),
+                vec![
+                    repository("legacy-operator", false),
+                    repository("router", false),
+                    repository("ingress-operator", false),
+                ],
+                None,
+            ),
+        ];
+        for (after, nodes, next) in pages {
+            let request = GraphQLQueryBuilder::owner_repositories("openshift")
+                .with_after_cursor(after)
+                .build();
+            let response = serde_json::json!({ "data": { "repositoryOwner": { "repositories": {
+                "nodes": nodes,
+                "pageInfo": { "hasNextPage": next.is_some(), "endCursor": next }
+            } } } });
+            recorder.record(&request, &response).unwrap();
+        }
+        for (name, number) in [("api-operator", 1), ("ingress-operator", 2)] {
+            let repo = Repo::new("openshift", name).unwrap();
+            let request = GraphQLQueryBuilder::search_pull_requests()
+                .with_search_query(&search::build_repo_search_query(&repo, &[]))
+                .with_after_cursor(None)
+                .build();
+            let response = serde_json::json!({ "data": { "search": {
+                "nodes": [search_node(number)],
+                "pageInfo": { "hasNextPage": false, "endCursor": null }
+            } } });
+            recorder.record(&request, &response).unwrap();
+        }
+        let transport = GraphQLTransport::Replay(Replay::load(dir.path()).unwrap());
+
+        let sweep = |include: &str| FetchPlan::OrganisationSweep {
+            sweep: OrgSweep {
+                org: "openshift".to_string(),
+                include: vec![include.to_string()],
+                exclude: vec!["legacy-*".to_string()],
+            },
+            criteria: vec![],
+            limit: 10,
+        };
+        let outcome = fetch_with_transport(
+            &transport,
+            None,
+            &sweep("*-operator"),
+            &FetchOptions::default(),
+            2,
+        )
+        .await
+        .unwrap();
+
+        let repos: Vec<_> = outcome
+            .pull_requests
+            .iter()
+            .map(|pr| pr.repo.name())
+            .collect();
+        assert_eq!(repos, vec!["api-operator", "ingress-operator"]);
+
+        let err = fetch_with_transport(
+            &transport,
+            None,
+            &sweep("console*"),
+            &FetchOptions::default(),
+            2,
+        )
+        .await
+        .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("No unarchived repositories in 'openshift'")
+        );
+    }
+
+    fn rest_client(server: &wiremock::MockServer) -> Octocrab {
+        Octocrab::builder()
+            .base_uri(server.uri())
+            .unwrap()
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn repository_verification_is_cached_and_revalidated() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{header, method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo"))
+            .and(header("if-none-match", "\"v1\""))
+            .respond_with(ResponseTemplate::new(304))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("etag", "\"v1\"")
+                    .set_body_json(serde_json::json!({ "full_name": "owner/repo" })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        let rest = rest_client(&server);
+        let repo = Repo::new("owner", "repo").unwrap();
+        let root = tempfile::tempdir().unwrap();
+
+        // With no TTL, every check goes to GitHub: the first fetches
+        // the ETag, the second revalidates it.
+        let stale = Cache::open(root.path(), "github.com", "token", Duration::ZERO).unwrap();
+        verify_repository_exists(&rest, &repo, Some(&stale))
+            .await
+            .unwrap();
+        verify_repository_exists(&rest, &repo, Some(&stale))
+            .await
+            .unwrap();
+
+        // Within the TTL the check is not repeated at all.
+        let fresh = Cache::open(root.path(), "github.com", "token", CACHE_TTL).unwrap();
+        verify_repository_exists(&rest, &repo, Some(&fresh))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn cached_search_pages_are_served_only_when_not_acting() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "search": {
+                    "nodes": [search_node(1)],
+                    "pageInfo": { "hasNextPage": false, "endCursor": null }
+                } }
+            })))
+            .expect(2)
+            .mount(&server)
+            .await;
+        let root = tempfile::tempdir().unwrap();
+        let transport = |serve_cached| GraphQLTransport::Live {
+            client: GraphQLClient::Octocrab(rest_client(&server)),
+            recorder: None,
+            cache: Some(Cache::open(root.path(), "github.com", "token", CACHE_TTL).unwrap()),
+            serve_cached,
+        };
+
+        // The first run stores the page; a run that will act asks
+        // again anyway; a listing run then reuses what was stored.
+        for serve_cached in [true, false, true] {
+            let outcome = paginate_search(
+                &transport(serve_cached),
+                "is:pr",
+                10,
+                None,
+                &FetchOptions::default(),
+            )
+            .await
+            .unwrap();
+            assert_eq!(pr_numbers(&outcome.pull_requests), vec![1]);
+        }
+    }
+
+    #[tokio::test]
+    async fn errored_graphql_responses_are_not_cached() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": null,
+                "errors": [{ "type": "RATE_LIMITED", "message": "API rate limit exceeded" }]
+            })))
+            .expect(2)
+            .mount(&server)
+            .await;
+        let root = tempfile::tempdir().unwrap();
+        let transport = GraphQLTransport::Live {
+            client: GraphQLClient::Octocrab(rest_client(&server)),
+            recorder: None,
+            cache: Some(Cache::open(root.path(), "github.com", "token", CACHE_TTL).unwrap()),
+            serve_cached: true,
+        };
+
+        for _ in 0..2 {
+            let response: serde_json::Value = execute_graphql_query(
+                &transport,
+                serde_json::json!({ "query": "{ viewer { login } }" }),
+                "test",
+            )
+            .await
+            .unwrap();
+            assert!(response["errors"].is_array());
+        }
+    }
+
+    #[tokio::test]
+    async fn missing_repository_is_not_cached() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/gone"))
+            .respond_with(
+                ResponseTemplate::new(404)
+                    .set_body_json(serde_json::json!({ "message": "Not Found" })),
+            )
+            .expect(2)
+            .mount(&server)
+            .await;
+        let rest = rest_client(&server);
+        let repo = Repo::new("owner", "gone").unwrap();
+        let root = tempfile::tempdir().unwrap();
+        let cache = Cache::open(root.path(), "github.com", "token", CACHE_TTL).unwrap();
+
+        for _ in 0..2 {
+            let err = verify_repository_exists(&rest, &repo, Some(&cache))
+                .await
+                .unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "Repository 'owner/gone' does not exist or is not accessible"
+            );
+        }
+    }
+
+    fn app_vars<'a>(vars: &'a [(&str, &str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        |name| {
+            vars.iter()
+                .find(|(var, _)| *var == name)
+                .map(|(_, value)| value.to_string())
+        }
+    }
+
+    fn test_app_key() -> PathBuf {
+        PathBuf::from(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/github-app-test-key.pem"
+        ))
+    }
+
+    #[test]
+    fn app_credentials_are_optional_but_all_or_nothing() {
+        assert_eq!(AppCredentials::from_vars(app_vars(&[])).unwrap(), None);
+
+        let credentials = AppCredentials::from_vars(app_vars(&[
+            (APP_ID_VAR, "12"),
+            (APP_PRIVATE_KEY_VAR, "/etc/autoprat/app.pem"),
+            (APP_INSTALLATION_ID_VAR, "34"),
+        ]))
+        .unwrap();
+        assert_eq!(
+            credentials,
+            Some(AppCredentials {
+                app_id: 12,
+                private_key_path: PathBuf::from("/etc/autoprat/app.pem"),
+                installation_id: 34,
+            })
+        );
+
+        let err = AppCredentials::from_vars(app_vars(&[(APP_ID_VAR, "12")])).unwrap_err();
+        assert!(
+            err.to_string().ends_with(&format!(
+                "{APP_PRIVATE_KEY_VAR}, {APP_INSTALLATION_ID_VAR} not set"
+            )),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn app_ids_must_be_numbers() {
+        let err = AppCredentials::from_vars(app_vars(&[
+            (APP_ID_VAR, "my-app"),
+            (APP_PRIVATE_KEY_VAR, "/etc/autoprat/app.pem"),
+            (APP_INSTALLATION_ID_VAR, "34"),
+        ]))
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("{APP_ID_VAR} must be a number, got 'my-app'")
+        );
+    }
+
+    #[tokio::test]
+    async fn app_installation_token_is_exchanged_and_refreshed() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{header, method, path},
+        };
+
+        let server = MockServer::start().await;
+        // Already inside the refresh margin, so every request needs a
+        // new installation token.
+        let expires_at = (Utc::now() + chrono::Duration::seconds(5)).to_rfc3339();
+        Mock::given(method("POST"))
+            .and(path("/app/installations/34/access_tokens"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "token": "ghs_installation",
+                "expires_at": expires_at,
+                "permissions": {}
+            })))
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rate_limit"))
+            .and(header("authorization", "Bearer ghs_installation"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let credentials = AppCredentials {
+            app_id: 12,
+            private_key_path: test_app_key(),
+            installation_id: 34,
+        };
+        let auth = ClientAuth::App {
+            key: credentials.load_key().unwrap(),
+            credentials,
+        };
+        let (client, _) = build_clients(&server.uri(), &server.uri(), &auth)
+            .await
+            .unwrap();
+
+        for _ in 0..2 {
+            let _: serde_json::Value = client.get("/rate_limit", None::<&()>).await.unwrap();
+        }
+        assert_eq!(auth.identity(), "app 12 installation 34");
+    }
+
+    /// Mounts an installation token exchange on the GHES REST base
+    /// whose tokens expire `lifetime` after they are issued, expecting
+    /// `exchanges` of them.
+    async fn mount_enterprise_token_exchange(
+        server: &wiremock::MockServer,
+        lifetime: TimeDelta,
+        exchanges: u64,
+    ) {
+        use wiremock::{
+            Mock, ResponseTemplate,
+            matchers::{method, path},
+        };
+
+        let expires_at = (Utc::now() + lifetime).to_rfc3339();
+        Mock::given(method("POST"))
+            .and(path("/api/v3/app/installations/34/access_tokens"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "token": "ghs_installation",
+                "expires_at": expires_at,
+                "permissions": {}
+            })))
+            .expect(exchanges)
+            .mount(server)
+            .await;
+    }
+
+    async fn enterprise_app_clients(server: &wiremock::MockServer) -> (Octocrab, GraphQLClient) {
+        let credentials = AppCredentials {
+            app_id: 12,
+            private_key_path: test_app_key(),
+            installation_id: 34,
+        };
+        let auth = ClientAuth::App {
+            key: credentials.load_key().unwrap(),
+            credentials,
+        };
+        build_clients(
+            &format!("{}/api/v3", server.uri()),
+            &format!("{}/api", server.uri()),
+            &auth,
+        )
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn enterprise_app_installation_tokens_are_exchanged_on_the_rest_base() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{header, method, path},
+        };
+
+        let server = MockServer::start().await;
+        // One token for REST and one for GraphQL, each good for the
+        // whole run.
+        mount_enterprise_token_exchange(&server, TimeDelta::hours(1), 2).await;
+        Mock::given(method("GET"))
+            .and(path("/api/v3/rate_limit"))
+            .and(header("authorization", "Bearer ghs_installation"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/graphql"))
+            .and(header("authorization", "Bearer ghs_installation"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"data": {}})))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let (rest, graphql) = enterprise_app_clients(&server).await;
+
+        let _: serde_json::Value = rest.get("/rate_limit", None::<&()>).await.unwrap();
+        for _ in 0..2 {
+            graphql
+                .graphql(&serde_json::json!({"query": "{ viewer { login } }"}))
+                .await
+                .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn enterprise_app_graphql_token_is_refreshed_before_it_expires() {
+        use wiremock::{
+            Mock, MockServer, ResponseTemplate,
+            matchers::{header, method, path},
+        };
+
+        let server = MockServer::start().await;
+        // Already inside the refresh margin, so setup and each query
+        // exchange a new token.
+        mount_enterprise_token_exchange(&server, TimeDelta::minutes(1), 3).await;
+        Mock::given(method("POST"))
+            .and(path("/api/graphql"))
+            .and(header("authorization", "Bearer ghs_installation"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"data": {}})))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let (_, graphql) = enterprise_app_clients(&server).await;
+
+        for _ in 0..2 {
+            graphql
+                .graphql(&serde_json::json!({"query": "{ viewer { login } }"}))
+                .await
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn unreadable_app_key_names_the_file() {
+        let credentials = AppCredentials {
+            app_id: 12,
+            private_key_path: PathBuf::from("/nonexistent/app.pem"),
+            installation_id: 34,
+        };
+
+        let err = credentials.load_key().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Failed to read GitHub App private key '/nonexistent/app.pem'"
+        );
+    }
+
+    #[test]
+    fn budget_concurrency_keeps_request_when_budget_allows() {
+        assert_eq!(budget_concurrency(4, Some(5000)), 4);
+        assert_eq!(budget_concurrency(4, None), 4);
+    }
+
+    #[test]
+    fn budget_concurrency_shrinks_with_remaining_points() {
+        assert_eq!(budget_concurrency(8, Some(120)), 2);
+        assert_eq!(budget_concurrency(8, Some(10)), 1);
+        assert_eq!(budget_concurrency(8, Some(0)), 1);
+    }
+
+    #[test]
+    fn budget_concurrency_is_never_zero() {
+        assert_eq!(budget_concurrency(0, None), 1);
+    }
+
+    #[test]
+    fn github_com_serves_both_apis_from_api_host() {
+        assert_eq!(
+            api_base_urls("github.com"),
+            (
+                "https://api.github.com".to_string(),
+                "https://api.github.com".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn enterprise_host_splits_rest_and_graphql_bases() {
+        assert_eq!(
+            api_base_urls("github.example.com"),
+            (
+                "https://github.example.com/api/v3".to_string(),
+                "https://github.example.com/api".to_string()
+            )
+        );
     }
 
     #[test]
@@ -608,7 +2817,7 @@ mod tests {
 
     #[test]
     fn take_until_limit_handles_empty_pages() {
-        let (taken, reached_limit) = take_until_limit(Vec::new(), 8, 10);
+        let (taken, reached_limit) = take_until_limit(Vec::<PullRequest>::new(), 8, 10);
 
         assert!(taken.is_empty());
         assert!(!reached_limit);
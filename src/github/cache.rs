@@ -0,0 +1,318 @@
+//! On-disk cache of GitHub responses, for runs repeated minutes apart.
+//!
+//! Each entry is one JSON file holding the request key, when it was
+//! stored, the response body, and the response's ETag if it had one.
+//! An entry younger than the cache's TTL is served as is; an older one
+//! can still revalidate a REST request with `If-None-Match`, which
+//! GitHub answers with a 304 that does not count against the rate
+//! limit.
+//!
+//! Nothing here is needed for correctness: an unreadable, corrupt, or
+//! unwritable entry is a miss, never an error.
+//!
+//! Entries can hold private repositories' data, so on Unix each
+//! identity's directory is readable by its owner alone, as is every
+//! entry in it.
+
+use std::{
+    fs::{DirBuilder, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tracing::debug;
+
+/// How long a cached response is served without asking GitHub.
+///
+/// Long enough to cover inspecting a selection and then acting on it,
+/// short enough that a `watch` loop still sees new pushes and labels.
+pub(crate) const CACHE_TTL: Duration = Duration::from_secs(120);
+
+/// Entries untouched for this long are removed when a cache is opened.
+const EXPIRE_AFTER: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+const FILE_SUFFIX: &str = ".json";
+
+/// The user's cache directory for autoprat: `$XDG_CACHE_HOME/autoprat`,
+/// or `~/.cache/autoprat` when that is unset.
+pub(crate) fn default_dir() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))?;
+    Some(base.join("autoprat"))
+}
+
+/// SHA-256 of `value` in hex: the same in every build, and not
+/// reversible to a token.
+fn fingerprint(value: &str) -> String {
+    format!("{:x}", Sha256::digest(value.as_bytes()))
+}
+
+/// Whether `name` is a directory name from before fingerprints were
+/// SHA-256: 16 hex digits.
+fn is_legacy_fingerprint(name: &str) -> bool {
+    name.len() == 16 && name.bytes().all(|byte| byte.is_ascii_hexdigit())
+}
+
+/// Creates `dir` and any missing parents, readable by the user alone,
+/// and takes away others' access to it if it already existed.
+fn create_private_dir(dir: &Path) -> std::io::Result<()> {
+    let mut builder = DirBuilder::new();
+    builder.recursive(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::DirBuilderExt;
+        builder.mode(0o700);
+    }
+    builder.create(dir)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(dir, std::fs::Permissions::from_mode(0o700))?;
+    }
+    Ok(())
+}
+
+/// One cached response.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Entry {
+    key: String,
+    pub(crate) stored_at: DateTime<Utc>,
+    pub(crate) etag: Option<String>,
+    pub(crate) body: serde_json::Value,
+}
+
+/// A directory of cached responses for one host and one identity.
+pub(crate) struct Cache {
+    dir: PathBuf,
+    ttl: Duration,
+}
+
+impl Cache {
+    /// Opens the cache under `root` for `host`, as seen by `identity`
+    /// (a token, or a GitHub App installation).
+    ///
+    /// Different identities can see different repositories, so each
+    /// gets its own directory, named by a hash rather than the identity
+    /// itself.
+    pub(crate) fn open(root: &Path, host: &str, identity: &str, ttl: Duration) -> Result<Self> {
+        let host_dir = root.join(host);
+        let dir = host_dir.join(fingerprint(identity));
+        create_private_dir(&dir)
+            .with_context(|| format!("Failed to create cache directory '{}'", dir.display()))?;
+
+        let cache = Self { dir, ttl };
+        cache.remove_expired();
+        remove_legacy_dirs(&host_dir);
+        Ok(cache)
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{}{FILE_SUFFIX}", fingerprint(key)))
+    }
+
+    /// The entry stored for `key`, however old.
+    pub(crate) fn get(&self, key: &str) -> Option<Entry> {
+        let contents = std::fs::read(self.path(key)).ok()?;
+        match serde_json::from_slice::<Entry>(&contents) {
+            // A hash collision would otherwise serve another request's
+            // response.
+            Ok(entry) if entry.key == key => Some(entry),
+            Ok(_) => None,
+            Err(e) => {
+                debug!(key = key, error = %e, "Ignoring unreadable cache entry");
+                None
+            }
+        }
+    }
+
+    /// The body stored for `key`, if it is younger than the TTL.
+    pub(crate) fn fresh(&self, key: &str, now: DateTime<Utc>) -> Option<serde_json::Value> {
+        self.get(key)
+            .filter(|entry| self.is_fresh(entry, now))
+            .map(|entry| entry.body)
+    }
+
+    pub(crate) fn is_fresh(&self, entry: &Entry, now: DateTime<Utc>) -> bool {
+        (now - entry.stored_at)
+            .to_std()
+            .is_ok_and(|age| age < self.ttl)
+    }
+
+    /// Stores `body` for `key`, replacing any earlier entry.
+    pub(crate) fn put(
+        &self,
+        key: &str,
+        etag: Option<String>,
+        body: &serde_json::Value,
+        now: DateTime<Utc>,
+    ) {
+        let entry = Entry {
+            key: key.to_string(),
+            stored_at: now,
+            etag,
+            body: body.clone(),
+        };
+        if let Err(e) = self.write(key, &entry) {
+            debug!(key = key, error = %e, "Failed to write cache entry");
+        }
+    }
+
+    fn write(&self, key: &str, entry: &Entry) -> Result<()> {
+        let path = self.path(key);
+        // Write then rename, so a concurrent run never reads half an
+        // entry.
+        let partial = path.with_extension(format!("{}.tmp", std::process::id()));
+        let mut options = OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        options
+            .open(&partial)?
+            .write_all(&serde_json::to_vec(entry)?)?;
+        std::fs::rename(&partial, &path)?;
+        Ok(())
+    }
+
+    fn remove_expired(&self) {
+        let Ok(entries) = std::fs::read_dir(&self.dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let expired = entry
+                .metadata()
+                .and_then(|metadata| metadata.modified())
+                .is_ok_and(|modified| {
+                    modified
+                        .elapsed()
+                        .is_ok_and(|elapsed| elapsed > EXPIRE_AFTER)
+                });
+            if expired {
+                let _ = std::fs::remove_file(entry.path());
+            }
+        }
+    }
+}
+
+/// Removes the directories of caches written before fingerprints were
+/// SHA-256, whose entries were readable by anyone. Nothing reads them
+/// any more.
+fn remove_legacy_dirs(host_dir: &Path) {
+    let Ok(entries) = std::fs::read_dir(host_dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let legacy = entry
+            .file_name()
+            .to_str()
+            .is_some_and(is_legacy_fingerprint);
+        if legacy && entry.file_type().is_ok_and(|kind| kind.is_dir()) {
+            let _ = std::fs::remove_dir_all(entry.path());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn entries_are_fresh_until_the_ttl_passes() {
+        let root = tempfile::tempdir().unwrap();
+        let cache = Cache::open(root.path(), "github.com", "token", CACHE_TTL).unwrap();
+        cache.put(
+            "key",
+            Some("\"abc\"".to_string()),
+            &json!({ "n": 1 }),
+            now(),
+        );
+
+        assert_eq!(cache.fresh("key", now()), Some(json!({ "n": 1 })));
+        let later = now() + chrono::Duration::seconds(CACHE_TTL.as_secs() as i64);
+        assert_eq!(cache.fresh("key", later), None);
+
+        let stale = cache.get("key").unwrap();
+        assert_eq!(stale.etag.as_deref(), Some("\"abc\""));
+        assert_eq!(cache.fresh("other", now()), None);
+    }
+
+    #[test]
+    fn tokens_do_not_share_entries() {
+        let root = tempfile::tempdir().unwrap();
+        let alice = Cache::open(root.path(), "github.com", "alice", CACHE_TTL).unwrap();
+        let bob = Cache::open(root.path(), "github.com", "bob", CACHE_TTL).unwrap();
+        alice.put("key", None, &json!(1), now());
+
+        assert!(bob.get("key").is_none());
+        assert!(!root.path().join("github.com").join("alice").exists());
+    }
+
+    #[test]
+    fn identities_are_named_by_their_sha256() {
+        let root = tempfile::tempdir().unwrap();
+        Cache::open(root.path(), "github.com", "token", CACHE_TTL).unwrap();
+
+        assert!(
+            root.path()
+                .join("github.com")
+                .join("3c469e9d6c5875d37a43f353d4f88e61fcf812c66eee3457465a40b0da4153e0")
+                .is_dir()
+        );
+    }
+
+    #[test]
+    fn legacy_identity_dirs_are_removed() {
+        let root = tempfile::tempdir().unwrap();
+        let legacy = root.path().join("github.com").join("0123456789abcdef");
+        std::fs::create_dir_all(&legacy).unwrap();
+        std::fs::write(legacy.join("entry.json"), "{}").unwrap();
+
+        Cache::open(root.path(), "github.com", "token", CACHE_TTL).unwrap();
+
+        assert!(!legacy.exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn entries_are_readable_by_their_owner_alone() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let root = tempfile::tempdir().unwrap();
+        let dir = root.path().join("github.com").join(fingerprint("token"));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::set_permissions(&dir, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let cache = Cache::open(root.path(), "github.com", "token", CACHE_TTL).unwrap();
+        cache.put("key", None, &json!(1), now());
+
+        let mode = |path: &Path| std::fs::metadata(path).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode(&dir), 0o700);
+        assert_eq!(mode(&cache.path("key")), 0o600);
+    }
+
+    #[test]
+    fn corrupt_entry_is_a_miss() {
+        let root = tempfile::tempdir().unwrap();
+        let cache = Cache::open(root.path(), "github.com", "token", CACHE_TTL).unwrap();
+        std::fs::write(cache.path("key"), "not json").unwrap();
+
+        assert!(cache.get("key").is_none());
+    }
+}
@@ -10,15 +10,20 @@
 //! is.
 
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use octocrab::models::{StatusState, workflows::Conclusion};
 
 use super::graphql::{
-    GraphQLCheckRunStatus, GraphQLCommentConnection, GraphQLPullRequest, GraphQLPullRequestState,
-    GraphQLStatusCheckRollup, GraphQLStatusContext,
+    GraphQLCheckRunStatus, GraphQLCommentConnection, GraphQLCommitDetailsConnection,
+    GraphQLGitActor, GraphQLIssueConnection, GraphQLLabelConnection, GraphQLMergeStateStatus,
+    GraphQLMergeableState, GraphQLPullRequest, GraphQLPullRequestState, GraphQLRequestedReviewer,
+    GraphQLReviewConnection, GraphQLReviewDecision, GraphQLReviewRequestConnection,
+    GraphQLReviewState, GraphQLStatusCheckRollup, GraphQLStatusContext,
 };
 use crate::types::{
-    CheckConclusion, CheckInfo, CheckName, CheckRunStatus, CheckState, CheckUrl, CommentInfo,
-    PrState, PullRequest, Repo,
+    CheckConclusion, CheckInfo, CheckName, CheckRunStatus, CheckState, CheckUrl, CommentAuthor,
+    CommentInfo, CommitInfo, LinkedIssue, MergeState, Mergeable, PrState, PullRequest, Repo,
+    ReviewDecision, ReviewInfo, ReviewState, SignatureState, parse_issue_keys,
 };
 
 fn convert_conclusion(conclusion: Conclusion) -> CheckConclusion {
@@ -63,13 +68,88 @@ fn convert_check_run_status(status: GraphQLCheckRunStatus) -> CheckRunStatus {
     }
 }
 
-fn convert_graphql_status_context(context: GraphQLStatusContext) -> CheckInfo {
-    match context {
+fn convert_mergeable(mergeable: GraphQLMergeableState) -> Mergeable {
+    match mergeable {
+        GraphQLMergeableState::Mergeable => Mergeable::Mergeable,
+        GraphQLMergeableState::Conflicting => Mergeable::Conflicting,
+        GraphQLMergeableState::Unknown => Mergeable::Unknown,
+    }
+}
+
+fn convert_merge_state(status: GraphQLMergeStateStatus) -> MergeState {
+    match status {
+        GraphQLMergeStateStatus::Behind => MergeState::Behind,
+        GraphQLMergeStateStatus::Blocked => MergeState::Blocked,
+        GraphQLMergeStateStatus::Clean => MergeState::Clean,
+        GraphQLMergeStateStatus::Dirty => MergeState::Dirty,
+        GraphQLMergeStateStatus::Draft => MergeState::Draft,
+        GraphQLMergeStateStatus::HasHooks => MergeState::HasHooks,
+        GraphQLMergeStateStatus::Unstable => MergeState::Unstable,
+        GraphQLMergeStateStatus::Unknown => MergeState::Unknown,
+    }
+}
+
+fn convert_review_decision(decision: GraphQLReviewDecision) -> ReviewDecision {
+    match decision {
+        GraphQLReviewDecision::Approved => ReviewDecision::Approved,
+        GraphQLReviewDecision::ChangesRequested => ReviewDecision::ChangesRequested,
+        GraphQLReviewDecision::ReviewRequired => ReviewDecision::ReviewRequired,
+    }
+}
+
+fn convert_review_state(state: GraphQLReviewState) -> ReviewState {
+    match state {
+        GraphQLReviewState::Approved => ReviewState::Approved,
+        GraphQLReviewState::ChangesRequested => ReviewState::ChangesRequested,
+        GraphQLReviewState::Commented => ReviewState::Commented,
+        GraphQLReviewState::Dismissed => ReviewState::Dismissed,
+        GraphQLReviewState::Pending => ReviewState::Pending,
+    }
+}
+
+/// Reviews from deleted accounts are dropped: there is no one left to
+/// filter on.
+fn convert_reviews(reviews: Option<GraphQLReviewConnection>) -> Vec<ReviewInfo> {
+    reviews.map_or_else(Vec::new, |reviews| {
+        reviews
+            .nodes
+            .into_iter()
+            .filter_map(|review| {
+                Some(ReviewInfo {
+                    reviewer: review.author?.login,
+                    state: convert_review_state(review.state),
+                })
+            })
+            .collect()
+    })
+}
+
+/// Users by login and teams as `org/team`.
+fn convert_review_requests(requests: Option<GraphQLReviewRequestConnection>) -> Vec<String> {
+    requests.map_or_else(Vec::new, |requests| {
+        requests
+            .nodes
+            .into_iter()
+            .filter_map(|request| match request.requested_reviewer? {
+                GraphQLRequestedReviewer::User { login }
+                | GraphQLRequestedReviewer::Bot { login }
+                | GraphQLRequestedReviewer::Mannequin { login } => Some(login),
+                GraphQLRequestedReviewer::Team { combined_slug } => Some(combined_slug),
+                GraphQLRequestedReviewer::Unknown => None,
+            })
+            .collect()
+    })
+}
+
+fn convert_graphql_status_context(context: GraphQLStatusContext, required: &[String]) -> CheckInfo {
+    let mut check = match context {
         GraphQLStatusContext::CheckRun {
             name,
             status,
             conclusion,
             details_url,
+            started_at,
+            completed_at,
         } => CheckInfo {
             name: CheckName::new(name.unwrap_or_else(|| "Unknown Check".to_string()))
                 .unwrap_or_else(|_| CheckName::new("Unknown").unwrap()),
@@ -77,11 +157,15 @@ fn convert_graphql_status_context(context: GraphQLStatusContext) -> CheckInfo {
             run_status: status.map(convert_check_run_status),
             status_state: None,
             url: details_url.and_then(|url| CheckUrl::new(&url).ok()),
+            required: false,
+            started_at,
+            completed_at,
         },
         GraphQLStatusContext::StatusContext {
             context,
             state,
             target_url,
+            created_at,
         } => CheckInfo {
             name: CheckName::new(context.unwrap_or_else(|| "Unknown Status".to_string()))
                 .unwrap_or_else(|_| CheckName::new("Unknown").unwrap()),
@@ -89,32 +173,113 @@ fn convert_graphql_status_context(context: GraphQLStatusContext) -> CheckInfo {
             run_status: None,
             status_state: state.map(convert_status_state),
             url: target_url.and_then(|url| CheckUrl::new(&url).ok()),
+            required: false,
+            // A status is replaced rather than updated, so this is when
+            // it was last set; a finished one has no start time.
+            started_at: Some(created_at),
+            completed_at: None,
         },
-    }
+    };
+    check.required = required.iter().any(|name| name == check.name.as_str());
+    check
 }
 
-fn convert_status_checks(rollup: Option<GraphQLStatusCheckRollup>) -> Vec<CheckInfo> {
+/// `required` names the checks the base branch needs to pass; see
+/// [`GraphQLPullRequest::required_checks`].
+pub(crate) fn convert_status_checks(
+    rollup: Option<GraphQLStatusCheckRollup>,
+    required: &[String],
+) -> Vec<CheckInfo> {
     rollup.map_or_else(Vec::new, |rollup| {
         rollup
             .contexts
             .nodes
             .into_iter()
-            .map(convert_graphql_status_context)
+            .map(|context| convert_graphql_status_context(context, required))
             .collect()
     })
 }
 
-fn convert_comments(comments: GraphQLCommentConnection) -> Vec<CommentInfo> {
+fn convert_linked_issues(issues: GraphQLIssueConnection) -> Vec<LinkedIssue> {
+    issues
+        .nodes
+        .into_iter()
+        .map(|issue| LinkedIssue {
+            number: issue.number,
+            title: issue.title,
+            url: issue.url,
+        })
+        .collect()
+}
+
+pub(crate) fn convert_labels(labels: GraphQLLabelConnection) -> Vec<String> {
+    labels.nodes.into_iter().map(|label| label.name).collect()
+}
+
+pub(crate) fn convert_commits(commits: GraphQLCommitDetailsConnection) -> Vec<CommitInfo> {
+    commits
+        .nodes
+        .into_iter()
+        .map(|node| {
+            let commit = node.commit;
+            CommitInfo {
+                sha: commit.oid,
+                headline: commit.message_headline,
+                message: commit.message,
+                author: commit.author.and_then(GraphQLGitActor::identity),
+                committer: commit.committer.and_then(GraphQLGitActor::identity),
+                signature: match commit.signature {
+                    Some(signature) if signature.is_valid => SignatureState::Verified,
+                    Some(_) => SignatureState::Unverified,
+                    None => SignatureState::Unsigned,
+                },
+            }
+        })
+        .collect()
+}
+
+pub(crate) fn convert_comments(comments: GraphQLCommentConnection) -> Vec<CommentInfo> {
     comments
         .nodes
         .into_iter()
         .map(|comment| CommentInfo {
             body: comment.body,
             created_at: comment.created_at,
+            author: comment.author.map(|author| CommentAuthor {
+                is_bot: author.actor_type.is_bot(),
+                login: author.login,
+            }),
         })
         .collect()
 }
 
+/// When the head branch was last pushed, as near as GitHub tells.
+///
+/// GitHub no longer reports push times. The latest force push is in
+/// the timeline with its time, so a rebase counts from when it was
+/// pushed whatever dates its commits keep. The head commit's commit
+/// time is no later than the push that brought it, and a PR is opened
+/// after its first push, so one opened from old commits does not read
+/// as long idle. A plain push of commits made well before it still
+/// reads as older than it was.
+fn last_push_time(graphql_pr: &GraphQLPullRequest) -> Option<DateTime<Utc>> {
+    let committed = graphql_pr
+        .commits
+        .nodes
+        .last()
+        .map(|node| node.commit.committed_date);
+    let force_pushed = graphql_pr
+        .timeline_items
+        .nodes
+        .last()
+        .map(|event| event.created_at);
+    committed
+        .into_iter()
+        .chain(force_pushed)
+        .chain(Some(graphql_pr.created_at))
+        .max()
+}
+
 /// Converts a GraphQL pull request to our domain model.
 ///
 /// Transforms GraphQL response data into a PullRequest struct,
@@ -124,8 +289,12 @@ pub(crate) fn convert_graphql_pr_to_pr_info(
     graphql_pr: GraphQLPullRequest,
     repo: Repo,
 ) -> Result<PullRequest> {
-    let checks = convert_status_checks(graphql_pr.status_check_rollup);
+    let last_pushed_at = last_push_time(&graphql_pr);
+    let required_checks = graphql_pr.required_checks();
+    let checks = convert_status_checks(graphql_pr.status_check_rollup, &required_checks);
+    let comment_history_complete = graphql_pr.comments.older_cursor().is_none();
     let recent_comments = convert_comments(graphql_pr.comments);
+    let issue_keys = parse_issue_keys(&[&graphql_pr.title, &graphql_pr.body]);
 
     Ok(PullRequest {
         repo,
@@ -141,21 +310,44 @@ pub(crate) fn convert_graphql_pr_to_pr_info(
             .map(|a| a.simple_name())
             .unwrap_or_else(|| "Unknown".to_string()),
         url: graphql_pr.url.to_string(),
-        labels: graphql_pr
-            .labels
-            .nodes
-            .into_iter()
-            .map(|label| label.name)
-            .collect(),
+        labels: convert_labels(graphql_pr.labels),
         created_at: graphql_pr.created_at,
+        updated_at: graphql_pr.updated_at,
+        last_pushed_at,
         base_branch: graphql_pr
             .base_ref_name
             .ok_or_else(|| anyhow::anyhow!("PR {} missing base branch", graphql_pr.number))?,
         commit_count: graphql_pr.commits.total_count,
+        additions: Some(graphql_pr.additions),
+        deletions: Some(graphql_pr.deletions),
+        changed_files: Some(graphql_pr.changed_files),
+        // Paged separately, and only when asked for.
+        changed_paths: None,
+        commits: None,
+        linked_issues: graphql_pr
+            .closing_issues_references
+            .map(convert_linked_issues),
+        issue_keys,
         is_draft: graphql_pr.is_draft,
         state: convert_pull_request_state(graphql_pr.state),
         checks,
         recent_comments,
+        comment_history_complete,
+        review_decision: graphql_pr.review_decision.map(convert_review_decision),
+        reviews: convert_reviews(graphql_pr.latest_reviews),
+        requested_reviewers: convert_review_requests(graphql_pr.review_requests),
+        assignees: graphql_pr
+            .assignees
+            .nodes
+            .into_iter()
+            .map(|assignee| assignee.login)
+            .collect(),
+        mergeable: convert_mergeable(graphql_pr.mergeable),
+        merge_state: convert_merge_state(graphql_pr.merge_state_status),
+        head_branch: graphql_pr.head_ref_name,
+        head_repo_owner: graphql_pr.head_repository_owner.map(|owner| owner.login),
+        head_sha: graphql_pr.head_ref_oid,
+        is_cross_repository: graphql_pr.is_cross_repository,
     })
 }
 
@@ -172,7 +364,7 @@ pub(crate) fn convert_graphql_pr_to_pr_info_with_url_parsing(
 
 #[cfg(test)]
 mod tests {
-    use chrono::DateTime;
+    use chrono::TimeDelta;
     use url::Url;
 
     use super::{super::graphql::*, *};
@@ -185,8 +377,34 @@ mod tests {
             state: GraphQLPullRequestState::Open,
             is_draft: false,
             created_at: DateTime::from_timestamp(1609459200, 0).unwrap(), // 2021-01-01.
+            updated_at: DateTime::from_timestamp(1609459400, 0).unwrap(),
+            additions: 12,
+            deletions: 3,
+            changed_files: 2,
             base_ref_name: Some("main".to_string()),
-            commits: GraphQLCommitConnection { total_count: 1 },
+            base_ref: Some(GraphQLBaseRef {
+                ref_update_rule: Some(GraphQLRefUpdateRule {
+                    required_status_check_contexts: Some(vec![
+                        Some("test-check".to_string()),
+                        None,
+                    ]),
+                }),
+            }),
+            head_ref_name: "fix-leak".to_string(),
+            head_ref_oid: "0123456789abcdef0123456789abcdef01234567".to_string(),
+            head_repository_owner: Some(GraphQLRepositoryOwner {
+                login: "testuser".to_string(),
+            }),
+            is_cross_repository: true,
+            commits: GraphQLCommitConnection {
+                total_count: 1,
+                nodes: vec![GraphQLPullRequestCommit {
+                    commit: GraphQLCommit {
+                        committed_date: DateTime::from_timestamp(1609459250, 0).unwrap(),
+                    },
+                }],
+            },
+            timeline_items: GraphQLForcePushConnection { nodes: vec![] },
             author: Some(GraphQLAuthor {
                 login: "testuser".to_string(),
                 actor_type: ActorType::User,
@@ -200,6 +418,57 @@ mod tests {
                         name: "priority/high".to_string(),
                     },
                 ],
+                page_info: PageInfo {
+                    has_next_page: false,
+                    end_cursor: None,
+                },
+            },
+            mergeable: GraphQLMergeableState::Conflicting,
+            merge_state_status: GraphQLMergeStateStatus::Dirty,
+            review_decision: Some(GraphQLReviewDecision::ChangesRequested),
+            latest_reviews: Some(GraphQLReviewConnection {
+                nodes: vec![
+                    GraphQLReview {
+                        state: GraphQLReviewState::Approved,
+                        author: Some(GraphQLAuthor {
+                            login: "bob".to_string(),
+                            actor_type: ActorType::User,
+                        }),
+                    },
+                    GraphQLReview {
+                        state: GraphQLReviewState::ChangesRequested,
+                        author: Some(GraphQLAuthor {
+                            login: "carol".to_string(),
+                            actor_type: ActorType::User,
+                        }),
+                    },
+                    GraphQLReview {
+                        state: GraphQLReviewState::Commented,
+                        author: None,
+                    },
+                ],
+            }),
+            review_requests: Some(GraphQLReviewRequestConnection {
+                nodes: vec![
+                    GraphQLReviewRequest {
+                        requested_reviewer: Some(GraphQLRequestedReviewer::User {
+                            login: "dave".to_string(),
+                        }),
+                    },
+                    GraphQLReviewRequest {
+                        requested_reviewer: Some(GraphQLRequestedReviewer::Team {
+                            combined_slug: "owner/reviewers".to_string(),
+                        }),
+                    },
+                    GraphQLReviewRequest {
+                        requested_reviewer: None,
+                    },
+                ],
+            }),
+            assignees: GraphQLAssigneeConnection {
+                nodes: vec![GraphQLRepositoryOwner {
+                    login: "erin".to_string(),
+                }],
             },
             status_check_rollup: Some(GraphQLStatusCheckRollup {
                 contexts: GraphQLStatusContextConnection {
@@ -209,13 +478,20 @@ mod tests {
                             status: Some(GraphQLCheckRunStatus::Completed),
                             conclusion: Some(Conclusion::Success),
                             details_url: Some("https://example.com/check/1".to_string()),
+                            started_at: DateTime::from_timestamp(1609459200, 0),
+                            completed_at: DateTime::from_timestamp(1609459500, 0),
                         },
                         GraphQLStatusContext::StatusContext {
                             context: Some("ci/build".to_string()),
                             state: Some(StatusState::Failure),
                             target_url: Some("https://example.com/build/1".to_string()),
+                            created_at: DateTime::from_timestamp(1609459600, 0).unwrap(),
                         },
                     ],
+                    page_info: PageInfo {
+                        has_next_page: false,
+                        end_cursor: None,
+                    },
                 },
             }),
             comments: GraphQLCommentConnection {
@@ -223,16 +499,94 @@ mod tests {
                     GraphQLComment {
                         body: "/lgtm".to_string(),
                         created_at: DateTime::from_timestamp(1609459300, 0).unwrap(),
+                        author: Some(GraphQLAuthor {
+                            login: "reviewer".to_string(),
+                            actor_type: ActorType::User,
+                        }),
                     },
                     GraphQLComment {
                         body: "Looks good to me!".to_string(),
                         created_at: DateTime::from_timestamp(1609459400, 0).unwrap(),
+                        author: Some(GraphQLAuthor {
+                            login: "openshift-ci".to_string(),
+                            actor_type: ActorType::Bot,
+                        }),
                     },
                 ],
+                page_info: CommentPageInfo {
+                    has_previous_page: false,
+                    start_cursor: None,
+                },
             },
+            closing_issues_references: Some(GraphQLIssueConnection {
+                nodes: vec![GraphQLIssue {
+                    number: 7,
+                    title: "Crash on start".to_string(),
+                    url: "https://github.com/owner/repo/issues/7".to_string(),
+                }],
+            }),
+            body: "Fixes #7 and OCPBUGS-12345.\n\nLike OCPBUGS-12345, see JIRA-1.".to_string(),
         }
     }
 
+    #[test]
+    fn test_convert_graphql_pr_keeps_linked_issues_and_issue_keys() {
+        let pr_info = convert_graphql_pr_to_pr_info(
+            create_test_graphql_pr(),
+            Repo::new("owner", "repo").unwrap(),
+        )
+        .unwrap();
+
+        let linked = pr_info.linked_issues.as_deref().unwrap();
+        assert_eq!(linked.len(), 1);
+        assert_eq!(linked[0].number, 7);
+        assert_eq!(linked[0].url, "https://github.com/owner/repo/issues/7");
+        assert_eq!(pr_info.issue_keys, vec!["OCPBUGS-12345", "JIRA-1"]);
+        assert_eq!(pr_info.references_issue(None), Some(true));
+
+        // Key-shaped words alone do not link a PR that closes nothing.
+        let mut bare = create_test_graphql_pr();
+        bare.title = "Bump SHA-256 helper to UTF-8".to_string();
+        bare.closing_issues_references = Some(GraphQLIssueConnection { nodes: vec![] });
+        bare.body = String::new();
+        let bare =
+            convert_graphql_pr_to_pr_info(bare, Repo::new("owner", "repo").unwrap()).unwrap();
+        assert_eq!(bare.issue_keys, vec!["SHA-256", "UTF-8"]);
+        assert_eq!(bare.references_issue(None), Some(false));
+    }
+
+    #[test]
+    fn last_push_counts_from_a_force_push_not_the_rebased_commit_dates() {
+        // A rebase that keeps its commits' old committer dates, pushed
+        // long after the PR was opened.
+        let opened = DateTime::from_timestamp(1609459200, 0).unwrap();
+        let committed = opened - TimeDelta::days(30);
+        let force_pushed = opened + TimeDelta::days(10);
+        let mut graphql_pr = create_test_graphql_pr();
+        graphql_pr.commits.nodes[0].commit.committed_date = committed;
+        graphql_pr.timeline_items = GraphQLForcePushConnection {
+            nodes: vec![GraphQLForcePush {
+                created_at: force_pushed,
+            }],
+        };
+
+        let pr_info =
+            convert_graphql_pr_to_pr_info(graphql_pr, Repo::new("owner", "repo").unwrap()).unwrap();
+        assert_eq!(pr_info.last_pushed_at, Some(force_pushed));
+    }
+
+    #[test]
+    fn last_push_is_no_earlier_than_the_pr_being_opened() {
+        // Commits made a month before they were pushed to open the PR.
+        let opened = DateTime::from_timestamp(1609459200, 0).unwrap();
+        let mut graphql_pr = create_test_graphql_pr();
+        graphql_pr.commits.nodes[0].commit.committed_date = opened - TimeDelta::days(30);
+
+        let pr_info =
+            convert_graphql_pr_to_pr_info(graphql_pr, Repo::new("owner", "repo").unwrap()).unwrap();
+        assert_eq!(pr_info.last_pushed_at, Some(opened));
+    }
+
     #[test]
     fn test_convert_graphql_pr_to_pr_info_with_repo_context() {
         let graphql_pr = create_test_graphql_pr();
@@ -249,6 +603,28 @@ mod tests {
         assert_eq!(pr_info.author_simple_name, "testuser");
         assert_eq!(pr_info.url, "https://github.com/owner/repo/pull/123");
         assert_eq!(pr_info.labels, vec!["bug", "priority/high"]);
+        assert_eq!(pr_info.changed_lines(), Some(15));
+        assert_eq!(pr_info.changed_files, Some(2));
+        assert_eq!(pr_info.head_branch, "fix-leak");
+        assert_eq!(pr_info.head_repo_owner.as_deref(), Some("testuser"));
+        assert_eq!(pr_info.head_sha, "0123456789abcdef0123456789abcdef01234567");
+        assert!(pr_info.is_cross_repository);
+        assert_eq!(
+            pr_info.updated_at,
+            DateTime::from_timestamp(1609459400, 0).unwrap()
+        );
+        assert_eq!(
+            pr_info.last_pushed_at,
+            DateTime::from_timestamp(1609459250, 0)
+        );
+        assert_eq!(
+            pr_info.last_commented_at(),
+            DateTime::from_timestamp(1609459400, 0)
+        );
+        assert!(pr_info.recent_comments[0].is_by("reviewer"));
+        assert!(!pr_info.recent_comments[0].is_from_bot());
+        assert!(pr_info.recent_comments[1].is_by("openshift-ci[bot]"));
+        assert!(pr_info.recent_comments[1].is_from_bot());
         assert_eq!(pr_info.checks.len(), 2);
         assert_eq!(pr_info.recent_comments.len(), 2);
 
@@ -261,6 +637,15 @@ mod tests {
         assert_eq!(check2.name.as_str(), "ci/build");
         assert_eq!(check2.conclusion, None);
         assert_eq!(check2.status_state, Some(CheckState::Failure));
+
+        assert!(check1.required && !check2.required);
+        assert!(pr_info.has_failing_ci());
+        assert!(!pr_info.has_failing_required_ci());
+
+        assert_eq!(check1.started_at, DateTime::from_timestamp(1609459200, 0));
+        assert_eq!(check1.completed_at, DateTime::from_timestamp(1609459500, 0));
+        assert_eq!(check2.started_at, DateTime::from_timestamp(1609459600, 0));
+        assert_eq!(check2.completed_at, None);
     }
 
     #[test]
@@ -332,13 +717,20 @@ mod tests {
                         status: Some(GraphQLCheckRunStatus::Completed),
                         conclusion: Some(Conclusion::Success),
                         details_url: Some("https://example.com/check/1".to_string()),
+                        started_at: None,
+                        completed_at: None,
                     },
                     GraphQLStatusContext::StatusContext {
                         context: Some("".to_string()), // Empty name.
                         state: Some(StatusState::Success),
                         target_url: None,
+                        created_at: DateTime::from_timestamp(1609459600, 0).unwrap(),
                     },
                 ],
+                page_info: PageInfo {
+                    has_next_page: false,
+                    end_cursor: None,
+                },
             },
         });
 
@@ -363,7 +755,13 @@ mod tests {
                     status: Some(GraphQLCheckRunStatus::Completed),
                     conclusion: Some(Conclusion::Success),
                     details_url: Some("not-a-valid-url".to_string()),
+                    started_at: None,
+                    completed_at: None,
                 }],
+                page_info: PageInfo {
+                    has_next_page: false,
+                    end_cursor: None,
+                },
             },
         });
 
@@ -376,6 +774,86 @@ mod tests {
         assert!(pr_info.checks[0].url.is_none());
     }
 
+    #[test]
+    fn test_convert_graphql_pr_to_pr_info_with_reviews() {
+        let repo = Repo::new("owner", "repo").unwrap();
+        let pr_info = convert_graphql_pr_to_pr_info(create_test_graphql_pr(), repo).unwrap();
+
+        assert_eq!(
+            pr_info.review_decision,
+            Some(ReviewDecision::ChangesRequested)
+        );
+        let reviews: Vec<_> = pr_info
+            .reviews
+            .iter()
+            .map(|review| (review.reviewer.as_str(), review.state))
+            .collect();
+        assert_eq!(
+            reviews,
+            vec![
+                ("bob", ReviewState::Approved),
+                ("carol", ReviewState::ChangesRequested)
+            ]
+        );
+        assert!(pr_info.reviewed_by("carol"));
+        assert!(!pr_info.reviewed_by("alice"));
+        assert!(pr_info.reviewed_by("Carol"));
+    }
+
+    #[test]
+    fn test_convert_graphql_pr_to_pr_info_with_merge_state() {
+        let repo = Repo::new("owner", "repo").unwrap();
+        let pr_info = convert_graphql_pr_to_pr_info(create_test_graphql_pr(), repo).unwrap();
+        assert_eq!(pr_info.mergeable, Mergeable::Conflicting);
+        assert_eq!(pr_info.merge_state, MergeState::Dirty);
+        assert!(pr_info.is_conflicting());
+
+        let mut graphql_pr = create_test_graphql_pr();
+        graphql_pr.mergeable = GraphQLMergeableState::Unknown;
+        graphql_pr.merge_state_status = GraphQLMergeStateStatus::Unknown;
+        let repo = Repo::new("owner", "repo").unwrap();
+        let pr_info = convert_graphql_pr_to_pr_info(graphql_pr, repo).unwrap();
+        assert_eq!(pr_info.mergeable, Mergeable::Unknown);
+        assert_eq!(pr_info.merge_state, MergeState::Unknown);
+        assert!(!pr_info.is_conflicting());
+    }
+
+    #[test]
+    fn test_convert_graphql_pr_to_pr_info_without_reviews() {
+        let mut graphql_pr = create_test_graphql_pr();
+        graphql_pr.review_decision = None;
+        graphql_pr.latest_reviews = None;
+
+        let repo = Repo::new("owner", "repo").unwrap();
+        let pr_info = convert_graphql_pr_to_pr_info(graphql_pr, repo).unwrap();
+
+        assert_eq!(pr_info.review_decision, None);
+        assert!(pr_info.reviews.is_empty());
+    }
+
+    #[test]
+    fn converts_review_requests_and_assignees() {
+        let repo = Repo::new("owner", "repo").unwrap();
+        let pr_info = convert_graphql_pr_to_pr_info(create_test_graphql_pr(), repo).unwrap();
+
+        assert_eq!(pr_info.requested_reviewers, vec!["dave", "owner/reviewers"]);
+        assert_eq!(pr_info.assignees, vec!["erin"]);
+    }
+
+    #[test]
+    fn requested_reviewer_deserialises_by_typename() {
+        let reviewer: GraphQLRequestedReviewer =
+            serde_json::from_str(r#"{"__typename": "Team", "combinedSlug": "org/team"}"#).unwrap();
+        assert!(matches!(
+            reviewer,
+            GraphQLRequestedReviewer::Team { combined_slug } if combined_slug == "org/team"
+        ));
+
+        let reviewer: GraphQLRequestedReviewer =
+            serde_json::from_str(r#"{"__typename": "Something"}"#).unwrap();
+        assert!(matches!(reviewer, GraphQLRequestedReviewer::Unknown));
+    }
+
     #[test]
     fn test_convert_conclusion() {
         assert_eq!(
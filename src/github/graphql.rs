@@ -9,13 +9,15 @@
 //! own equivalent of this module and reuse none of it; only the domain
 //! model it converges on is shared.
 
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt::Write};
 
 use chrono::{DateTime, Utc};
 use octocrab::models::{StatusState, workflows::Conclusion};
 use serde::{Deserialize, Deserializer};
 use url::Url;
 
+use crate::types::Repo;
+
 /// Simple GraphQL query builder that eliminates brittle JSON manipulation
 /// while maintaining clean type boundaries - only the GitHub adapter knows
 /// about GraphQL.
@@ -28,11 +30,115 @@ impl GraphQLQueryBuilder {
     /// Create a new query builder for searching pull requests
     pub(crate) fn search_pull_requests() -> Self {
         Self {
-            query: include_str!("search_prs.graphql").to_string(),
+            query: with_pull_request_fields(include_str!("search_prs.graphql")),
             variables: HashMap::new(),
         }
     }
 
+    /// Create a query that only counts a search's results.
+    pub(crate) fn count_search_results() -> Self {
+        Self {
+            query: include_str!("count_search.graphql").to_string(),
+            variables: HashMap::new(),
+        }
+    }
+
+    /// Create a query for a page of one pull request's comments, the
+    /// newest first unless [`Self::with_before_cursor`] moves it back.
+    pub(crate) fn pull_request_comments(repo: &Repo, number: u64) -> Self {
+        Self::pull_request_page(include_str!("pr_comments.graphql"), repo, number)
+    }
+
+    /// Create a query for a page of one pull request's labels.
+    pub(crate) fn pull_request_labels(repo: &Repo, number: u64) -> Self {
+        Self::pull_request_page(include_str!("pr_labels.graphql"), repo, number)
+    }
+
+    /// Create a query for a page of the paths one pull request changes.
+    pub(crate) fn pull_request_files(repo: &Repo, number: u64) -> Self {
+        Self::pull_request_page(include_str!("pr_files.graphql"), repo, number)
+    }
+
+    /// Create a query for a page of one pull request's commits.
+    pub(crate) fn pull_request_commits(repo: &Repo, number: u64) -> Self {
+        Self::pull_request_page(include_str!("pr_commits.graphql"), repo, number)
+    }
+
+    /// Create a query for a page of one pull request's status check
+    /// contexts.
+    pub(crate) fn pull_request_status_contexts(repo: &Repo, number: u64) -> Self {
+        Self::pull_request_page(include_str!("pr_status_contexts.graphql"), repo, number)
+    }
+
+    /// Create a query for a page of the repositories an organisation
+    /// or user owns, by name.
+    pub(crate) fn owner_repositories(login: &str) -> Self {
+        let mut variables = HashMap::new();
+        variables.insert("login".to_string(), login.into());
+        Self {
+            query: include_str!("owner_repos.graphql").to_string(),
+            variables,
+        }
+    }
+
+    fn pull_request_page(query: &str, repo: &Repo, number: u64) -> Self {
+        let mut variables = HashMap::new();
+        variables.insert("owner".to_string(), repo.owner().into());
+        variables.insert("name".to_string(), repo.name().into());
+        variables.insert("number".to_string(), number.into());
+        Self {
+            query: query.to_string(),
+            variables,
+        }
+    }
+
+    /// Create a query fetching specific pull requests by number.
+    ///
+    /// Each repository is aliased `repo{i}` and each pull request within
+    /// it `pr{number}`, so a [`BatchResponse`] can be matched back to
+    /// what was asked for. Owners, names, and numbers are passed as
+    /// variables rather than spliced into the text.
+    pub(crate) fn pull_requests_by_number(batch: &[(Repo, Vec<u64>)]) -> Self {
+        let mut parameters = Vec::new();
+        let mut selections = String::new();
+        let mut variables = HashMap::new();
+
+        for (i, (repo, numbers)) in batch.iter().enumerate() {
+            let owner = format!("repo{i}_owner");
+            let name = format!("repo{i}_name");
+            parameters.push(format!("${owner}: String!"));
+            parameters.push(format!("${name}: String!"));
+            variables.insert(owner.clone(), repo.owner().into());
+            variables.insert(name.clone(), repo.name().into());
+
+            let _ = writeln!(
+                selections,
+                "    {}: repository(owner: ${owner}, name: ${name}) {{",
+                repo_alias(i)
+            );
+            for number in numbers {
+                let variable = format!("repo{i}_pr{number}");
+                parameters.push(format!("${variable}: Int!"));
+                variables.insert(variable.clone(), (*number).into());
+                let _ = writeln!(
+                    selections,
+                    "        {}: pullRequest(number: ${variable}) {{\n            ...PullRequestFields\n        }}",
+                    pr_alias(*number)
+                );
+            }
+            selections.push_str("    }\n");
+        }
+
+        let query = format!(
+            "query PullRequestsByNumber({}) {{\n{selections}}}",
+            parameters.join(", ")
+        );
+        Self {
+            query: with_pull_request_fields(&query),
+            variables,
+        }
+    }
+
     /// Set the search query string
     pub(crate) fn with_search_query(mut self, query: &str) -> Self {
         self.variables.insert("query".to_string(), query.into());
@@ -47,6 +153,14 @@ impl GraphQLQueryBuilder {
         self
     }
 
+    pub(crate) fn with_before_cursor(mut self, cursor: Option<String>) -> Self {
+        self.variables.insert(
+            "before".to_string(),
+            cursor.map_or(serde_json::Value::Null, |c| c.into()),
+        );
+        self
+    }
+
     pub(crate) fn build(self) -> serde_json::Value {
         serde_json::json!({
             "query": self.query,
@@ -55,6 +169,23 @@ impl GraphQLQueryBuilder {
     }
 }
 
+/// Appends the shared pull request fragment to an operation.
+fn with_pull_request_fields(operation: &str) -> String {
+    format!(
+        "{}\n{}",
+        operation.trim_end(),
+        include_str!("pull_request_fields.graphql")
+    )
+}
+
+pub(crate) fn repo_alias(index: usize) -> String {
+    format!("repo{index}")
+}
+
+pub(crate) fn pr_alias(number: u64) -> String {
+    format!("pr{number}")
+}
+
 /// Custom deserialiser for GraphQL conclusion values.
 ///
 /// Converts uppercase GraphQL enum values (e.g., "SUCCESS") to
@@ -126,7 +257,7 @@ pub(crate) enum ActorType {
 }
 
 impl ActorType {
-    fn is_bot(&self) -> bool {
+    pub(crate) fn is_bot(&self) -> bool {
         matches!(self, ActorType::Bot | ActorType::App)
     }
 }
@@ -178,6 +309,10 @@ pub(crate) enum GraphQLStatusContext {
         conclusion: Option<Conclusion>,
         #[serde(rename = "detailsUrl")]
         details_url: Option<String>,
+        #[serde(rename = "startedAt", deserialize_with = "Option::deserialize")]
+        started_at: Option<DateTime<Utc>>,
+        #[serde(rename = "completedAt", deserialize_with = "Option::deserialize")]
+        completed_at: Option<DateTime<Utc>>,
     },
     StatusContext {
         context: Option<String>,
@@ -185,6 +320,8 @@ pub(crate) enum GraphQLStatusContext {
         state: Option<StatusState>,
         #[serde(rename = "targetUrl")]
         target_url: Option<String>,
+        #[serde(rename = "createdAt")]
+        created_at: DateTime<Utc>,
     },
 }
 
@@ -193,6 +330,56 @@ pub(crate) struct GraphQLResponse {
     pub(crate) data: SearchData,
 }
 
+/// Response to [`GraphQLQueryBuilder::count_search_results`].
+#[derive(Debug, Deserialize)]
+pub(crate) struct CountResponse {
+    pub(crate) data: CountData,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct CountData {
+    pub(crate) search: SearchCount,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SearchCount {
+    pub(crate) issue_count: u64,
+}
+
+/// Response to [`GraphQLQueryBuilder::pull_requests_by_number`].
+///
+/// GitHub answers a missing repository or pull request with `null` in
+/// its place and an entry in `errors`, leaving the rest of the batch
+/// intact.
+#[derive(Debug, Deserialize)]
+pub(crate) struct BatchResponse {
+    pub(crate) data: Option<HashMap<String, Option<RepositoryPullRequests>>>,
+    #[serde(default)]
+    pub(crate) errors: Vec<GraphQLError>,
+}
+
+/// One aliased repository in a [`BatchResponse`], keyed by PR alias.
+pub(crate) type RepositoryPullRequests = HashMap<String, Option<GraphQLPullRequest>>;
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct GraphQLError {
+    pub(crate) message: String,
+    #[serde(default)]
+    pub(crate) path: Vec<serde_json::Value>,
+}
+
+impl GraphQLError {
+    /// True when this error is about the field at `path` (or inside it).
+    pub(crate) fn concerns(&self, path: &[&str]) -> bool {
+        self.path.len() >= path.len()
+            && path
+                .iter()
+                .zip(&self.path)
+                .all(|(want, got)| got.as_str() == Some(want))
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub(crate) struct SearchData {
     pub(crate) search: SearchResults,
@@ -212,6 +399,13 @@ pub(crate) struct PageInfo {
     pub(crate) end_cursor: Option<String>,
 }
 
+impl PageInfo {
+    /// Cursor for the next page, if there is one.
+    fn next_cursor(&self) -> Option<String> {
+        self.end_cursor.clone().filter(|_| self.has_next_page)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub(crate) enum GraphQLPullRequestState {
@@ -229,18 +423,170 @@ pub(crate) struct GraphQLPullRequest {
     pub(crate) state: GraphQLPullRequestState,
     pub(crate) is_draft: bool,
     pub(crate) created_at: DateTime<Utc>,
+    pub(crate) updated_at: DateTime<Utc>,
+    pub(crate) additions: u64,
+    pub(crate) deletions: u64,
+    pub(crate) changed_files: u64,
     pub(crate) base_ref_name: Option<String>,
+    /// Null once the base branch is deleted.
+    #[serde(deserialize_with = "Option::deserialize")]
+    pub(crate) base_ref: Option<GraphQLBaseRef>,
+    pub(crate) head_ref_name: String,
+    pub(crate) head_ref_oid: String,
+    /// Null once the head repository is deleted.
+    #[serde(deserialize_with = "Option::deserialize")]
+    pub(crate) head_repository_owner: Option<GraphQLRepositoryOwner>,
+    pub(crate) is_cross_repository: bool,
     pub(crate) commits: GraphQLCommitConnection,
+    /// The latest force push to the head branch.
+    pub(crate) timeline_items: GraphQLForcePushConnection,
     pub(crate) author: Option<GraphQLAuthor>,
     pub(crate) labels: GraphQLLabelConnection,
+    pub(crate) mergeable: GraphQLMergeableState,
+    pub(crate) merge_state_status: GraphQLMergeStateStatus,
+    /// Null when the base branch requires no reviews.
+    #[serde(deserialize_with = "Option::deserialize")]
+    pub(crate) review_decision: Option<GraphQLReviewDecision>,
+    #[serde(deserialize_with = "Option::deserialize")]
+    pub(crate) latest_reviews: Option<GraphQLReviewConnection>,
+    #[serde(deserialize_with = "Option::deserialize")]
+    pub(crate) review_requests: Option<GraphQLReviewRequestConnection>,
+    pub(crate) assignees: GraphQLAssigneeConnection,
     pub(crate) status_check_rollup: Option<GraphQLStatusCheckRollup>,
     pub(crate) comments: GraphQLCommentConnection,
+    #[serde(deserialize_with = "Option::deserialize")]
+    pub(crate) closing_issues_references: Option<GraphQLIssueConnection>,
+    pub(crate) body: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct GraphQLIssueConnection {
+    pub(crate) nodes: Vec<GraphQLIssue>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct GraphQLIssue {
+    pub(crate) number: u64,
+    pub(crate) title: String,
+    pub(crate) url: String,
+}
+
+impl GraphQLPullRequest {
+    /// Names of the checks the base branch needs to pass before
+    /// merging. Empty when it is unprotected.
+    pub(crate) fn required_checks(&self) -> Vec<String> {
+        self.base_ref
+            .as_ref()
+            .and_then(|base_ref| base_ref.ref_update_rule.as_ref())
+            .and_then(|rule| rule.required_status_check_contexts.clone())
+            .unwrap_or_default()
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GraphQLBaseRef {
+    /// The branch's protection as GitHub shows it to anyone who can
+    /// read the repository, not just admins. Null when unprotected.
+    pub(crate) ref_update_rule: Option<GraphQLRefUpdateRule>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GraphQLRefUpdateRule {
+    pub(crate) required_status_check_contexts: Option<Vec<Option<String>>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct GraphQLRepositoryOwner {
+    pub(crate) login: String,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct GraphQLCommitConnection {
     pub(crate) total_count: u64,
+    /// The head commit.
+    pub(crate) nodes: Vec<GraphQLPullRequestCommit>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct GraphQLForcePushConnection {
+    pub(crate) nodes: Vec<GraphQLForcePush>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GraphQLForcePush {
+    pub(crate) created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct GraphQLPullRequestCommit {
+    pub(crate) commit: GraphQLCommit,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GraphQLCommit {
+    pub(crate) committed_date: DateTime<Utc>,
+}
+
+/// A page of commits with the detail [`GraphQLQueryBuilder::pull_request_commits`]
+/// asks for, as opposed to the head commit the search selects.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GraphQLCommitDetailsConnection {
+    pub(crate) nodes: Vec<GraphQLPullRequestCommitDetails>,
+    pub(crate) page_info: PageInfo,
+}
+
+impl GraphQLCommitDetailsConnection {
+    /// Cursor for the next page, if the pull request has more commits.
+    pub(crate) fn next_cursor(&self) -> Option<String> {
+        self.page_info.next_cursor()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct GraphQLPullRequestCommitDetails {
+    pub(crate) commit: GraphQLCommitDetails,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GraphQLCommitDetails {
+    pub(crate) oid: String,
+    pub(crate) message_headline: String,
+    pub(crate) message: String,
+    pub(crate) author: Option<GraphQLGitActor>,
+    pub(crate) committer: Option<GraphQLGitActor>,
+    /// Null for unsigned commits.
+    pub(crate) signature: Option<GraphQLCommitSignature>,
+}
+
+/// Author or committer as recorded in the commit, with the account it
+/// maps to if any.
+#[derive(Debug, Deserialize)]
+pub(crate) struct GraphQLGitActor {
+    pub(crate) name: Option<String>,
+    pub(crate) user: Option<GraphQLRepositoryOwner>,
+}
+
+impl GraphQLGitActor {
+    /// The account's login, else the recorded name.
+    pub(crate) fn identity(self) -> Option<String> {
+        self.user.map(|user| user.login).or(self.name)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GraphQLCommitSignature {
+    pub(crate) is_valid: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -266,8 +612,36 @@ impl GraphQLAuthor {
 }
 
 #[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub(crate) struct GraphQLLabelConnection {
     pub(crate) nodes: Vec<GraphQLLabel>,
+    pub(crate) page_info: PageInfo,
+}
+
+impl GraphQLLabelConnection {
+    /// Cursor for the next page, if the pull request has more labels.
+    pub(crate) fn next_cursor(&self) -> Option<String> {
+        self.page_info.next_cursor()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GraphQLChangedFileConnection {
+    pub(crate) nodes: Vec<GraphQLChangedFile>,
+    pub(crate) page_info: PageInfo,
+}
+
+impl GraphQLChangedFileConnection {
+    /// Cursor for the next page, if the pull request changes more files.
+    pub(crate) fn next_cursor(&self) -> Option<String> {
+        self.page_info.next_cursor()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct GraphQLChangedFile {
+    pub(crate) path: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -275,19 +649,228 @@ pub(crate) struct GraphQLLabel {
     pub(crate) name: String,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub(crate) enum GraphQLMergeableState {
+    Mergeable,
+    Conflicting,
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub(crate) enum GraphQLMergeStateStatus {
+    Behind,
+    Blocked,
+    Clean,
+    Dirty,
+    Draft,
+    HasHooks,
+    Unstable,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub(crate) enum GraphQLReviewDecision {
+    Approved,
+    ChangesRequested,
+    ReviewRequired,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub(crate) enum GraphQLReviewState {
+    Approved,
+    ChangesRequested,
+    Commented,
+    Dismissed,
+    Pending,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct GraphQLReviewConnection {
+    pub(crate) nodes: Vec<GraphQLReview>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct GraphQLReview {
+    pub(crate) state: GraphQLReviewState,
+    /// Null when the reviewer's account has been deleted.
+    pub(crate) author: Option<GraphQLAuthor>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct GraphQLReviewRequestConnection {
+    pub(crate) nodes: Vec<GraphQLReviewRequest>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GraphQLReviewRequest {
+    /// Null when the requested account or team has been deleted.
+    pub(crate) requested_reviewer: Option<GraphQLRequestedReviewer>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "__typename")]
+pub(crate) enum GraphQLRequestedReviewer {
+    User {
+        login: String,
+    },
+    Bot {
+        login: String,
+    },
+    Mannequin {
+        login: String,
+    },
+    Team {
+        #[serde(rename = "combinedSlug")]
+        combined_slug: String,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct GraphQLAssigneeConnection {
+    pub(crate) nodes: Vec<GraphQLRepositoryOwner>,
+}
+
 #[derive(Debug, Deserialize)]
 pub(crate) struct GraphQLStatusCheckRollup {
     pub(crate) contexts: GraphQLStatusContextConnection,
 }
 
 #[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub(crate) struct GraphQLStatusContextConnection {
     pub(crate) nodes: Vec<GraphQLStatusContext>,
+    pub(crate) page_info: PageInfo,
+}
+
+impl GraphQLStatusContextConnection {
+    /// Cursor for the next page, if the pull request has more contexts.
+    pub(crate) fn next_cursor(&self) -> Option<String> {
+        self.page_info.next_cursor()
+    }
 }
 
 #[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub(crate) struct GraphQLCommentConnection {
     pub(crate) nodes: Vec<GraphQLComment>,
+    pub(crate) page_info: CommentPageInfo,
+}
+
+impl GraphQLCommentConnection {
+    /// Cursor for the page before this one, if there are older comments.
+    pub(crate) fn older_cursor(&self) -> Option<String> {
+        self.page_info
+            .start_cursor
+            .clone()
+            .filter(|_| self.page_info.has_previous_page)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CommentPageInfo {
+    pub(crate) has_previous_page: bool,
+    pub(crate) start_cursor: Option<String>,
+}
+
+/// Response to a query for one page of a single pull request's
+/// connection, e.g. [`GraphQLQueryBuilder::pull_request_labels`].
+#[derive(Debug, Deserialize)]
+pub(crate) struct PullRequestPageResponse<T> {
+    data: PullRequestPageData<T>,
+}
+
+impl<T> PullRequestPageResponse<T> {
+    /// The page, or `None` if the repository or pull request is gone.
+    pub(crate) fn into_page(self) -> Option<T> {
+        self.data
+            .repository
+            .and_then(|repository| repository.pull_request)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct PullRequestPageData<T> {
+    repository: Option<PullRequestPageRepository<T>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PullRequestPageRepository<T> {
+    pull_request: Option<T>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct CommentsPage {
+    pub(crate) comments: GraphQLCommentConnection,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct LabelsPage {
+    pub(crate) labels: GraphQLLabelConnection,
+}
+
+/// Null when GitHub cannot list the files, e.g. for a very large diff.
+#[derive(Debug, Deserialize)]
+pub(crate) struct FilesPage {
+    pub(crate) files: Option<GraphQLChangedFileConnection>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct CommitsPage {
+    pub(crate) commits: GraphQLCommitDetailsConnection,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct StatusContextsPage {
+    pub(crate) status_check_rollup: Option<GraphQLStatusCheckRollup>,
+}
+
+/// Response to [`GraphQLQueryBuilder::owner_repositories`].
+#[derive(Debug, Deserialize)]
+pub(crate) struct OwnerRepositoriesResponse {
+    data: OwnerRepositoriesData,
+}
+
+impl OwnerRepositoriesResponse {
+    /// The page, or `None` if no organisation or user has that login.
+    pub(crate) fn into_page(self) -> Option<GraphQLRepositoryConnection> {
+        self.data.repository_owner.map(|owner| owner.repositories)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OwnerRepositoriesData {
+    repository_owner: Option<RepositoryOwner>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RepositoryOwner {
+    repositories: GraphQLRepositoryConnection,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GraphQLRepositoryConnection {
+    pub(crate) nodes: Vec<GraphQLRepository>,
+    pub(crate) page_info: PageInfo,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GraphQLRepository {
+    pub(crate) name: String,
+    pub(crate) is_archived: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -295,6 +878,9 @@ pub(crate) struct GraphQLCommentConnection {
 pub(crate) struct GraphQLComment {
     pub(crate) body: String,
     pub(crate) created_at: DateTime<Utc>,
+    /// Null for deleted accounts.
+    #[serde(deserialize_with = "Option::deserialize")]
+    pub(crate) author: Option<GraphQLAuthor>,
 }
 
 #[cfg(test)]
@@ -343,12 +929,72 @@ mod tests {
         let query_str = query.get("query").unwrap().as_str().unwrap();
 
         // Verify key GraphQL elements are present.
-        assert!(query_str.contains("query($query: String!, $after: String)"));
+        assert!(query_str.contains("query SearchPullRequests($query: String!, $after: String)"));
         assert!(query_str.contains("search(query: $query"));
         assert!(query_str.contains("... on PullRequest"));
         assert!(query_str.contains("pageInfo"));
     }
 
+    #[test]
+    fn test_query_shares_pull_request_fragment() {
+        let search = GraphQLQueryBuilder::search_pull_requests().build();
+        let batch = GraphQLQueryBuilder::pull_requests_by_number(&[(
+            Repo::new("o", "r").unwrap(),
+            vec![1],
+        )])
+        .build();
+
+        for query in [search, batch] {
+            let query_str = query.get("query").unwrap().as_str().unwrap();
+            assert!(query_str.contains("...PullRequestFields"));
+            assert!(query_str.contains("fragment PullRequestFields on PullRequest"));
+        }
+    }
+
+    #[test]
+    fn test_pull_requests_by_number_aliases_repos_and_numbers() {
+        let query = GraphQLQueryBuilder::pull_requests_by_number(&[
+            (Repo::new("owner", "repo").unwrap(), vec![7, 9]),
+            (Repo::new("other", "tools").unwrap(), vec![1]),
+        ])
+        .build();
+
+        let query_str = query.get("query").unwrap().as_str().unwrap();
+        assert!(query_str.starts_with("query PullRequestsByNumber("));
+        assert!(query_str.contains("repo0: repository(owner: $repo0_owner, name: $repo0_name)"));
+        assert!(query_str.contains("pr7: pullRequest(number: $repo0_pr7)"));
+        assert!(query_str.contains("pr9: pullRequest(number: $repo0_pr9)"));
+        assert!(query_str.contains("repo1: repository(owner: $repo1_owner, name: $repo1_name)"));
+        assert!(query_str.contains("$repo1_pr1: Int!"));
+
+        let variables = query.get("variables").unwrap();
+        assert_eq!(variables["repo0_owner"], "owner");
+        assert_eq!(variables["repo1_name"], "tools");
+        assert_eq!(variables["repo0_pr9"], 9);
+    }
+
+    #[test]
+    fn test_batch_response_keeps_nulls_and_errors() {
+        let response: BatchResponse = serde_json::from_str(
+            r#"{
+                "data": { "repo0": { "pr5": null }, "repo1": null },
+                "errors": [
+                    { "type": "NOT_FOUND", "path": ["repo0", "pr5"], "message": "no PR 5" },
+                    { "type": "NOT_FOUND", "path": ["repo1"], "message": "no repo" }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let data = response.data.unwrap();
+        assert!(data["repo0"].as_ref().unwrap()["pr5"].is_none());
+        assert!(data["repo1"].is_none());
+        assert!(response.errors[0].concerns(&["repo0", "pr5"]));
+        assert!(!response.errors[0].concerns(&["repo1"]));
+        assert!(response.errors[1].concerns(&["repo1"]));
+        assert!(!response.errors[1].concerns(&["repo1", "pr2"]));
+    }
+
     #[test]
     fn test_deserialize_graphql_conclusion_valid() {
         #[derive(Debug, serde::Deserialize)]
@@ -0,0 +1,274 @@
+//! Record and replay of GitHub GraphQL exchanges.
+//!
+//! A recording is a directory of `graphql-NNNN.json` files, one per
+//! request, each holding the operation name, the variables, and the raw
+//! response. Replay matches requests on operation name and variables
+//! rather than on the query text, so recordings survive changes to the
+//! selected fields as long as the response still deserialises.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    path::{Path, PathBuf},
+    sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+const FILE_PREFIX: &str = "graphql-";
+const FILE_SUFFIX: &str = ".json";
+
+/// One recorded request and its response.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Exchange {
+    operation_name: String,
+    variables: serde_json::Value,
+    response: serde_json::Value,
+}
+
+/// Extracts the operation name from a GraphQL document, e.g.
+/// `SearchPullRequests` from `query SearchPullRequests($query: ...)`.
+pub(crate) fn operation_name(query: &str) -> Option<&str> {
+    let rest = query.trim_start().strip_prefix("query")?;
+    let rest = rest.trim_start();
+    let end = rest
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(rest.len());
+    (end > 0).then(|| &rest[..end])
+}
+
+fn request_parts(request: &serde_json::Value) -> Result<(&str, &serde_json::Value)> {
+    let query = request
+        .get("query")
+        .and_then(|query| query.as_str())
+        .context("GraphQL request has no query text")?;
+    let operation_name =
+        operation_name(query).context("GraphQL query has no operation name to record it by")?;
+    let variables = request.get("variables").unwrap_or(&serde_json::Value::Null);
+    Ok((operation_name, variables))
+}
+
+/// Replay key: serde_json's default map is ordered, so serialising the
+/// variables gives the same key however they were built.
+fn exchange_key(operation_name: &str, variables: &serde_json::Value) -> String {
+    format!("{operation_name} {variables}")
+}
+
+fn is_recording_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.starts_with(FILE_PREFIX) && name.ends_with(FILE_SUFFIX))
+}
+
+fn recording_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read recording directory '{}'", dir.display()))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::io::Result<Vec<_>>>()
+        .with_context(|| format!("Failed to read recording directory '{}'", dir.display()))?;
+    files.retain(|path| is_recording_file(path));
+    // Zero-padded sequence numbers sort in the order they were written.
+    files.sort();
+    Ok(files)
+}
+
+/// Writes each GraphQL exchange to its own numbered file.
+pub(crate) struct Recorder {
+    dir: PathBuf,
+    next: AtomicUsize,
+}
+
+impl Recorder {
+    /// Prepares `dir` for a new recording, creating it if needed.
+    ///
+    /// Refuses a directory that already holds a recording: appending
+    /// would mix two runs, and replay could serve the stale one.
+    pub(crate) fn create(dir: &Path) -> Result<Self> {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create recording directory '{}'", dir.display()))?;
+        if !recording_files(dir)?.is_empty() {
+            anyhow::bail!(
+                "Recording directory '{}' already contains a recording; choose an empty directory",
+                dir.display()
+            );
+        }
+
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            next: AtomicUsize::new(1),
+        })
+    }
+
+    pub(crate) fn record(
+        &self,
+        request: &serde_json::Value,
+        response: &serde_json::Value,
+    ) -> Result<()> {
+        let (operation_name, variables) = request_parts(request)?;
+        let exchange = Exchange {
+            operation_name: operation_name.to_string(),
+            variables: variables.clone(),
+            response: response.clone(),
+        };
+
+        let sequence = self.next.fetch_add(1, Ordering::Relaxed);
+        let path = self
+            .dir
+            .join(format!("{FILE_PREFIX}{sequence:04}{FILE_SUFFIX}"));
+        let contents = serde_json::to_vec_pretty(&exchange)?;
+        std::fs::write(&path, contents)
+            .with_context(|| format!("Failed to write recording '{}'", path.display()))
+    }
+}
+
+/// Serves GraphQL responses from a recording instead of the network.
+pub(crate) struct Replay {
+    dir: PathBuf,
+    responses: Mutex<HashMap<String, VecDeque<serde_json::Value>>>,
+}
+
+impl Replay {
+    pub(crate) fn load(dir: &Path) -> Result<Self> {
+        let mut responses: HashMap<String, VecDeque<serde_json::Value>> = HashMap::new();
+
+        for path in recording_files(dir)? {
+            let contents = std::fs::read(&path)
+                .with_context(|| format!("Failed to read recording '{}'", path.display()))?;
+            let exchange: Exchange = serde_json::from_slice(&contents)
+                .with_context(|| format!("Invalid recording '{}'", path.display()))?;
+            responses
+                .entry(exchange_key(&exchange.operation_name, &exchange.variables))
+                .or_default()
+                .push_back(exchange.response);
+        }
+
+        if responses.is_empty() {
+            anyhow::bail!("No recording found in '{}'", dir.display());
+        }
+
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            responses: Mutex::new(responses),
+        })
+    }
+
+    /// Returns the recorded response to `request`.
+    ///
+    /// Identical requests recorded more than once are answered in the
+    /// order they were recorded; the last answer is then repeated.
+    pub(crate) fn respond(&self, request: &serde_json::Value) -> Result<serde_json::Value> {
+        let (operation_name, variables) = request_parts(request)?;
+        let mut responses = self.responses.lock().expect("replay lock poisoned");
+        let queue = responses
+            .get_mut(&exchange_key(operation_name, variables))
+            .with_context(|| {
+                format!(
+                    "No recorded response in '{}' for {operation_name} with variables {variables}",
+                    self.dir.display()
+                )
+            })?;
+
+        Ok(if queue.len() > 1 {
+            queue.pop_front().expect("queue has more than one response")
+        } else {
+            queue
+                .front()
+                .cloned()
+                .expect("recorded queues are never empty")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn request(after: Option<&str>) -> serde_json::Value {
+        json!({
+            "query": "query SearchPullRequests($query: String!, $after: String) { search }",
+            "variables": { "query": "repo:owner/repo", "after": after }
+        })
+    }
+
+    #[test]
+    fn operation_name_is_read_from_query_header() {
+        assert_eq!(
+            operation_name("query SearchPullRequests($query: String!) { x }"),
+            Some("SearchPullRequests")
+        );
+        assert_eq!(operation_name("\n  query Batch { x }"), Some("Batch"));
+        assert_eq!(operation_name("query($query: String!) { x }"), None);
+        assert_eq!(operation_name("{ viewer { login } }"), None);
+    }
+
+    #[test]
+    fn replay_serves_what_was_recorded_by_variables() {
+        let dir = tempfile::tempdir().unwrap();
+        let recorder = Recorder::create(dir.path()).unwrap();
+        recorder
+            .record(&request(None), &json!({ "page": 1 }))
+            .unwrap();
+        recorder
+            .record(&request(Some("c1")), &json!({ "page": 2 }))
+            .unwrap();
+
+        assert!(dir.path().join("graphql-0001.json").exists());
+        assert!(dir.path().join("graphql-0002.json").exists());
+
+        let replay = Replay::load(dir.path()).unwrap();
+        assert_eq!(
+            replay.respond(&request(Some("c1"))).unwrap(),
+            json!({ "page": 2 })
+        );
+        assert_eq!(
+            replay.respond(&request(None)).unwrap(),
+            json!({ "page": 1 })
+        );
+    }
+
+    #[test]
+    fn repeated_requests_replay_in_order_then_repeat_the_last() {
+        let dir = tempfile::tempdir().unwrap();
+        let recorder = Recorder::create(dir.path()).unwrap();
+        recorder.record(&request(None), &json!(1)).unwrap();
+        recorder.record(&request(None), &json!(2)).unwrap();
+
+        let replay = Replay::load(dir.path()).unwrap();
+        assert_eq!(replay.respond(&request(None)).unwrap(), json!(1));
+        assert_eq!(replay.respond(&request(None)).unwrap(), json!(2));
+        assert_eq!(replay.respond(&request(None)).unwrap(), json!(2));
+    }
+
+    #[test]
+    fn unrecorded_request_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        Recorder::create(dir.path())
+            .unwrap()
+            .record(&request(None), &json!(1))
+            .unwrap();
+
+        let err = Replay::load(dir.path())
+            .unwrap()
+            .respond(&request(Some("other")))
+            .unwrap_err();
+        assert!(err.to_string().contains("No recorded response"));
+        assert!(err.to_string().contains("SearchPullRequests"));
+    }
+
+    #[test]
+    fn recorder_refuses_to_overwrite_a_recording() {
+        let dir = tempfile::tempdir().unwrap();
+        Recorder::create(dir.path())
+            .unwrap()
+            .record(&request(None), &json!(1))
+            .unwrap();
+
+        assert!(Recorder::create(dir.path()).is_err());
+    }
+}
@@ -36,7 +36,7 @@ mod tests {
     use chrono::{TimeZone, Utc};
 
     use super::*;
-    use crate::types::{CommentAction, PrState, Repo};
+    use crate::types::{CommentAction, MergeState, Mergeable, PrState, Repo};
 
     fn pr() -> PullRequest {
         PullRequest {
@@ -48,12 +48,32 @@ mod tests {
             url: "https://github.com/owner/repo/pull/123".to_string(),
             labels: vec![],
             created_at: Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap(),
+            updated_at: Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap(),
+            last_pushed_at: None,
             base_branch: "main".to_string(),
             commit_count: 1,
+            additions: None,
+            deletions: None,
+            changed_files: None,
+            changed_paths: None,
+            commits: None,
+            linked_issues: None,
+            issue_keys: vec![],
             is_draft: false,
             state: PrState::Open,
             checks: vec![],
             recent_comments: vec![],
+            comment_history_complete: true,
+            review_decision: None,
+            reviews: vec![],
+            requested_reviewers: vec![],
+            assignees: vec![],
+            mergeable: Mergeable::Unknown,
+            merge_state: MergeState::Unknown,
+            head_branch: "feature".to_string(),
+            head_repo_owner: None,
+            head_sha: String::new(),
+            is_cross_repository: false,
         }
     }
 
@@ -85,4 +105,17 @@ mod tests {
             "gh pr merge --merge https://github.com/owner/repo/pull/123"
         );
     }
+
+    #[test]
+    fn enterprise_pr_urls_carry_their_host_to_gh() {
+        // gh picks the host from a PR URL argument, so commands for a
+        // GitHub Enterprise Server PR need no --hostname or GH_HOST.
+        let mut pr = pr();
+        pr.url = "https://github.example.com/owner/repo/pull/123".to_string();
+
+        assert_eq!(
+            GhCliRenderer.render(&pr, &PrAction::Comment(CommentAction::Lgtm)),
+            "gh pr comment https://github.example.com/owner/repo/pull/123 --body \"/lgtm\""
+        );
+    }
 }
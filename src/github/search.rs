@@ -3,8 +3,166 @@
 //! The core carries forge-neutral criteria. This module owns the GitHub
 //! search syntax used to narrow those criteria server-side.
 
+use chrono::{DateTime, SubsecRound, TimeDelta, TimeZone, Utc};
+
 use crate::types::{Repo, SearchCriterion};
 
+/// GitHub search returns at most this many results for one query, however
+/// many pages are requested.
+pub(crate) const SEARCH_RESULT_CAP: u64 = 1000;
+
+/// How search qualifiers spell a point in time.
+const SEARCH_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%SZ";
+
+/// An inclusive `created:` range used to split a search that would
+/// otherwise hit [`SEARCH_RESULT_CAP`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct CreatedWindow {
+    pub(crate) start: DateTime<Utc>,
+    pub(crate) end: DateTime<Utc>,
+}
+
+impl CreatedWindow {
+    /// Everything created up to `end`. GitHub predates no PR before
+    /// 2008, so that is where the first window starts.
+    pub(crate) fn up_to(end: DateTime<Utc>) -> Self {
+        Self {
+            start: Utc.with_ymd_and_hms(2008, 1, 1, 0, 0, 0).unwrap(),
+            end: end.trunc_subsecs(0),
+        }
+    }
+
+    /// Everything created up to `end` that `bounds` admits, or `None`
+    /// when they admit nothing.
+    pub(crate) fn within(bounds: &CreatedBounds, end: DateTime<Utc>) -> Option<Self> {
+        let mut window = Self::up_to(end);
+        // Search compares against whole seconds, and both bounds are
+        // strict while windows are inclusive.
+        if let Some(after) = bounds.after {
+            window.start = window
+                .start
+                .max(after.trunc_subsecs(0) + TimeDelta::seconds(1));
+        }
+        if let Some(before) = bounds.before {
+            window.end = window
+                .end
+                .min(before.trunc_subsecs(0) - TimeDelta::seconds(1));
+        }
+        (window.start <= window.end).then_some(window)
+    }
+
+    /// Halves the window. Search ranges are inclusive at both ends, so
+    /// the second half starts a second after the first ends. `None` when
+    /// the window is too narrow to split.
+    pub(crate) fn split(&self) -> Option<(Self, Self)> {
+        let span = self.end - self.start;
+        if span < TimeDelta::seconds(1) {
+            return None;
+        }
+        let mid = (self.start + span / 2).trunc_subsecs(0);
+        Some((
+            Self {
+                start: self.start,
+                end: mid,
+            },
+            Self {
+                start: mid + TimeDelta::seconds(1),
+                end: self.end,
+            },
+        ))
+    }
+
+    fn qualifier(&self) -> String {
+        format!(
+            "created:{}..{}",
+            self.start.format(SEARCH_TIME_FORMAT),
+            self.end.format(SEARCH_TIME_FORMAT)
+        )
+    }
+}
+
+pub(crate) fn with_created_window(query: &str, window: &CreatedWindow) -> String {
+    format!("{query} {}", window.qualifier())
+}
+
+/// A search query, with the creation-date range its criteria narrow it
+/// to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SearchQuery {
+    pub(crate) text: String,
+    pub(crate) created: CreatedBounds,
+}
+
+impl SearchQuery {
+    /// A query whose criteria, if any, are all in its text.
+    pub(crate) fn unbounded(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            created: CreatedBounds::default(),
+        }
+    }
+
+    /// The query GitHub runs for `criteria` in `repo`.
+    pub(crate) fn for_repo(repo: &Repo, criteria: &[SearchCriterion]) -> Self {
+        Self {
+            text: build_repo_search_query(repo, criteria),
+            created: CreatedBounds::of(criteria),
+        }
+    }
+}
+
+/// The creation-date range a search's criteria narrow it to. Splitting
+/// the search confines its windows to this range, in place of the
+/// `created:` terms the criteria put in the query, which would
+/// conflict with them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct CreatedBounds {
+    after: Option<DateTime<Utc>>,
+    before: Option<DateTime<Utc>>,
+}
+
+impl CreatedBounds {
+    pub(crate) fn of(criteria: &[SearchCriterion]) -> Self {
+        let mut bounds = Self::default();
+        for criterion in criteria {
+            match *criterion {
+                SearchCriterion::CreatedAfter(after) => {
+                    bounds.after = bounds.after.max(Some(after));
+                }
+                SearchCriterion::CreatedBefore(before) => {
+                    bounds.before = Some(bounds.before.map_or(before, |b| b.min(before)));
+                }
+                _ => {}
+            }
+        }
+        bounds
+    }
+
+    /// `query` without the `created:` terms these bounds put in it.
+    pub(crate) fn strip(&self, query: &str) -> String {
+        let mut terms = Vec::new();
+        if let Some(after) = self.after {
+            apply_criterion(&SearchCriterion::CreatedAfter(after), &mut terms);
+        }
+        if let Some(before) = self.before {
+            apply_criterion(&SearchCriterion::CreatedBefore(before), &mut terms);
+        }
+        query
+            .split_whitespace()
+            .filter(|term| !terms.iter().any(|created| created == term))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Whether the query already narrows on creation date, in which case
+/// adding windows would conflict with the user's own range.
+pub(crate) fn has_created_qualifier(query: &str) -> bool {
+    query
+        .split_whitespace()
+        .any(|term| term.trim_start_matches('-').starts_with("created:"))
+}
+
 pub(crate) fn build_repo_search_query(repo: &Repo, criteria: &[SearchCriterion]) -> String {
     let mut parts = Vec::with_capacity(criteria.len() + 4);
 
@@ -19,10 +177,6 @@ pub(crate) fn build_repo_search_query(repo: &Repo, criteria: &[SearchCriterion])
     parts.join(" ")
 }
 
-pub(crate) fn build_specific_pr_search_query(repo: &Repo, number: u64) -> String {
-    format!("repo:{repo} type:pr {number}")
-}
-
 // The default terms added here are documented in prose twice: in the
 // --query help text (src/cli.rs) and in README.md. If this behaviour
 // changes, update both.
@@ -45,6 +199,22 @@ fn apply_criterion(criterion: &SearchCriterion, terms: &mut Vec<String>) {
         SearchCriterion::MissingLabel(label) => terms.push(format!("-label:{label}")),
         SearchCriterion::PresentLabel(label) => terms.push(format!("label:{label}")),
         SearchCriterion::BaseBranch(branch) => terms.push(format!("base:{branch}")),
+        SearchCriterion::Assignee(user) => terms.push(format!("assignee:{user}")),
+        SearchCriterion::Unassigned => terms.push("no:assignee".to_string()),
+        // `review-requested:` also matches requests to the user's
+        // teams, which the local predicate cannot see.
+        SearchCriterion::ReviewRequested(user) => {
+            terms.push(format!("user-review-requested:{user}"))
+        }
+        SearchCriterion::CreatedBefore(cutoff) => {
+            terms.push(format!("created:<{}", cutoff.format(SEARCH_TIME_FORMAT)))
+        }
+        SearchCriterion::CreatedAfter(cutoff) => {
+            terms.push(format!("created:>{}", cutoff.format(SEARCH_TIME_FORMAT)))
+        }
+        SearchCriterion::UpdatedBefore(cutoff) => {
+            terms.push(format!("updated:<{}", cutoff.format(SEARCH_TIME_FORMAT)))
+        }
     }
 }
 
@@ -53,7 +223,7 @@ mod tests {
     use chrono::{TimeZone, Utc};
 
     use super::*;
-    use crate::types::{PrState, PullRequest};
+    use crate::types::{MergeState, Mergeable, PrState, PullRequest};
 
     fn repo() -> Repo {
         Repo::new("owner", "repo").unwrap()
@@ -69,12 +239,32 @@ mod tests {
             url: "https://github.com/owner/repo/pull/123".to_string(),
             labels: labels.iter().map(|label| label.to_string()).collect(),
             created_at: Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap(),
+            updated_at: Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap(),
+            last_pushed_at: None,
             base_branch: base_branch.to_string(),
             commit_count: 1,
+            additions: None,
+            deletions: None,
+            changed_files: None,
+            changed_paths: None,
+            commits: None,
+            linked_issues: None,
+            issue_keys: vec![],
             is_draft: false,
             state: PrState::Open,
             checks: vec![],
             recent_comments: vec![],
+            comment_history_complete: true,
+            review_decision: None,
+            reviews: vec![],
+            requested_reviewers: vec![],
+            assignees: vec![],
+            mergeable: Mergeable::Unknown,
+            merge_state: MergeState::Unknown,
+            head_branch: "feature".to_string(),
+            head_repo_owner: None,
+            head_sha: String::new(),
+            is_cross_repository: false,
         }
     }
 
@@ -101,11 +291,76 @@ mod tests {
     }
 
     #[test]
-    fn specific_pr_query_includes_repo_pr_type_and_number() {
+    fn created_window_splits_into_adjacent_halves() {
+        let window = CreatedWindow {
+            start: Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+            end: Utc.with_ymd_and_hms(2026, 1, 3, 0, 0, 0).unwrap(),
+        };
+
+        let (first, second) = window.split().unwrap();
+
+        assert_eq!(
+            with_created_window("is:pr", &first),
+            "is:pr created:2026-01-01T00:00:00Z..2026-01-02T00:00:00Z"
+        );
+        assert_eq!(
+            with_created_window("is:pr", &second),
+            "is:pr created:2026-01-02T00:00:01Z..2026-01-03T00:00:00Z"
+        );
+    }
+
+    #[test]
+    fn created_window_stops_splitting_at_one_second() {
+        let at = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let window = CreatedWindow { start: at, end: at };
+
+        assert_eq!(window.split(), None);
+        assert!(
+            CreatedWindow {
+                start: at,
+                end: at + TimeDelta::seconds(1)
+            }
+            .split()
+            .is_some()
+        );
+    }
+
+    #[test]
+    fn created_bounds_narrow_windows_and_come_out_of_the_query() {
+        let now = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let after = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+        let before = Utc.with_ymd_and_hms(2025, 7, 1, 0, 0, 0).unwrap();
+        let criteria = [
+            SearchCriterion::MissingLabel("lgtm".to_string()),
+            SearchCriterion::CreatedAfter(after),
+            SearchCriterion::CreatedBefore(before),
+        ];
+        let query = SearchQuery::for_repo(&repo(), &criteria);
+
         assert_eq!(
-            build_specific_pr_search_query(&repo(), 123),
-            "repo:owner/repo type:pr 123"
+            CreatedWindow::within(&query.created, now),
+            Some(CreatedWindow {
+                start: after + TimeDelta::seconds(1),
+                end: before - TimeDelta::seconds(1),
+            })
         );
+        assert_eq!(
+            query.created.strip(&query.text),
+            build_repo_search_query(&repo(), &criteria[..1])
+        );
+
+        let empty = CreatedBounds::of(&[
+            SearchCriterion::CreatedAfter(before),
+            SearchCriterion::CreatedBefore(after),
+        ]);
+        assert_eq!(CreatedWindow::within(&empty, now), None);
+    }
+
+    #[test]
+    fn created_qualifier_is_detected_including_negation() {
+        assert!(has_created_qualifier("org:o created:>2026-01-01"));
+        assert!(has_created_qualifier("org:o -created:2026-01-01"));
+        assert!(!has_created_qualifier("org:o sort:created-asc"));
     }
 
     #[test]
@@ -152,4 +407,52 @@ mod tests {
         assert!(criterion.matches(&pr(&[], "release-1.0")));
         assert!(!criterion.matches(&pr(&[], "main")));
     }
+
+    #[test]
+    fn assignment_search_terms_match_local_predicates() {
+        let mut assigned = pr(&[], "main");
+        assigned.assignees = vec!["alice".to_string()];
+        assigned.requested_reviewers = vec!["bob".to_string(), "org/team".to_string()];
+        let unassigned = pr(&[], "main");
+
+        let criterion = SearchCriterion::Assignee("alice".to_string());
+        assert_eq!(terms(&criterion), vec!["assignee:alice"]);
+        assert!(criterion.matches(&assigned));
+        assert!(!criterion.matches(&unassigned));
+        assert!(SearchCriterion::Assignee("Alice".to_string()).matches(&assigned));
+
+        let criterion = SearchCriterion::Unassigned;
+        assert_eq!(terms(&criterion), vec!["no:assignee"]);
+        assert!(criterion.matches(&unassigned));
+        assert!(!criterion.matches(&assigned));
+
+        let criterion = SearchCriterion::ReviewRequested("bob".to_string());
+        assert_eq!(terms(&criterion), vec!["user-review-requested:bob"]);
+        assert!(criterion.matches(&assigned));
+        assert!(!criterion.matches(&unassigned));
+        assert!(SearchCriterion::ReviewRequested("BOB".to_string()).matches(&assigned));
+    }
+
+    #[test]
+    fn age_search_terms_match_local_predicates() {
+        // The test PR was created and last updated at noon.
+        let before_noon = Utc.with_ymd_and_hms(2026, 5, 29, 11, 0, 0).unwrap();
+        let after_noon = Utc.with_ymd_and_hms(2026, 5, 29, 13, 0, 0).unwrap();
+        let pr = pr(&[], "main");
+
+        let criterion = SearchCriterion::CreatedBefore(after_noon);
+        assert_eq!(terms(&criterion), vec!["created:<2026-05-29T13:00:00Z"]);
+        assert!(criterion.matches(&pr));
+        assert!(!SearchCriterion::CreatedBefore(before_noon).matches(&pr));
+
+        let criterion = SearchCriterion::CreatedAfter(before_noon);
+        assert_eq!(terms(&criterion), vec!["created:>2026-05-29T11:00:00Z"]);
+        assert!(criterion.matches(&pr));
+        assert!(!SearchCriterion::CreatedAfter(after_noon).matches(&pr));
+
+        let criterion = SearchCriterion::UpdatedBefore(after_noon);
+        assert_eq!(terms(&criterion), vec!["updated:<2026-05-29T13:00:00Z"]);
+        assert!(criterion.matches(&pr));
+        assert!(!SearchCriterion::UpdatedBefore(before_noon).matches(&pr));
+    }
 }
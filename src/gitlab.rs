@@ -0,0 +1,423 @@
+//! GitLab forge adapter: the IO that talks to a GitLab instance and
+//! produces the forge-neutral domain model.
+//!
+//! This module owns authentication, the HTTP client, GraphQL execution,
+//! pagination, and the [`Forge`] impl. As with the GitHub adapter, the
+//! pure pieces live in submodules: [`graphql`] (the wire protocol and
+//! query builder), [`convert`] (wire -> domain), and [`search`]
+//! (criteria -> merge request arguments).
+//!
+//! [`Forge`]: crate::types::Forge
+
+mod convert;
+mod graphql;
+mod render;
+mod search;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use convert::{convert_merge_request, convert_notes};
+use graphql::{
+    GraphQLMergeRequest, GraphQLQueryBuilder, GraphQLResponse, ProjectData, ProjectMergeRequest,
+    ProjectMergeRequestNotes, ProjectMergeRequests,
+};
+pub use render::GlabCliRenderer;
+use search::MergeRequestFilter;
+use serde::de::DeserializeOwned;
+use tracing::{debug, info, instrument, warn};
+use url::Url;
+
+use crate::{
+    pr_selector::PrIdentifier,
+    search::{FetchOptions, FetchPlan, RepoSearch},
+    types::{FetchOutcome, PullRequest, Repo},
+};
+
+/// GitLab forge implementation for fetching merge requests.
+///
+/// Talks to the GraphQL API of a single GitLab instance, either
+/// gitlab.com or a self-hosted one, and presents its merge requests,
+/// pipeline jobs, and notes as pull requests, checks, and comments.
+pub struct GitLab {
+    base_url: Url,
+    token: Option<String>,
+    client: reqwest::Client,
+}
+
+impl GitLab {
+    /// Creates an unauthenticated client for the instance at `base_url`
+    /// (e.g. `https://gitlab.com`). Only public projects are visible
+    /// without a token.
+    pub fn new(base_url: Url) -> Self {
+        Self {
+            base_url,
+            token: None,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Authenticates requests with a personal, project, or group
+    /// access token.
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Creates a client for the instance at `base_url`, authenticated
+    /// with the GITLAB_TOKEN environment variable when it is set.
+    pub fn from_env(base_url: Url) -> Self {
+        let gitlab = Self::new(base_url);
+        match std::env::var("GITLAB_TOKEN") {
+            Ok(token) if !token.is_empty() => {
+                debug!("Using GITLAB_TOKEN environment variable");
+                gitlab.with_token(token)
+            }
+            _ => gitlab,
+        }
+    }
+
+    fn graphql_endpoint(&self) -> String {
+        format!(
+            "{}/api/graphql",
+            self.base_url.as_str().trim_end_matches('/')
+        )
+    }
+
+    /// Executes a GraphQL query, surfacing both HTTP failures and
+    /// GraphQL-level errors as an error that names `context`.
+    #[instrument(skip(self, query))]
+    async fn execute_graphql_query<T: DeserializeOwned>(
+        &self,
+        query: serde_json::Value,
+        context: &str,
+    ) -> Result<T> {
+        debug!("Executing GraphQL query");
+
+        let mut request = self
+            .client
+            .post(self.graphql_endpoint())
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(serde_json::to_vec(&query)?);
+        if let Some(token) = &self.token {
+            request = request.bearer_auth(token);
+        }
+
+        let response = request
+            .send()
+            .await
+            .with_context(|| format!("{context}: HTTP request failed"))?;
+
+        let status = response.status();
+        let body = response
+            .bytes()
+            .await
+            .with_context(|| format!("{context}: failed to read response body"))?;
+
+        if !status.is_success() {
+            anyhow::bail!(
+                "{context}: GitLab API returned {status}: {}",
+                String::from_utf8_lossy(&body).trim()
+            );
+        }
+
+        let response: GraphQLResponse<T> = serde_json::from_slice(&body)
+            .with_context(|| format!("{context}: failed to parse GraphQL response"))?;
+
+        if !response.errors.is_empty() {
+            let messages = response
+                .errors
+                .iter()
+                .map(|error| error.message.as_str())
+                .collect::<Vec<_>>()
+                .join("; ");
+            anyhow::bail!("{context}: GitLab API error: {messages}");
+        }
+
+        response
+            .data
+            .with_context(|| format!("{context}: GraphQL response contained no data"))
+    }
+
+    /// Converts a merge request, then pages back through its notes
+    /// until they cover what `options` asks for.
+    async fn convert_with_comment_history(
+        &self,
+        mr: GraphQLMergeRequest,
+        repo: &Repo,
+        options: &FetchOptions,
+    ) -> Result<PullRequest> {
+        let older = mr.notes.older_cursor();
+        let oldest_note = mr.notes.oldest();
+        let mut pr = convert_merge_request(mr, repo.clone(), &self.base_url)?;
+        self.complete_comment_history(&mut pr, older, oldest_note, options)
+            .await;
+        Ok(pr)
+    }
+
+    /// Pages back through a merge request's notes until they cover what
+    /// `options` asks for, or there are no older ones.
+    ///
+    /// `older` is the cursor before the notes fetched with the merge
+    /// request, and `oldest_note` when the oldest of them was made. Sets
+    /// `comment_history_complete` to say whether the history now covers
+    /// the window; a failed page leaves it incomplete rather than
+    /// failing the fetch, as the GitHub adapter does.
+    async fn complete_comment_history(
+        &self,
+        pr: &mut PullRequest,
+        mut older: Option<String>,
+        mut oldest_note: Option<DateTime<Utc>>,
+        options: &FetchOptions,
+    ) {
+        while older.is_some() && needs_older_notes(pr, oldest_note, options) {
+            let query = GraphQLQueryBuilder::merge_request_notes(&pr.repo.to_string(), pr.number)
+                .with_before_cursor(older.take())
+                .build();
+            let context = format!("Note history query for {}!{}", pr.repo, pr.number);
+            let notes = match self
+                .execute_graphql_query::<ProjectData<ProjectMergeRequestNotes>>(query, &context)
+                .await
+            {
+                Ok(data) => data
+                    .project
+                    .and_then(|project| project.merge_request)
+                    .map(|mr| mr.notes),
+                Err(e) => {
+                    warn!(
+                        repo = %pr.repo,
+                        number = pr.number,
+                        error = %e,
+                        "Failed to fetch older notes, history is incomplete"
+                    );
+                    None
+                }
+            };
+            let Some(notes) = notes else {
+                pr.comment_history_complete = false;
+                return;
+            };
+
+            older = notes.older_cursor();
+            oldest_note = notes.oldest().or(oldest_note);
+            let mut history = convert_notes(notes);
+            history.append(&mut pr.recent_comments);
+            pr.recent_comments = history;
+            debug!(
+                repo = %pr.repo,
+                number = pr.number,
+                comment_count = pr.recent_comments.len(),
+                "Fetched older notes"
+            );
+        }
+
+        pr.comment_history_complete =
+            older.is_none() || !needs_older_notes(pr, oldest_note, options);
+    }
+
+    /// Fetches a single merge request by its project-scoped IID.
+    ///
+    /// A missing merge request is not an error, matching how the GitHub
+    /// adapter treats PR numbers that do not exist; a missing project is.
+    #[instrument(skip(self, options), fields(repo = %identifier.repo, number = identifier.number))]
+    async fn fetch_merge_request(
+        &self,
+        identifier: &PrIdentifier,
+        options: &FetchOptions,
+    ) -> Result<Option<PullRequest>> {
+        let repo = &identifier.repo;
+        let query = GraphQLQueryBuilder::merge_request(&repo.to_string(), identifier.number)
+            .with_changed_paths(options.changed_paths)
+            .with_commits(options.commits)
+            .build();
+        let context = format!("Merge request query for {repo}!{}", identifier.number);
+
+        let data: ProjectData<ProjectMergeRequest> =
+            self.execute_graphql_query(query, &context).await?;
+        let project = data
+            .project
+            .with_context(|| format!("Project '{repo}' does not exist or is not accessible"))?;
+
+        match project.merge_request {
+            Some(mr) => self
+                .convert_with_comment_history(mr, repo, options)
+                .await
+                .map(Some),
+            None => {
+                debug!("No merge request found");
+                Ok(None)
+            }
+        }
+    }
+
+    #[instrument(skip(self, options), fields(mr_count = identifiers.len()))]
+    async fn collect_specific_merge_requests(
+        &self,
+        identifiers: &[PrIdentifier],
+        options: &FetchOptions,
+    ) -> Result<Vec<PullRequest>> {
+        info!("Collecting specific merge requests");
+        let mut all_prs = Vec::with_capacity(identifiers.len());
+
+        for identifier in identifiers {
+            if let Some(pr) = self.fetch_merge_request(identifier, options).await? {
+                all_prs.push(pr);
+            }
+        }
+
+        info!(
+            found_count = all_prs.len(),
+            "Collected specific merge requests"
+        );
+        Ok(all_prs)
+    }
+
+    /// Lists a project's open merge requests, oldest first, up to the
+    /// search limit.
+    ///
+    /// A failure on the first page is an error. A failure on a later
+    /// page returns the merge requests gathered so far, marked
+    /// incomplete, as the GitHub adapter's pagination does.
+    #[instrument(skip(self, options), fields(repo = %search.repo, limit = search.limit))]
+    async fn fetch_project_merge_requests(
+        &self,
+        search: &RepoSearch,
+        options: &FetchOptions,
+    ) -> Result<FetchOutcome> {
+        info!("Fetching merge requests with pagination");
+        let full_path = search.repo.to_string();
+        let filter = MergeRequestFilter::from_criteria(&search.criteria);
+        let mut all_prs = Vec::with_capacity(search.limit.min(100));
+        let mut incomplete = Vec::new();
+        let mut after_cursor: Option<String> = None;
+        let mut page_count = 0;
+
+        while all_prs.len() < search.limit {
+            page_count += 1;
+            debug!(page = page_count, cursor = ?after_cursor, "Fetching page");
+
+            let query = GraphQLQueryBuilder::project_merge_requests(&full_path)
+                .with_filter(&filter)
+                .with_changed_paths(options.changed_paths)
+                .with_commits(options.commits)
+                .with_after_cursor(after_cursor.clone())
+                .build();
+            let context = format!("Merge request query page {page_count} for {full_path}");
+
+            let data: ProjectData<ProjectMergeRequests> =
+                match self.execute_graphql_query(query, &context).await {
+                    Ok(data) => data,
+                    Err(e) if page_count > 1 => {
+                        warn!(
+                            page = page_count,
+                            error = %e,
+                            current_pr_count = all_prs.len(),
+                            "GraphQL pagination failed, returning partial results"
+                        );
+                        incomplete.push(format!(
+                            "{full_path}: page {page_count} failed after {} merge requests: {e}",
+                            all_prs.len()
+                        ));
+                        break;
+                    }
+                    Err(e) => return Err(e),
+                };
+
+            let connection = data
+                .project
+                .with_context(|| {
+                    format!("Project '{full_path}' does not exist or is not accessible")
+                })?
+                .merge_requests;
+
+            for mr in connection.nodes {
+                match self
+                    .convert_with_comment_history(mr, &search.repo, options)
+                    .await
+                {
+                    Ok(pr) => all_prs.push(pr),
+                    Err(e) => warn!(error = %e, "Failed to convert GraphQL merge request"),
+                }
+            }
+
+            if !connection.page_info.has_next_page {
+                break;
+            }
+            after_cursor = connection.page_info.end_cursor;
+        }
+
+        all_prs.truncate(search.limit);
+        info!(
+            final_count = all_prs.len(),
+            pages = page_count,
+            "Pagination completed"
+        );
+        Ok(FetchOutcome {
+            pull_requests: all_prs,
+            incomplete,
+        })
+    }
+
+    #[instrument(skip(self, plan, options), fields(plan = ?plan))]
+    async fn fetch_gitlab_data(
+        &self,
+        plan: &FetchPlan,
+        options: &FetchOptions,
+    ) -> Result<FetchOutcome> {
+        info!("Starting GitLab data fetch");
+        match plan {
+            FetchPlan::SpecificPullRequests(identifiers) => self
+                .collect_specific_merge_requests(identifiers, options)
+                .await
+                .map(FetchOutcome::complete),
+            FetchPlan::UserSearch { .. } => {
+                anyhow::bail!(
+                    "GitLab has no free-text merge request search; use --repo with filters instead of --query"
+                )
+            }
+            FetchPlan::OrganisationSweep { .. } => {
+                anyhow::bail!(
+                    "Sweeping a GitLab group is not supported; name each project with --repo instead of --org"
+                )
+            }
+            FetchPlan::RepositorySearches(searches) => {
+                debug!("Fetching merge requests from {} project(s)", searches.len());
+                let mut outcome = FetchOutcome::default();
+                for search in searches {
+                    outcome.merge(self.fetch_project_merge_requests(search, options).await?);
+                }
+                Ok(outcome)
+            }
+        }
+    }
+}
+
+/// Whether notes reaching back to `oldest_note` fall short of
+/// `options`. System notes are not comments, but they still show how
+/// far back the notes fetched so far reach.
+fn needs_older_notes(
+    pr: &PullRequest,
+    oldest_note: Option<DateTime<Utc>>,
+    options: &FetchOptions,
+) -> bool {
+    if pr.recent_comments.len() < options.min_comments {
+        return true;
+    }
+    match (options.comments_since, oldest_note) {
+        (Some(since), Some(oldest)) => oldest > since,
+        _ => false,
+    }
+}
+
+#[async_trait]
+impl crate::types::Forge for GitLab {
+    /// Older notes are paged in as the comment history options ask,
+    /// and changed paths and commits are fetched when asked for.
+    async fn fetch_pull_requests(
+        &self,
+        plan: &FetchPlan,
+        options: &FetchOptions,
+    ) -> Result<FetchOutcome> {
+        self.fetch_gitlab_data(plan, options).await
+    }
+}
@@ -0,0 +1,599 @@
+//! Conversion from GitLab's GraphQL wire types ([`super::graphql`]) to
+//! the forge-neutral domain model ([`crate::types`]).
+//!
+//! GitLab's merge requests, pipeline jobs, and notes are mapped onto the
+//! same pull request, check, and comment types the GitHub adapter
+//! produces, so filtering and action planning need not know which forge
+//! a pull request came from.
+
+use anyhow::{Context, Result};
+use url::Url;
+
+use super::graphql::{
+    GraphQLCommitConnection, GraphQLJob, GraphQLJobStatus, GraphQLMergeRequest,
+    GraphQLMergeRequestState, GraphQLPipeline, GraphQLVerificationStatus, NoteConnection,
+    UserConnection,
+};
+use crate::types::{
+    CheckConclusion, CheckInfo, CheckName, CheckRunStatus, CheckUrl, CommentAuthor, CommentInfo,
+    CommitInfo, MergeState, Mergeable, PrState, PullRequest, Repo, SignatureState,
+    parse_issue_keys,
+};
+
+fn convert_merge_request_state(state: GraphQLMergeRequestState) -> PrState {
+    match state {
+        GraphQLMergeRequestState::Opened => PrState::Open,
+        // A locked merge request is closed to further discussion; for
+        // our purposes it is no longer actionable.
+        GraphQLMergeRequestState::Closed | GraphQLMergeRequestState::Locked => PrState::Closed,
+        GraphQLMergeRequestState::Merged => PrState::Merged,
+    }
+}
+
+fn convert_job_status(
+    status: GraphQLJobStatus,
+) -> (Option<CheckRunStatus>, Option<CheckConclusion>) {
+    match status {
+        GraphQLJobStatus::Created
+        | GraphQLJobStatus::WaitingForResource
+        | GraphQLJobStatus::Preparing
+        | GraphQLJobStatus::Pending
+        | GraphQLJobStatus::Scheduled
+        | GraphQLJobStatus::WaitingForCallback => (Some(CheckRunStatus::Queued), None),
+        GraphQLJobStatus::Running | GraphQLJobStatus::Canceling => {
+            (Some(CheckRunStatus::InProgress), None)
+        }
+        GraphQLJobStatus::Success => (
+            Some(CheckRunStatus::Completed),
+            Some(CheckConclusion::Success),
+        ),
+        GraphQLJobStatus::Failed => (
+            Some(CheckRunStatus::Completed),
+            Some(CheckConclusion::Failure),
+        ),
+        GraphQLJobStatus::Canceled => (
+            Some(CheckRunStatus::Completed),
+            Some(CheckConclusion::Cancelled),
+        ),
+        // A manual job has not run and may never run; treat it like a
+        // skipped one rather than as CI that is still in flight.
+        GraphQLJobStatus::Skipped | GraphQLJobStatus::Manual => (
+            Some(CheckRunStatus::Completed),
+            Some(CheckConclusion::Skipped),
+        ),
+        GraphQLJobStatus::Unknown => (None, None),
+    }
+}
+
+fn convert_job(job: GraphQLJob, base_url: &Url) -> CheckInfo {
+    let (run_status, conclusion) = job.status.map_or((None, None), convert_job_status);
+
+    CheckInfo {
+        name: CheckName::new(job.name.unwrap_or_else(|| "Unknown Job".to_string()))
+            .unwrap_or_else(|_| CheckName::new("Unknown").unwrap()),
+        conclusion,
+        run_status,
+        status_state: None,
+        url: job
+            .web_path
+            .and_then(|path| base_url.join(&path).ok())
+            .and_then(|url| CheckUrl::new(url).ok()),
+        // A job allowed to fail cannot fail the pipeline.
+        required: !job.allow_failure,
+        // A job still waiting for a runner has not started; count from
+        // when it was created.
+        started_at: job.started_at.or(Some(job.created_at)),
+        completed_at: job.finished_at,
+    }
+}
+
+fn convert_pipeline(pipeline: Option<GraphQLPipeline>, base_url: &Url) -> Vec<CheckInfo> {
+    pipeline
+        .and_then(|pipeline| pipeline.jobs)
+        .map_or_else(Vec::new, |jobs| {
+            jobs.nodes
+                .into_iter()
+                .map(|job| convert_job(job, base_url))
+                .collect()
+        })
+}
+
+pub(crate) fn convert_notes(notes: NoteConnection) -> Vec<CommentInfo> {
+    notes
+        .nodes
+        .into_iter()
+        // System notes ("added 1 commit", "changed the description")
+        // are GitLab's timeline events, not comments anyone wrote.
+        .filter(|note| !note.system)
+        .map(|note| CommentInfo {
+            body: note.body,
+            created_at: note.created_at,
+            author: note.author.map(|author| CommentAuthor {
+                login: author.username,
+                is_bot: author.bot,
+            }),
+        })
+        .collect()
+}
+
+/// The merge request's commits, oldest first. `None` when they were
+/// not asked for, or there are more than one page holds: a filter must
+/// not pass judgement on commits it has not seen.
+fn convert_commits(commits: Option<GraphQLCommitConnection>) -> Option<Vec<CommitInfo>> {
+    let commits = commits.filter(|commits| !commits.page_info.has_next_page)?;
+    let mut commits: Vec<CommitInfo> = commits
+        .nodes
+        .into_iter()
+        .map(|commit| CommitInfo {
+            headline: commit.title.unwrap_or_default(),
+            message: commit.message.unwrap_or_default(),
+            author: commit
+                .author
+                .map(|author| author.username)
+                .or(commit.author_name),
+            committer: commit.committer_name,
+            signature: match commit
+                .signature
+                .map(|signature| signature.verification_status)
+            {
+                Some(
+                    GraphQLVerificationStatus::Verified
+                    | GraphQLVerificationStatus::VerifiedSystem
+                    | GraphQLVerificationStatus::VerifiedCa,
+                ) => SignatureState::Verified,
+                Some(GraphQLVerificationStatus::Other) => SignatureState::Unverified,
+                None => SignatureState::Unsigned,
+            },
+            sha: commit.sha,
+        })
+        .collect();
+    // GitLab lists a merge request's commits newest first.
+    commits.reverse();
+    Some(commits)
+}
+
+fn usernames(users: Option<UserConnection>) -> Vec<String> {
+    users.map_or_else(Vec::new, |users| {
+        users.nodes.into_iter().map(|user| user.username).collect()
+    })
+}
+
+/// Converts a GitLab merge request to our domain model.
+///
+/// `base_url` is the GitLab instance root, used to turn the relative
+/// job paths GitLab reports into absolute check URLs.
+pub(crate) fn convert_merge_request(
+    mr: GraphQLMergeRequest,
+    repo: Repo,
+    base_url: &Url,
+) -> Result<PullRequest> {
+    let number = mr
+        .iid
+        .parse()
+        .with_context(|| format!("Merge request has a non-numeric IID '{}'", mr.iid))?;
+    let author = mr
+        .author
+        .map(|author| author.username)
+        .unwrap_or_else(|| "Unknown".to_string());
+
+    let size = mr.diff_stats_summary;
+    let issue_keys = parse_issue_keys(&[&mr.title, mr.description.as_deref().unwrap_or_default()]);
+
+    Ok(PullRequest {
+        repo,
+        number,
+        title: mr.title,
+        author_login: author.clone(),
+        author_simple_name: author,
+        url: mr.web_url,
+        labels: mr.labels.map_or_else(Vec::new, |labels| {
+            labels.nodes.into_iter().map(|label| label.title).collect()
+        }),
+        created_at: mr.created_at,
+        updated_at: mr.updated_at,
+        // GitLab reports no push time on the merge request itself.
+        last_pushed_at: None,
+        base_branch: mr.target_branch,
+        commit_count: mr.commit_count.unwrap_or_default(),
+        additions: size.as_ref().map(|size| size.additions),
+        deletions: size.as_ref().map(|size| size.deletions),
+        changed_files: size.as_ref().map(|size| size.file_count),
+        changed_paths: mr
+            .diff_stats
+            .map(|stats| stats.into_iter().map(|stat| stat.path).collect()),
+        commits: convert_commits(mr.commits),
+        // Which issues a merge request closes is not in GitLab's GraphQL
+        // schema.
+        linked_issues: None,
+        issue_keys,
+        is_draft: mr.draft,
+        state: convert_merge_request_state(mr.state),
+        checks: convert_pipeline(mr.head_pipeline, base_url),
+        comment_history_complete: !mr.notes.has_older(),
+        recent_comments: convert_notes(mr.notes),
+        // GitLab approvals are not fetched yet; reviewer filters match
+        // no merge request.
+        review_decision: None,
+        reviews: vec![],
+        // GitLab keeps reviewers listed after they review, so this
+        // over-reports who still has a request outstanding.
+        requested_reviewers: usernames(mr.reviewers),
+        assignees: usernames(mr.assignees),
+        mergeable: if mr.conflicts {
+            Mergeable::Conflicting
+        } else {
+            Mergeable::Mergeable
+        },
+        // GitLab's merge status has no one-to-one GitHub equivalent.
+        merge_state: MergeState::Unknown,
+        head_branch: mr.source_branch,
+        head_repo_owner: mr
+            .source_project
+            .and_then(|project| project.namespace)
+            .map(|namespace| namespace.full_path),
+        head_sha: mr.diff_head_sha.unwrap_or_default(),
+        is_cross_repository: mr
+            .source_project_id
+            .is_some_and(|id| id != mr.target_project_id),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+
+    use super::{super::graphql::*, *};
+
+    fn base_url() -> Url {
+        Url::parse("https://gitlab.example.com").unwrap()
+    }
+
+    fn create_test_merge_request() -> GraphQLMergeRequest {
+        GraphQLMergeRequest {
+            iid: "42".to_string(),
+            title: "Fix pipeline".to_string(),
+            description: Some("Follow-up to OCPBUGS-7.".to_string()),
+            web_url: "https://gitlab.example.com/group/project/-/merge_requests/42".to_string(),
+            state: GraphQLMergeRequestState::Opened,
+            draft: false,
+            conflicts: false,
+            created_at: DateTime::from_timestamp(1609459200, 0).unwrap(),
+            updated_at: DateTime::from_timestamp(1609459500, 0).unwrap(),
+            target_branch: "main".to_string(),
+            source_branch: "fix-pipeline".to_string(),
+            diff_head_sha: Some("abc123".to_string()),
+            source_project_id: Some(7),
+            target_project_id: 3,
+            source_project: Some(GraphQLProject {
+                namespace: Some(GraphQLNamespace {
+                    full_path: "alice".to_string(),
+                }),
+            }),
+            commit_count: Some(2),
+            diff_stats_summary: Some(GraphQLDiffStatsSummary {
+                additions: 40,
+                deletions: 2,
+                file_count: 3,
+            }),
+            diff_stats: Some(vec![
+                GraphQLDiffStats {
+                    path: "ci/pipeline.yml".to_string(),
+                },
+                GraphQLDiffStats {
+                    path: "README.md".to_string(),
+                },
+            ]),
+            commits: Some(GraphQLCommitConnection {
+                nodes: vec![
+                    GraphQLCommit {
+                        sha: "bbbb".to_string(),
+                        title: Some("Fix the pipeline".to_string()),
+                        message: Some("Fix the pipeline".to_string()),
+                        author_name: Some("Someone Else".to_string()),
+                        committer_name: Some("Someone Else".to_string()),
+                        author: None,
+                        signature: Some(GraphQLCommitSignature {
+                            verification_status: GraphQLVerificationStatus::Other,
+                        }),
+                    },
+                    GraphQLCommit {
+                        sha: "aaaa".to_string(),
+                        title: Some("Add the pipeline".to_string()),
+                        message: Some(
+                            "Add the pipeline\n\nSigned-off-by: Alice <alice@example.com>"
+                                .to_string(),
+                        ),
+                        author_name: Some("Alice".to_string()),
+                        committer_name: Some("Alice".to_string()),
+                        author: Some(GraphQLUser {
+                            username: "alice".to_string(),
+                            bot: false,
+                        }),
+                        signature: Some(GraphQLCommitSignature {
+                            verification_status: GraphQLVerificationStatus::Verified,
+                        }),
+                    },
+                ],
+                page_info: GraphQLCommitPageInfo {
+                    has_next_page: false,
+                },
+            }),
+            author: Some(GraphQLUser {
+                username: "alice".to_string(),
+                bot: false,
+            }),
+            assignees: Some(UserConnection {
+                nodes: vec![GraphQLUser {
+                    username: "bob".to_string(),
+                    bot: false,
+                }],
+            }),
+            reviewers: None,
+            labels: Some(LabelConnection {
+                nodes: vec![GraphQLLabel {
+                    title: "bug".to_string(),
+                }],
+            }),
+            head_pipeline: Some(GraphQLPipeline {
+                jobs: Some(JobConnection {
+                    nodes: vec![
+                        GraphQLJob {
+                            name: Some("unit".to_string()),
+                            status: Some(GraphQLJobStatus::Success),
+                            web_path: Some("/group/project/-/jobs/1".to_string()),
+                            allow_failure: false,
+                            created_at: DateTime::from_timestamp(1609459200, 0).unwrap(),
+                            started_at: DateTime::from_timestamp(1609459260, 0),
+                            finished_at: DateTime::from_timestamp(1609459500, 0),
+                        },
+                        GraphQLJob {
+                            name: Some("e2e".to_string()),
+                            status: Some(GraphQLJobStatus::Failed),
+                            web_path: Some("/group/project/-/jobs/2".to_string()),
+                            allow_failure: true,
+                            created_at: DateTime::from_timestamp(1609459200, 0).unwrap(),
+                            started_at: None,
+                            finished_at: None,
+                        },
+                        GraphQLJob {
+                            name: Some("lint".to_string()),
+                            status: Some(GraphQLJobStatus::Running),
+                            web_path: None,
+                            allow_failure: false,
+                            created_at: DateTime::from_timestamp(1609459200, 0).unwrap(),
+                            started_at: None,
+                            finished_at: None,
+                        },
+                    ],
+                }),
+            }),
+            notes: NoteConnection {
+                nodes: vec![
+                    GraphQLNote {
+                        body: "added 1 commit".to_string(),
+                        created_at: DateTime::from_timestamp(1609459300, 0).unwrap(),
+                        system: true,
+                        author: None,
+                    },
+                    GraphQLNote {
+                        body: "/retest".to_string(),
+                        created_at: DateTime::from_timestamp(1609459400, 0).unwrap(),
+                        system: false,
+                        author: Some(GraphQLUser {
+                            username: "carol".to_string(),
+                            bot: false,
+                        }),
+                    },
+                ],
+                page_info: NotePageInfo {
+                    has_previous_page: false,
+                    start_cursor: None,
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn converts_merge_request_core_fields() {
+        let repo = Repo::new("group", "project").unwrap();
+
+        let pr =
+            convert_merge_request(create_test_merge_request(), repo.clone(), &base_url()).unwrap();
+
+        assert_eq!(pr.repo, repo);
+        assert_eq!(pr.number, 42);
+        assert_eq!(pr.title, "Fix pipeline");
+        assert_eq!(pr.author_login, "alice");
+        assert_eq!(
+            pr.url,
+            "https://gitlab.example.com/group/project/-/merge_requests/42"
+        );
+        assert_eq!(pr.labels, vec!["bug"]);
+        assert_eq!(pr.base_branch, "main");
+        assert_eq!(
+            pr.updated_at,
+            DateTime::from_timestamp(1609459500, 0).unwrap()
+        );
+        assert_eq!(pr.commit_count, 2);
+        assert_eq!(
+            (pr.additions, pr.deletions, pr.changed_files),
+            (Some(40), Some(2), Some(3))
+        );
+        assert_eq!(
+            pr.changed_paths,
+            Some(vec!["ci/pipeline.yml".to_string(), "README.md".to_string()])
+        );
+        assert_eq!(pr.state, PrState::Open);
+        assert_eq!(pr.mergeable, Mergeable::Mergeable);
+        assert_eq!(pr.assignees, vec!["bob"]);
+        assert!(pr.requested_reviewers.is_empty());
+    }
+
+    #[test]
+    fn merge_requests_from_another_project_are_cross_repository() {
+        let repo = Repo::new("group", "project").unwrap();
+
+        let pr =
+            convert_merge_request(create_test_merge_request(), repo.clone(), &base_url()).unwrap();
+        assert_eq!(pr.head_branch, "fix-pipeline");
+        assert_eq!(pr.head_repo_owner.as_deref(), Some("alice"));
+        assert_eq!(pr.head_sha, "abc123");
+        assert!(pr.is_cross_repository);
+
+        let mut mr = create_test_merge_request();
+        mr.source_project_id = Some(mr.target_project_id);
+        let pr = convert_merge_request(mr, repo, &base_url()).unwrap();
+        assert!(!pr.is_cross_repository);
+    }
+
+    #[test]
+    fn converts_pipeline_jobs_to_checks_with_absolute_urls() {
+        let pr = convert_merge_request(
+            create_test_merge_request(),
+            Repo::new("group", "project").unwrap(),
+            &base_url(),
+        )
+        .unwrap();
+
+        assert_eq!(pr.checks.len(), 3);
+        assert_eq!(pr.checks[0].conclusion, Some(CheckConclusion::Success));
+        assert_eq!(
+            pr.checks[0].url.as_ref().map(|url| url.as_str()),
+            Some("https://gitlab.example.com/group/project/-/jobs/1")
+        );
+        assert!(pr.checks[1].is_failed());
+        assert_eq!(pr.checks[2].run_status, Some(CheckRunStatus::InProgress));
+        assert!(pr.checks[2].url.is_none());
+        assert!(pr.has_failing_ci());
+        assert!(pr.checks[0].required && !pr.checks[1].required);
+        assert!(
+            !pr.has_failing_required_ci(),
+            "the only failure is allowed to fail"
+        );
+        assert_eq!(
+            pr.checks[0].started_at,
+            DateTime::from_timestamp(1609459260, 0)
+        );
+        assert_eq!(
+            pr.checks[0].completed_at,
+            DateTime::from_timestamp(1609459500, 0)
+        );
+        assert_eq!(
+            pr.checks[2].started_at,
+            DateTime::from_timestamp(1609459200, 0),
+            "a job with no start time counts from its creation"
+        );
+    }
+
+    #[test]
+    fn issue_keys_come_from_the_title_and_description() {
+        let mut mr = create_test_merge_request();
+        mr.title = "OCPBUGS-9: Fix pipeline".to_string();
+        let pr =
+            convert_merge_request(mr, Repo::new("group", "project").unwrap(), &base_url()).unwrap();
+
+        assert_eq!(pr.issue_keys, vec!["OCPBUGS-9", "OCPBUGS-7"]);
+        assert!(pr.linked_issues.is_none());
+        let ocpbugs = regex::Regex::new("^OCPBUGS-").unwrap();
+        assert_eq!(pr.references_issue(Some(&ocpbugs)), Some(true));
+    }
+
+    #[test]
+    fn key_shaped_words_do_not_link_a_merge_request() {
+        let mut mr = create_test_merge_request();
+        mr.title = "Bump SHA-256 helper to UTF-8".to_string();
+        mr.description = None;
+        let pr =
+            convert_merge_request(mr, Repo::new("group", "project").unwrap(), &base_url()).unwrap();
+
+        assert_eq!(pr.issue_keys, vec!["SHA-256", "UTF-8"]);
+        // GitLab does not say which issues it closes, so whether it
+        // references one stays unknown rather than becoming true.
+        assert_eq!(pr.references_issue(None), None);
+        let ocpbugs = regex::Regex::new("^OCPBUGS-").unwrap();
+        assert_eq!(pr.references_issue(Some(&ocpbugs)), None);
+    }
+
+    #[test]
+    fn converts_commits_oldest_first() {
+        let pr = convert_merge_request(
+            create_test_merge_request(),
+            Repo::new("group", "project").unwrap(),
+            &base_url(),
+        )
+        .unwrap();
+
+        let commits = pr.commits.unwrap();
+        assert_eq!(commits.len(), 2);
+        assert_eq!(commits[0].sha, "aaaa");
+        assert_eq!(commits[0].author.as_deref(), Some("alice"));
+        assert_eq!(commits[0].signature, SignatureState::Verified);
+        assert!(commits[0].is_signed_off());
+        assert_eq!(commits[1].headline, "Fix the pipeline");
+        assert_eq!(commits[1].author.as_deref(), Some("Someone Else"));
+        assert_eq!(commits[1].signature, SignatureState::Unverified);
+        assert!(!commits[1].is_signed_off());
+    }
+
+    #[test]
+    fn partial_commit_lists_are_dropped() {
+        let mut mr = create_test_merge_request();
+        if let Some(commits) = mr.commits.as_mut() {
+            commits.page_info.has_next_page = true;
+        }
+
+        let pr =
+            convert_merge_request(mr, Repo::new("group", "project").unwrap(), &base_url()).unwrap();
+
+        assert!(pr.commits.is_none());
+    }
+
+    #[test]
+    fn drops_system_notes() {
+        let pr = convert_merge_request(
+            create_test_merge_request(),
+            Repo::new("group", "project").unwrap(),
+            &base_url(),
+        )
+        .unwrap();
+
+        assert_eq!(pr.recent_comments.len(), 1);
+        assert_eq!(pr.recent_comments[0].body, "/retest");
+        assert!(pr.recent_comments[0].is_by("carol"));
+    }
+
+    #[test]
+    fn older_notes_mark_history_incomplete() {
+        let repo = Repo::new("group", "project").unwrap();
+        let pr =
+            convert_merge_request(create_test_merge_request(), repo.clone(), &base_url()).unwrap();
+        assert!(pr.comment_history_complete);
+
+        let mut mr = create_test_merge_request();
+        mr.notes.page_info = NotePageInfo {
+            has_previous_page: true,
+            start_cursor: Some("older".to_string()),
+        };
+        let pr = convert_merge_request(mr, repo, &base_url()).unwrap();
+        assert!(!pr.comment_history_complete);
+    }
+
+    #[test]
+    fn rejects_non_numeric_iid() {
+        let mut mr = create_test_merge_request();
+        mr.iid = "abc".to_string();
+
+        let err = convert_merge_request(mr, Repo::new("group", "project").unwrap(), &base_url())
+            .unwrap_err();
+
+        assert!(err.to_string().contains("'abc'"));
+    }
+
+    #[test]
+    fn locked_merge_requests_are_closed() {
+        assert_eq!(
+            convert_merge_request_state(GraphQLMergeRequestState::Locked),
+            PrState::Closed
+        );
+    }
+}
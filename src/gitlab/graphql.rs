@@ -0,0 +1,483 @@
+//! GitLab GraphQL protocol layer: the outgoing query builder and the
+//! incoming wire types.
+//!
+//! Like its GitHub counterpart this is IO-free (sans-IO) and models
+//! GitLab's external GraphQL contract, not the domain. The IO that
+//! drives it lives in the parent [`super`] module; the wire -> domain
+//! conversion lives in [`super::convert`].
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use super::search::MergeRequestFilter;
+
+const MERGE_REQUEST_FIELDS: &str = include_str!("merge_request_fields.graphql");
+
+/// Query builder for the GitLab GraphQL endpoint. Mirrors the GitHub
+/// adapter's builder so both adapters read the same way.
+pub(crate) struct GraphQLQueryBuilder {
+    query: String,
+    variables: HashMap<String, serde_json::Value>,
+}
+
+impl GraphQLQueryBuilder {
+    /// Create a query listing a project's open merge requests.
+    pub(crate) fn project_merge_requests(full_path: &str) -> Self {
+        Self::with_fragment(include_str!("project_merge_requests.graphql"))
+            .with_variable("fullPath", full_path.into())
+    }
+
+    /// Create a query fetching one merge request by its project-scoped IID.
+    pub(crate) fn merge_request(full_path: &str, iid: u64) -> Self {
+        Self::with_fragment(include_str!("merge_request.graphql"))
+            .with_variable("fullPath", full_path.into())
+            .with_variable("iid", iid.to_string().into())
+    }
+
+    /// Create a query for a page of one merge request's notes, newest
+    /// first.
+    pub(crate) fn merge_request_notes(full_path: &str, iid: u64) -> Self {
+        Self {
+            query: include_str!("merge_request_notes.graphql").to_string(),
+            variables: HashMap::new(),
+        }
+        .with_variable("fullPath", full_path.into())
+        .with_variable("iid", iid.to_string().into())
+    }
+
+    fn with_fragment(query: &str) -> Self {
+        Self {
+            query: format!("{query}\n{MERGE_REQUEST_FIELDS}"),
+            variables: HashMap::new(),
+        }
+    }
+
+    fn with_variable(mut self, name: &str, value: serde_json::Value) -> Self {
+        self.variables.insert(name.to_string(), value);
+        self
+    }
+
+    /// Narrow the merge request listing server-side.
+    pub(crate) fn with_filter(self, filter: &MergeRequestFilter) -> Self {
+        fn list(values: &[String]) -> serde_json::Value {
+            if values.is_empty() {
+                serde_json::Value::Null
+            } else {
+                values.into()
+            }
+        }
+
+        fn time(value: Option<DateTime<Utc>>) -> serde_json::Value {
+            value.map_or(serde_json::Value::Null, |time| time.to_rfc3339().into())
+        }
+
+        self.with_variable("labels", list(&filter.labels))
+            .with_variable("notLabels", list(&filter.not_labels))
+            .with_variable("targetBranches", list(&filter.target_branches))
+            .with_variable(
+                "assigneeUsername",
+                filter
+                    .assignee_username
+                    .as_ref()
+                    .map_or(serde_json::Value::Null, |user| user.as_str().into()),
+            )
+            .with_variable(
+                "assigneeWildcardId",
+                if filter.unassigned {
+                    "NONE".into()
+                } else {
+                    serde_json::Value::Null
+                },
+            )
+            .with_variable(
+                "reviewerUsername",
+                filter
+                    .reviewer_username
+                    .as_ref()
+                    .map_or(serde_json::Value::Null, |user| user.as_str().into()),
+            )
+            .with_variable("createdBefore", time(filter.created_before))
+            .with_variable("createdAfter", time(filter.created_after))
+            .with_variable("updatedBefore", time(filter.updated_before))
+    }
+
+    /// Include the paths each merge request changes.
+    pub(crate) fn with_changed_paths(self, changed_paths: bool) -> Self {
+        self.with_variable("withPaths", changed_paths.into())
+    }
+
+    /// Include each merge request's commits.
+    pub(crate) fn with_commits(self, commits: bool) -> Self {
+        self.with_variable("withCommits", commits.into())
+    }
+
+    pub(crate) fn with_after_cursor(self, cursor: Option<String>) -> Self {
+        self.with_variable(
+            "after",
+            cursor.map_or(serde_json::Value::Null, |c| c.into()),
+        )
+    }
+
+    pub(crate) fn with_before_cursor(self, cursor: Option<String>) -> Self {
+        self.with_variable(
+            "before",
+            cursor.map_or(serde_json::Value::Null, |c| c.into()),
+        )
+    }
+
+    pub(crate) fn build(self) -> serde_json::Value {
+        serde_json::json!({
+            "query": self.query,
+            "variables": self.variables
+        })
+    }
+}
+
+/// Top-level GraphQL envelope. GitLab reports query problems in
+/// `errors` alongside a possibly partial `data`.
+#[derive(Debug, Deserialize)]
+pub(crate) struct GraphQLResponse<T> {
+    pub(crate) data: Option<T>,
+    #[serde(default)]
+    pub(crate) errors: Vec<GraphQLError>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct GraphQLError {
+    pub(crate) message: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct ProjectData<T> {
+    pub(crate) project: Option<T>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ProjectMergeRequests {
+    pub(crate) merge_requests: MergeRequestConnection,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ProjectMergeRequest {
+    pub(crate) merge_request: Option<GraphQLMergeRequest>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ProjectMergeRequestNotes {
+    pub(crate) merge_request: Option<MergeRequestNotes>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct MergeRequestNotes {
+    pub(crate) notes: NoteConnection,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct MergeRequestConnection {
+    pub(crate) nodes: Vec<GraphQLMergeRequest>,
+    pub(crate) page_info: PageInfo,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PageInfo {
+    pub(crate) has_next_page: bool,
+    pub(crate) end_cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum GraphQLMergeRequestState {
+    Opened,
+    Closed,
+    Locked,
+    Merged,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GraphQLMergeRequest {
+    pub(crate) iid: String,
+    pub(crate) title: String,
+    #[serde(deserialize_with = "Option::deserialize")]
+    pub(crate) description: Option<String>,
+    pub(crate) web_url: String,
+    pub(crate) state: GraphQLMergeRequestState,
+    pub(crate) draft: bool,
+    pub(crate) conflicts: bool,
+    pub(crate) created_at: DateTime<Utc>,
+    pub(crate) updated_at: DateTime<Utc>,
+    pub(crate) target_branch: String,
+    pub(crate) source_branch: String,
+    #[serde(deserialize_with = "Option::deserialize")]
+    pub(crate) diff_head_sha: Option<String>,
+    #[serde(deserialize_with = "Option::deserialize")]
+    pub(crate) source_project_id: Option<u64>,
+    pub(crate) target_project_id: u64,
+    /// Null when the source project has been deleted or is not visible.
+    #[serde(deserialize_with = "Option::deserialize")]
+    pub(crate) source_project: Option<GraphQLProject>,
+    pub(crate) commit_count: Option<u64>,
+    #[serde(deserialize_with = "Option::deserialize")]
+    pub(crate) diff_stats_summary: Option<GraphQLDiffStatsSummary>,
+    /// Only requested when changed paths are wanted.
+    #[serde(default)]
+    pub(crate) diff_stats: Option<Vec<GraphQLDiffStats>>,
+    /// Only requested when commits are wanted.
+    #[serde(default)]
+    pub(crate) commits: Option<GraphQLCommitConnection>,
+    pub(crate) author: Option<GraphQLUser>,
+    #[serde(deserialize_with = "Option::deserialize")]
+    pub(crate) assignees: Option<UserConnection>,
+    #[serde(deserialize_with = "Option::deserialize")]
+    pub(crate) reviewers: Option<UserConnection>,
+    pub(crate) labels: Option<LabelConnection>,
+    pub(crate) head_pipeline: Option<GraphQLPipeline>,
+    pub(crate) notes: NoteConnection,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct GraphQLProject {
+    pub(crate) namespace: Option<GraphQLNamespace>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GraphQLNamespace {
+    pub(crate) full_path: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GraphQLDiffStatsSummary {
+    pub(crate) additions: u64,
+    pub(crate) deletions: u64,
+    pub(crate) file_count: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct GraphQLDiffStats {
+    pub(crate) path: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GraphQLCommitConnection {
+    pub(crate) nodes: Vec<GraphQLCommit>,
+    pub(crate) page_info: GraphQLCommitPageInfo,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GraphQLCommitPageInfo {
+    pub(crate) has_next_page: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GraphQLCommit {
+    pub(crate) sha: String,
+    pub(crate) title: Option<String>,
+    pub(crate) message: Option<String>,
+    pub(crate) author_name: Option<String>,
+    #[serde(deserialize_with = "Option::deserialize")]
+    pub(crate) committer_name: Option<String>,
+    /// Null when the author's email maps to no GitLab user.
+    pub(crate) author: Option<GraphQLUser>,
+    /// Null for unsigned commits.
+    pub(crate) signature: Option<GraphQLCommitSignature>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GraphQLCommitSignature {
+    pub(crate) verification_status: GraphQLVerificationStatus,
+}
+
+/// GitLab's verdict on a signature; only the verified ones matter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub(crate) enum GraphQLVerificationStatus {
+    Verified,
+    VerifiedSystem,
+    VerifiedCa,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct GraphQLUser {
+    pub(crate) username: String,
+    /// Only selected for note authors.
+    #[serde(default)]
+    pub(crate) bot: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct UserConnection {
+    pub(crate) nodes: Vec<GraphQLUser>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct LabelConnection {
+    pub(crate) nodes: Vec<GraphQLLabel>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct GraphQLLabel {
+    pub(crate) title: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct GraphQLPipeline {
+    pub(crate) jobs: Option<JobConnection>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct JobConnection {
+    pub(crate) nodes: Vec<GraphQLJob>,
+}
+
+/// CI job status as reported by GitLab's `CiJobStatus` enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub(crate) enum GraphQLJobStatus {
+    Created,
+    WaitingForResource,
+    Preparing,
+    Pending,
+    Running,
+    Success,
+    Failed,
+    Canceled,
+    Canceling,
+    Skipped,
+    Manual,
+    Scheduled,
+    WaitingForCallback,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GraphQLJob {
+    pub(crate) name: Option<String>,
+    pub(crate) status: Option<GraphQLJobStatus>,
+    pub(crate) web_path: Option<String>,
+    pub(crate) allow_failure: bool,
+    pub(crate) created_at: DateTime<Utc>,
+    #[serde(deserialize_with = "Option::deserialize")]
+    pub(crate) started_at: Option<DateTime<Utc>>,
+    #[serde(deserialize_with = "Option::deserialize")]
+    pub(crate) finished_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct NoteConnection {
+    pub(crate) nodes: Vec<GraphQLNote>,
+    pub(crate) page_info: NotePageInfo,
+}
+
+impl NoteConnection {
+    /// Whether the merge request has notes older than these.
+    pub(crate) fn has_older(&self) -> bool {
+        self.page_info.has_previous_page
+    }
+
+    /// Cursor for the page before this one, if there are older notes.
+    pub(crate) fn older_cursor(&self) -> Option<String> {
+        self.page_info
+            .start_cursor
+            .clone()
+            .filter(|_| self.page_info.has_previous_page)
+    }
+
+    /// When the oldest of these notes was made, system notes included.
+    pub(crate) fn oldest(&self) -> Option<DateTime<Utc>> {
+        self.nodes.first().map(|note| note.created_at)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct NotePageInfo {
+    pub(crate) has_previous_page: bool,
+    pub(crate) start_cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct GraphQLNote {
+    pub(crate) body: String,
+    pub(crate) created_at: DateTime<Utc>,
+    pub(crate) system: bool,
+    #[serde(deserialize_with = "Option::deserialize")]
+    pub(crate) author: Option<GraphQLUser>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn project_merge_requests_query_carries_filter_variables() {
+        let filter = MergeRequestFilter {
+            labels: vec!["bug".to_string()],
+            not_labels: vec![],
+            target_branches: vec!["main".to_string()],
+            created_before: Some(DateTime::from_timestamp(1609459200, 0).unwrap()),
+            ..MergeRequestFilter::default()
+        };
+        let query = GraphQLQueryBuilder::project_merge_requests("group/project")
+            .with_filter(&filter)
+            .with_after_cursor(None)
+            .build();
+
+        let variables = query.get("variables").unwrap();
+        assert_eq!(variables["fullPath"], "group/project");
+        assert_eq!(variables["labels"], serde_json::json!(["bug"]));
+        assert!(variables["notLabels"].is_null());
+        assert_eq!(variables["targetBranches"], serde_json::json!(["main"]));
+        assert_eq!(variables["createdBefore"], "2021-01-01T00:00:00+00:00");
+        assert!(variables["createdAfter"].is_null());
+        assert!(variables["updatedBefore"].is_null());
+        assert!(variables["assigneeUsername"].is_null());
+        assert!(variables["assigneeWildcardId"].is_null());
+        assert!(variables["after"].is_null());
+
+        let query_str = query.get("query").unwrap().as_str().unwrap();
+        assert!(query_str.contains("mergeRequests("));
+        assert!(query_str.contains("fragment MergeRequestFields on MergeRequest"));
+    }
+
+    #[test]
+    fn merge_request_query_passes_iid_as_string() {
+        let query = GraphQLQueryBuilder::merge_request("group/project", 42)
+            .with_changed_paths(true)
+            .with_commits(true)
+            .build();
+
+        let variables = query.get("variables").unwrap();
+        assert_eq!(variables["fullPath"], "group/project");
+        assert_eq!(variables["iid"], "42");
+        assert_eq!(variables["withPaths"], true);
+        assert_eq!(variables["withCommits"], true);
+    }
+
+    #[test]
+    fn job_status_deserialises_known_and_unknown_values() {
+        let status: GraphQLJobStatus = serde_json::from_str(r#""WAITING_FOR_RESOURCE""#).unwrap();
+        assert_eq!(status, GraphQLJobStatus::WaitingForResource);
+
+        let status: GraphQLJobStatus = serde_json::from_str(r#""SOMETHING_NEW""#).unwrap();
+        assert_eq!(status, GraphQLJobStatus::Unknown);
+    }
+}
@@ -0,0 +1,121 @@
+//! GitLab command rendering for planned pull-request actions.
+//!
+//! The action intent is forge-neutral; this module renders that intent as
+//! GitLab CLI (`glab`) commands.
+
+use crate::{
+    render::ActionRenderer,
+    types::{PrAction, PullRequest},
+};
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GlabCliRenderer;
+
+impl ActionRenderer for GlabCliRenderer {
+    fn render(&self, pr: &PullRequest, action: &PrAction) -> String {
+        // glab takes a merge request by number, with the project given
+        // as a URL so the command carries the instance's host.
+        let target = format!("{} --repo {}", pr.number, project_url(pr));
+        match action {
+            PrAction::Close => format!("glab mr close {target}"),
+            PrAction::Merge => format!("glab mr merge {target} --yes"),
+            PrAction::Comment(action) => {
+                format!("glab mr note {target} --message \"{}\"", action.body())
+            }
+            PrAction::GroupedComment(actions) => {
+                let body = actions
+                    .iter()
+                    .map(|action| action.body())
+                    .collect::<Vec<_>>()
+                    .join("\\n");
+                format!("glab mr note {target} --message $'{body}'")
+            }
+        }
+    }
+}
+
+/// The project's web URL: the merge request URL without its
+/// `/-/merge_requests/N` suffix.
+fn project_url(pr: &PullRequest) -> &str {
+    pr.url
+        .split_once("/-/merge_requests/")
+        .map_or(pr.url.as_str(), |(project, _)| project)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+    use crate::types::{CommentAction, MergeState, Mergeable, PrState, Repo};
+
+    fn pr() -> PullRequest {
+        PullRequest {
+            repo: Repo::new("group", "project").unwrap(),
+            number: 12,
+            title: "Test MR".to_string(),
+            author_login: "alice".to_string(),
+            author_simple_name: "alice".to_string(),
+            url: "https://gitlab.com/group/project/-/merge_requests/12".to_string(),
+            labels: vec![],
+            created_at: Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap(),
+            updated_at: Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap(),
+            last_pushed_at: None,
+            base_branch: "main".to_string(),
+            commit_count: 1,
+            additions: None,
+            deletions: None,
+            changed_files: None,
+            changed_paths: None,
+            commits: None,
+            linked_issues: None,
+            issue_keys: vec![],
+            is_draft: false,
+            state: PrState::Open,
+            checks: vec![],
+            recent_comments: vec![],
+            comment_history_complete: true,
+            review_decision: None,
+            reviews: vec![],
+            requested_reviewers: vec![],
+            assignees: vec![],
+            mergeable: Mergeable::Unknown,
+            merge_state: MergeState::Unknown,
+            head_branch: "feature".to_string(),
+            head_repo_owner: None,
+            head_sha: String::new(),
+            is_cross_repository: false,
+        }
+    }
+
+    #[test]
+    fn renders_comments_as_notes_on_the_project() {
+        assert_eq!(
+            GlabCliRenderer.render(&pr(), &PrAction::Comment(CommentAction::Lgtm)),
+            "glab mr note 12 --repo https://gitlab.com/group/project --message \"/lgtm\""
+        );
+        assert_eq!(
+            GlabCliRenderer.render(
+                &pr(),
+                &PrAction::comments(vec![
+                    CommentAction::Approve,
+                    CommentAction::Custom("Please review".to_string()),
+                ])
+                .unwrap(),
+            ),
+            "glab mr note 12 --repo https://gitlab.com/group/project --message $'/approve\\nPlease review'"
+        );
+    }
+
+    #[test]
+    fn renders_close_and_merge_actions() {
+        assert_eq!(
+            GlabCliRenderer.render(&pr(), &PrAction::Close),
+            "glab mr close 12 --repo https://gitlab.com/group/project"
+        );
+        assert_eq!(
+            GlabCliRenderer.render(&pr(), &PrAction::Merge),
+            "glab mr merge 12 --repo https://gitlab.com/group/project --yes"
+        );
+    }
+}
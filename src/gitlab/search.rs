@@ -0,0 +1,114 @@
+//! GitLab merge request filter compilation.
+//!
+//! GitLab has no free-text search equivalent to GitHub's; its merge
+//! request listing takes structured arguments instead. This module maps
+//! the forge-neutral criteria onto those arguments.
+
+use chrono::{DateTime, Utc};
+
+use crate::types::SearchCriterion;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct MergeRequestFilter {
+    pub(crate) labels: Vec<String>,
+    pub(crate) not_labels: Vec<String>,
+    pub(crate) target_branches: Vec<String>,
+    pub(crate) assignee_username: Option<String>,
+    pub(crate) unassigned: bool,
+    pub(crate) reviewer_username: Option<String>,
+    pub(crate) created_before: Option<DateTime<Utc>>,
+    pub(crate) created_after: Option<DateTime<Utc>>,
+    pub(crate) updated_before: Option<DateTime<Utc>>,
+}
+
+impl MergeRequestFilter {
+    pub(crate) fn from_criteria(criteria: &[SearchCriterion]) -> Self {
+        let mut filter = Self::default();
+        for criterion in criteria {
+            match criterion {
+                SearchCriterion::MissingLabel(label) => filter.not_labels.push(label.clone()),
+                SearchCriterion::PresentLabel(label) => filter.labels.push(label.clone()),
+                SearchCriterion::BaseBranch(branch) => filter.target_branches.push(branch.clone()),
+                // GitLab takes one user of each kind; any others are
+                // checked once the merge requests are fetched.
+                SearchCriterion::Assignee(user) => {
+                    filter.assignee_username.get_or_insert_with(|| user.clone());
+                }
+                SearchCriterion::Unassigned => filter.unassigned = true,
+                SearchCriterion::ReviewRequested(user) => {
+                    filter.reviewer_username.get_or_insert_with(|| user.clone());
+                }
+                // GitLab takes one bound of each kind; the tightest wins.
+                SearchCriterion::CreatedBefore(cutoff) => {
+                    filter.created_before =
+                        Some(filter.created_before.map_or(*cutoff, |b| b.min(*cutoff)));
+                }
+                SearchCriterion::CreatedAfter(cutoff) => {
+                    filter.created_after =
+                        Some(filter.created_after.map_or(*cutoff, |a| a.max(*cutoff)));
+                }
+                SearchCriterion::UpdatedBefore(cutoff) => {
+                    filter.updated_before =
+                        Some(filter.updated_before.map_or(*cutoff, |b| b.min(*cutoff)));
+                }
+            }
+        }
+        filter
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn criteria_map_onto_merge_request_arguments() {
+        let filter = MergeRequestFilter::from_criteria(&[
+            SearchCriterion::MissingLabel("approved".to_string()),
+            SearchCriterion::PresentLabel("bug".to_string()),
+            SearchCriterion::BaseBranch("main".to_string()),
+        ]);
+
+        assert_eq!(
+            filter,
+            MergeRequestFilter {
+                labels: vec!["bug".to_string()],
+                not_labels: vec!["approved".to_string()],
+                target_branches: vec!["main".to_string()],
+                ..MergeRequestFilter::default()
+            }
+        );
+    }
+
+    #[test]
+    fn user_criteria_send_the_first_of_each_kind() {
+        let filter = MergeRequestFilter::from_criteria(&[
+            SearchCriterion::Assignee("alice".to_string()),
+            SearchCriterion::Assignee("bob".to_string()),
+            SearchCriterion::ReviewRequested("carol".to_string()),
+            SearchCriterion::Unassigned,
+        ]);
+
+        assert_eq!(filter.assignee_username.as_deref(), Some("alice"));
+        assert_eq!(filter.reviewer_username.as_deref(), Some("carol"));
+        assert!(filter.unassigned);
+    }
+
+    #[test]
+    fn age_criteria_keep_the_tightest_bound() {
+        let at = |day| Utc.with_ymd_and_hms(2026, 5, day, 0, 0, 0).unwrap();
+        let filter = MergeRequestFilter::from_criteria(&[
+            SearchCriterion::CreatedBefore(at(20)),
+            SearchCriterion::CreatedBefore(at(10)),
+            SearchCriterion::CreatedAfter(at(1)),
+            SearchCriterion::CreatedAfter(at(5)),
+            SearchCriterion::UpdatedBefore(at(15)),
+        ]);
+
+        assert_eq!(filter.created_before, Some(at(10)));
+        assert_eq!(filter.created_after, Some(at(5)));
+        assert_eq!(filter.updated_before, Some(at(15)));
+    }
+}
@@ -8,7 +8,9 @@
 pub mod cli;
 pub mod decision;
 pub mod filters;
+pub mod forge;
 pub mod github;
+pub mod gitlab;
 pub mod pr_selector;
 pub mod query;
 pub mod render;
@@ -17,12 +19,16 @@ pub mod shell;
 pub mod types;
 
 pub use cli::parse_args;
+pub use forge::{ForgeRegistry, ForgeRegistryError};
 pub use github::{GhCliRenderer, GitHub};
+pub use gitlab::{GitLab, GlabCliRenderer};
 pub use pr_selector::{PrIdentifier, PrSelectorError};
 pub use query::{fetch_pull_requests, fetch_pull_requests_at};
 pub use types::{
     ActionPolicy, AppRequest, CheckConclusion, CheckInfo, CheckName, CheckNameError,
-    CheckRunStatus, CheckState, CheckUrl, CommentAction, CommentInfo, DisplayMode, DisplaySettings,
-    FetchCriteria, Forge, LogUrl, LogUrlError, PostFilter, PrAction, PrState, PullRequest,
-    QueryResult, QuerySpec, Repo, RepoError, RepoUrlError, SearchCriterion, SelectionPolicy, Task,
+    CheckRunStatus, CheckState, CheckUrl, CommentAction, CommentAuthor, CommentInfo, CommentScope,
+    CommitInfo, DisplayMode, DisplaySettings, FetchCriteria, FetchOutcome, Forge, ForgeSettings,
+    LinkedIssue, LogUrl, LogUrlError, MergeState, Mergeable, PostFilter, PrAction, PrState,
+    PullRequest, QueryResult, QuerySpec, Repo, RepoError, RepoUrlError, ReviewDecision, ReviewInfo,
+    ReviewState, SearchCriterion, SelectionPolicy, SignatureState, Task,
 };
@@ -4,11 +4,33 @@ use crate::types::{Repo, RepoUrlError, parse_forge_url};
 pub struct PrIdentifier {
     pub repo: Repo,
     pub number: u64,
+    /// Host the PR was named on (e.g. `github.com`), when it came from
+    /// a URL. `None` means the default forge.
+    pub host: Option<String>,
 }
 
 impl PrIdentifier {
     pub const fn new(repo: Repo, number: u64) -> Self {
-        Self { repo, number }
+        Self {
+            repo,
+            number,
+            host: None,
+        }
+    }
+
+    pub fn with_host(mut self, host: impl Into<String>) -> Self {
+        self.host = Some(host.into());
+        self
+    }
+}
+
+/// `owner/repo#123`, prefixed with the host when one was named.
+impl std::fmt::Display for PrIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(host) = &self.host {
+            write!(f, "{host}/")?;
+        }
+        write!(f, "{}#{}", self.repo, self.number)
     }
 }
 
@@ -98,7 +120,11 @@ pub fn parse_pr_url(token: &str) -> Result<PrIdentifier, PrSelectorError> {
     let number = extract_pr_number(&parsed.path_segments)
         .ok_or_else(|| PrSelectorError::UrlWithoutPull(token.to_string()))?;
 
-    Ok(PrIdentifier::new(parsed.repo, number))
+    let identifier = PrIdentifier::new(parsed.repo, number);
+    Ok(match parsed.host {
+        Some(host) => identifier.with_host(host),
+        None => identifier,
+    })
 }
 
 fn extract_pr_number(path_segments: &[String]) -> Option<u64> {
@@ -164,6 +190,20 @@ mod tests {
         assert_eq!(numbers, vec![1, 2, 3, 9, 11, 12]);
     }
 
+    #[test]
+    fn displays_repo_number_and_any_host() {
+        assert_eq!(
+            PrIdentifier::new(repo(), 7).to_string(),
+            "openshift/bpfman-operator#7"
+        );
+        assert_eq!(
+            PrIdentifier::new(repo(), 7)
+                .with_host("gitlab.com")
+                .to_string(),
+            "gitlab.com/openshift/bpfman-operator#7"
+        );
+    }
+
     #[test]
     fn treats_equal_bounds_as_single_pr() {
         assert_eq!(expand_pr_number_token("42-42").unwrap(), vec![42]);
@@ -204,6 +244,16 @@ mod tests {
         let identifier = parse_pr_url("https://github.com/owner/repo/pull/123").unwrap();
         assert_eq!(identifier.repo, Repo::new("owner", "repo").unwrap());
         assert_eq!(identifier.number, 123);
+        assert_eq!(identifier.host.as_deref(), Some("github.com"));
+    }
+
+    #[test]
+    fn pr_url_host_keeps_non_default_port() {
+        let identifier =
+            parse_pr_url("https://gitlab.example.com:8443/group/project/-/merge_requests/7")
+                .unwrap();
+        assert_eq!(identifier.host.as_deref(), Some("gitlab.example.com:8443"));
+        assert_eq!(identifier.number, 7);
     }
 
     #[test]
@@ -1,9 +1,14 @@
 use chrono::{DateTime, Utc};
+use tracing::warn;
 
 use crate::{
-    decision::{commit_limit_offenders, generate_executable_actions, pull_request_matches},
-    search::FetchPlan,
-    types::{Forge, PullRequest, QueryResult, QuerySpec, Task},
+    decision::{
+        commit_limit_offenders, generate_executable_actions, pull_request_matches,
+        unverified_comment_prs, window_start,
+    },
+    pr_selector::PrIdentifier,
+    search::{FetchOptions, FetchPlan},
+    types::{ActionPolicy, FetchOutcome, Forge, PullRequest, QueryResult, QuerySpec, Task},
 };
 
 /// Fetches and filters pull requests according to the query specification.
@@ -28,7 +33,33 @@ where
 {
     let fetch_plan = FetchPlan::from_criteria(&request.fetch)
         .ok_or_else(|| anyhow::anyhow!("Query is required when not fetching specific PRs"))?;
-    let all_prs = forge.fetch_pull_requests(&fetch_plan).await?;
+    let FetchOutcome {
+        pull_requests: all_prs,
+        mut incomplete,
+    } = forge
+        .fetch_pull_requests(
+            &fetch_plan,
+            &FetchOptions {
+                changed_paths: request.selection.needs_changed_paths(),
+                commits: request.selection.needs_commits(),
+                ..fetch_options(&request.action_policy, now)
+            },
+        )
+        .await?;
+    if request.selection.needs_required_checks() {
+        for branch in unknown_required_checks(&all_prs) {
+            warn!(
+                branch = %branch,
+                "No required checks known, so --failing-required-ci matches none of its PRs"
+            );
+        }
+    }
+    let missing_prs = match &fetch_plan {
+        FetchPlan::SpecificPullRequests(identifiers) => missing_prs(identifiers, &all_prs),
+        FetchPlan::UserSearch { .. }
+        | FetchPlan::RepositorySearches(_)
+        | FetchPlan::OrganisationSweep { .. } => vec![],
+    };
 
     let filtered_prs: Vec<PullRequest> = all_prs
         .into_iter()
@@ -37,15 +68,77 @@ where
 
     let executable_actions =
         generate_executable_actions(&filtered_prs, &request.action_policy, now);
+    incomplete.extend(
+        unverified_comment_prs(&filtered_prs, &request.action_policy, now)
+            .into_iter()
+            .map(|url| format!("{url}: comment history incomplete, so no comment was planned")),
+    );
 
     enforce_commit_limit(&executable_actions, request.action_policy.commit_limit)?;
+    if request.action_policy.strict && request.action_policy.has_actions() {
+        enforce_complete_fetch(&incomplete)?;
+    }
 
     Ok(QueryResult {
         filtered_prs,
         executable_actions,
+        missing_prs,
+        incomplete,
     })
 }
 
+/// Requested PRs the forge did not return, in request order: numbers
+/// that do not exist, or that the caller is not allowed to see.
+fn missing_prs(requested: &[PrIdentifier], fetched: &[PullRequest]) -> Vec<PrIdentifier> {
+    let mut missing: Vec<PrIdentifier> = Vec::new();
+    for identifier in requested {
+        let found = fetched
+            .iter()
+            .any(|pr| pr.repo == identifier.repo && pr.number == identifier.number);
+        if !found && !missing.contains(identifier) {
+            missing.push(identifier.clone());
+        }
+    }
+    missing
+}
+
+/// Base branches, as `owner/repo@branch` in first-seen order, whose
+/// PRs have checks but none the forge marked required. That is true of
+/// an unprotected branch, but also of one whose required checks come
+/// from rules the forge does not report, such as GitHub rulesets.
+fn unknown_required_checks(prs: &[PullRequest]) -> Vec<String> {
+    let mut known = Vec::new();
+    let mut unknown = Vec::new();
+    for pr in prs.iter().filter(|pr| !pr.checks.is_empty()) {
+        let branch = format!("{}@{}", pr.repo, pr.base_branch);
+        if pr.checks.iter().any(|check| check.required) {
+            known.push(branch);
+        } else if !unknown.contains(&branch) {
+            unknown.push(branch);
+        }
+    }
+    unknown.retain(|branch| !known.contains(branch));
+    unknown
+}
+
+/// Comment history the action policy's checks need. Without actions no
+/// history is consulted, so the forge's default is enough.
+fn fetch_options(policy: &ActionPolicy, now: DateTime<Utc>) -> FetchOptions {
+    if !policy.has_actions() {
+        return FetchOptions::default();
+    }
+
+    let window = policy.throttle.map_or(policy.history_max_age, |throttle| {
+        throttle.max(policy.history_max_age)
+    });
+    FetchOptions {
+        comments_since: Some(window_start(now, window)),
+        min_comments: policy.history_max_comments,
+        fresh: true,
+        ..FetchOptions::default()
+    }
+}
+
 fn enforce_commit_limit(tasks: &[Task], limit: u64) -> anyhow::Result<()> {
     let offenders = commit_limit_offenders(tasks, limit);
 
@@ -70,6 +163,18 @@ fn enforce_commit_limit(tasks: &[Task], limit: u64) -> anyhow::Result<()> {
     anyhow::bail!(msg)
 }
 
+fn enforce_complete_fetch(incomplete: &[String]) -> anyhow::Result<()> {
+    if incomplete.is_empty() {
+        return Ok(());
+    }
+
+    let mut msg = "Fetch was incomplete, so --strict emitted no commands:".to_string();
+    for reason in incomplete {
+        msg.push_str(&format!("\n  {reason}"));
+    }
+    anyhow::bail!(msg)
+}
+
 #[cfg(test)]
 mod tests {
     use std::{sync::Mutex, time::Duration};
@@ -79,37 +184,53 @@ mod tests {
 
     use super::*;
     use crate::{
-        filters::AuthorPost,
+        filters::{AuthorPost, MissingSignoffPost, TouchesPost},
         search::RepoSearch,
         types::{
-            ActionPolicy, CommentAction, FetchCriteria, PrAction, PrState, Repo, SearchCriterion,
-            SelectionPolicy,
+            ActionPolicy, CheckInfo, CheckName, CommentAction, CommentScope, FetchCriteria,
+            MergeState, Mergeable, PrAction, PrState, Repo, SearchCriterion, SelectionPolicy,
         },
     };
 
     struct RecordingForge {
         prs: Vec<PullRequest>,
+        incomplete: Vec<String>,
         seen_plan: Mutex<Option<FetchPlan>>,
+        seen_options: Mutex<Option<FetchOptions>>,
     }
 
     impl RecordingForge {
         fn new(prs: Vec<PullRequest>) -> Self {
             Self {
                 prs,
+                incomplete: vec![],
                 seen_plan: Mutex::new(None),
+                seen_options: Mutex::new(None),
             }
         }
 
         fn seen_plan(&self) -> Option<FetchPlan> {
             self.seen_plan.lock().unwrap().clone()
         }
+
+        fn seen_options(&self) -> Option<FetchOptions> {
+            *self.seen_options.lock().unwrap()
+        }
     }
 
     #[async_trait]
     impl Forge for RecordingForge {
-        async fn fetch_pull_requests(&self, plan: &FetchPlan) -> anyhow::Result<Vec<PullRequest>> {
+        async fn fetch_pull_requests(
+            &self,
+            plan: &FetchPlan,
+            options: &FetchOptions,
+        ) -> anyhow::Result<FetchOutcome> {
             *self.seen_plan.lock().unwrap() = Some(plan.clone());
-            Ok(self.prs.clone())
+            *self.seen_options.lock().unwrap() = Some(*options);
+            Ok(FetchOutcome {
+                pull_requests: self.prs.clone(),
+                incomplete: self.incomplete.clone(),
+            })
         }
     }
 
@@ -127,12 +248,32 @@ mod tests {
             url: format!("https://github.com/owner/repo/pull/{number}"),
             labels: labels.iter().map(|label| label.to_string()).collect(),
             created_at: Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap(),
+            updated_at: Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap(),
+            last_pushed_at: None,
             base_branch: "main".to_string(),
             commit_count: 1,
+            additions: None,
+            deletions: None,
+            changed_files: None,
+            changed_paths: None,
+            commits: None,
+            linked_issues: None,
+            issue_keys: vec![],
             is_draft: false,
             state: PrState::Open,
             checks: vec![],
             recent_comments: vec![],
+            comment_history_complete: true,
+            review_decision: None,
+            reviews: vec![],
+            requested_reviewers: vec![],
+            assignees: vec![],
+            mergeable: Mergeable::Unknown,
+            merge_state: MergeState::Unknown,
+            head_branch: "feature".to_string(),
+            head_repo_owner: None,
+            head_sha: String::new(),
+            is_cross_repository: false,
         }
     }
 
@@ -140,6 +281,7 @@ mod tests {
         QuerySpec {
             fetch: FetchCriteria {
                 repos: vec![repo()],
+                org: None,
                 prs: vec![],
                 query: None,
                 limit: 20,
@@ -154,11 +296,88 @@ mod tests {
                 throttle: None,
                 history_max_age: Duration::from_secs(3600),
                 history_max_comments: 10,
+                comment_scope: CommentScope::default(),
                 commit_limit: 10,
+                strict: false,
             },
         }
     }
 
+    #[tokio::test]
+    async fn path_filters_ask_the_forge_for_changed_paths() {
+        let forge = RecordingForge::new(vec![]);
+        let now = Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap();
+
+        fetch_pull_requests_at(&request(), &forge, now)
+            .await
+            .unwrap();
+        assert!(!forge.seen_options().unwrap().changed_paths);
+
+        let mut request = request();
+        request.selection.post_filters.push(Box::new(TouchesPost {
+            patterns: vec!["api/**".to_string()],
+        }));
+        fetch_pull_requests_at(&request, &forge, now).await.unwrap();
+        assert!(forge.seen_options().unwrap().changed_paths);
+    }
+
+    #[tokio::test]
+    async fn commit_filters_ask_the_forge_for_commits() {
+        let forge = RecordingForge::new(vec![]);
+        let now = Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap();
+
+        fetch_pull_requests_at(&request(), &forge, now)
+            .await
+            .unwrap();
+        assert!(!forge.seen_options().unwrap().commits);
+
+        let mut request = request();
+        request
+            .selection
+            .post_filters
+            .push(Box::new(MissingSignoffPost));
+        fetch_pull_requests_at(&request, &forge, now).await.unwrap();
+        let options = forge.seen_options().unwrap();
+        assert!(options.commits && !options.changed_paths);
+    }
+
+    #[test]
+    fn branches_without_known_required_checks_are_named_once() {
+        let check = |required| CheckInfo {
+            name: CheckName::new("ci").unwrap(),
+            conclusion: None,
+            run_status: None,
+            status_state: None,
+            url: None,
+            required,
+            started_at: None,
+            completed_at: None,
+        };
+        let mut unchecked = pr(1, "alice", &[]);
+        unchecked.base_branch = "release".to_string();
+        let mut optional = pr(2, "alice", &[]);
+        optional.checks = vec![check(false)];
+        let mut required = optional.clone();
+        required.number = 3;
+        required.checks.push(check(true));
+        let mut other_branch = optional.clone();
+        other_branch.base_branch = "release".to_string();
+
+        assert_eq!(
+            unknown_required_checks(&[
+                unchecked.clone(),
+                optional.clone(),
+                other_branch.clone(),
+                other_branch.clone()
+            ]),
+            vec!["owner/repo@main", "owner/repo@release"]
+        );
+        assert_eq!(
+            unknown_required_checks(&[unchecked, optional, required, other_branch]),
+            vec!["owner/repo@release"]
+        );
+    }
+
     #[tokio::test]
     async fn fetch_pull_requests_at_passes_plan_to_forge_then_filters_and_plans() {
         let forge = RecordingForge::new(vec![
@@ -201,6 +420,95 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn fetch_pull_requests_at_reports_requested_prs_the_forge_did_not_return() {
+        let mut request = request();
+        request.fetch.prs = [4, 5, 6, 5]
+            .into_iter()
+            .map(|number| PrIdentifier::new(repo(), number))
+            .collect();
+        let forge = RecordingForge::new(vec![pr(5, "alice", &[])]);
+        let now = Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap();
+
+        let result = fetch_pull_requests_at(&request, &forge, now).await.unwrap();
+
+        assert_eq!(
+            result.missing_prs,
+            vec![PrIdentifier::new(repo(), 4), PrIdentifier::new(repo(), 6)]
+        );
+    }
+
+    #[tokio::test]
+    async fn incomplete_fetch_is_reported_and_still_acted_on_by_default() {
+        let mut forge = RecordingForge::new(vec![pr(1, "alice", &[])]);
+        forge.incomplete = vec!["page 2 failed".to_string()];
+        let now = Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap();
+
+        let result = fetch_pull_requests_at(&request(), &forge, now)
+            .await
+            .unwrap();
+
+        assert_eq!(result.incomplete, vec!["page 2 failed".to_string()]);
+        assert_eq!(result.executable_actions.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn strict_refuses_actions_on_incomplete_fetch() {
+        let mut request = request();
+        request.action_policy.strict = true;
+        let mut forge = RecordingForge::new(vec![pr(1, "alice", &[])]);
+        forge.incomplete = vec!["page 2 failed".to_string()];
+        let now = Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap();
+
+        let err = fetch_pull_requests_at(&request, &forge, now)
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Fetch was incomplete, so --strict emitted no commands:\n  page 2 failed"
+        );
+
+        forge.incomplete.clear();
+        assert!(fetch_pull_requests_at(&request, &forge, now).await.is_ok());
+    }
+
+    #[test]
+    fn fetch_options_cover_the_longer_of_throttle_and_history_age() {
+        let now = Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap();
+        let mut policy = request().action_policy;
+
+        let options = fetch_options(&policy, now);
+        assert_eq!(
+            options.comments_since,
+            Some(Utc.with_ymd_and_hms(2026, 5, 29, 11, 0, 0).unwrap())
+        );
+        assert_eq!(options.min_comments, 10);
+
+        policy.throttle = Some(Duration::from_secs(2 * 3600));
+        assert_eq!(
+            fetch_options(&policy, now).comments_since,
+            Some(Utc.with_ymd_and_hms(2026, 5, 29, 10, 0, 0).unwrap())
+        );
+
+        policy.actions.clear();
+        assert_eq!(fetch_options(&policy, now), FetchOptions::default());
+    }
+
+    #[test]
+    fn fetch_options_for_a_window_older_than_time_itself_cover_all_history() {
+        let now = Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap();
+        let mut policy = request().action_policy;
+
+        for window in [9_999_999_999 * 3600, u64::MAX] {
+            policy.throttle = Some(Duration::from_secs(window));
+            assert_eq!(
+                fetch_options(&policy, now).comments_since,
+                Some(DateTime::<Utc>::MIN_UTC)
+            );
+        }
+    }
+
     #[tokio::test]
     async fn fetch_pull_requests_at_errors_before_forge_without_fetch_criteria() {
         let mut request = request();
@@ -2,9 +2,149 @@
 //!
 //! The core decides *what* to do through [`PrAction`] and [`Task`]. A forge
 //! adapter owns how that intent becomes an operator-facing command line.
+//!
+//! [`Task`]: crate::types::Task
+
+use url::Url;
 
-use crate::types::{PrAction, PullRequest};
+use crate::{
+    forge::normalise_host,
+    types::{PrAction, PullRequest},
+};
 
 pub trait ActionRenderer {
     fn render(&self, pr: &PullRequest, action: &PrAction) -> String;
 }
+
+/// Renderers keyed by host, with one default.
+///
+/// A run can mix pull requests from several forges, and each one's
+/// actions must be rendered for the CLI of the forge that hosts it.
+/// The renderer is chosen by the host in the PR's URL; PRs from a host
+/// with no registered renderer use the default.
+pub struct RendererRegistry {
+    default: Box<dyn ActionRenderer>,
+    renderers: Vec<(String, Box<dyn ActionRenderer>)>,
+}
+
+impl RendererRegistry {
+    pub fn new(default: impl ActionRenderer + 'static) -> Self {
+        Self {
+            default: Box::new(default),
+            renderers: Vec::new(),
+        }
+    }
+
+    /// Renders PRs on `host` with `renderer`, replacing any earlier
+    /// registration.
+    pub fn with_renderer(mut self, host: &str, renderer: impl ActionRenderer + 'static) -> Self {
+        let host = normalise_host(host);
+        self.renderers.retain(|(registered, _)| *registered != host);
+        self.renderers.push((host, Box::new(renderer)));
+        self
+    }
+
+    fn renderer_for(&self, pr: &PullRequest) -> &dyn ActionRenderer {
+        let Some(host) = url_host(&pr.url) else {
+            return self.default.as_ref();
+        };
+        self.renderers
+            .iter()
+            .find(|(registered, _)| *registered == host)
+            .map_or(self.default.as_ref(), |(_, renderer)| renderer.as_ref())
+    }
+}
+
+impl ActionRenderer for RendererRegistry {
+    fn render(&self, pr: &PullRequest, action: &PrAction) -> String {
+        self.renderer_for(pr).render(pr, action)
+    }
+}
+
+/// The host of a PR URL, with its port when it is not the default.
+fn url_host(url: &str) -> Option<String> {
+    let url = Url::parse(url).ok()?;
+    let host = url.host_str()?;
+    Some(match url.port() {
+        Some(port) => format!("{host}:{port}"),
+        None => host.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+    use crate::{
+        GhCliRenderer,
+        gitlab::GlabCliRenderer,
+        types::{CommentAction, MergeState, Mergeable, PrState, Repo},
+    };
+
+    fn pr(url: &str) -> PullRequest {
+        PullRequest {
+            repo: Repo::new("owner", "repo").unwrap(),
+            number: 7,
+            title: "Test PR".to_string(),
+            author_login: "alice".to_string(),
+            author_simple_name: "alice".to_string(),
+            url: url.to_string(),
+            labels: vec![],
+            created_at: Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap(),
+            updated_at: Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap(),
+            last_pushed_at: None,
+            base_branch: "main".to_string(),
+            commit_count: 1,
+            additions: None,
+            deletions: None,
+            changed_files: None,
+            changed_paths: None,
+            commits: None,
+            linked_issues: None,
+            issue_keys: vec![],
+            is_draft: false,
+            state: PrState::Open,
+            checks: vec![],
+            recent_comments: vec![],
+            comment_history_complete: true,
+            review_decision: None,
+            reviews: vec![],
+            requested_reviewers: vec![],
+            assignees: vec![],
+            mergeable: Mergeable::Unknown,
+            merge_state: MergeState::Unknown,
+            head_branch: "feature".to_string(),
+            head_repo_owner: None,
+            head_sha: String::new(),
+            is_cross_repository: false,
+        }
+    }
+
+    #[test]
+    fn renders_each_pr_for_the_forge_that_hosts_it() {
+        let renderers = RendererRegistry::new(GhCliRenderer)
+            .with_renderer("gitlab.com", GlabCliRenderer)
+            .with_renderer("GitLab.Example.com:8443", GlabCliRenderer);
+        let approve = PrAction::Comment(CommentAction::Approve);
+
+        assert_eq!(
+            renderers.render(&pr("https://github.com/owner/repo/pull/7"), &approve),
+            "gh pr comment https://github.com/owner/repo/pull/7 --body \"/approve\""
+        );
+        assert_eq!(
+            renderers.render(
+                &pr("https://gitlab.com/owner/repo/-/merge_requests/7"),
+                &approve
+            ),
+            "glab mr note 7 --repo https://gitlab.com/owner/repo --message \"/approve\""
+        );
+        assert_eq!(
+            renderers.render(
+                &pr("https://gitlab.example.com:8443/owner/repo/-/merge_requests/7"),
+                &PrAction::Merge
+            ),
+            "glab mr merge 7 --repo https://gitlab.example.com:8443/owner/repo --yes"
+        );
+    }
+}
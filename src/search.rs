@@ -1,6 +1,8 @@
+use chrono::{DateTime, Utc};
+
 use crate::{
     pr_selector::PrIdentifier,
-    types::{FetchCriteria, Repo, SearchCriterion},
+    types::{CommentInfo, FetchCriteria, Repo, SearchCriterion},
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -10,11 +12,128 @@ pub struct RepoSearch {
     pub limit: usize,
 }
 
+/// Which of an owner's repositories an `--org` sweep searches.
+///
+/// The forge lists the owner's unarchived repositories; this decides
+/// which of them are kept, by name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrgSweep {
+    pub org: String,
+    /// Keep repositories matching any of these globs; empty keeps all.
+    pub include: Vec<String>,
+    /// Drop repositories matching any of these globs, even if included.
+    pub exclude: Vec<String>,
+}
+
+impl OrgSweep {
+    pub fn matches(&self, name: &str) -> bool {
+        let matches = |pattern: &String| glob_matches(pattern, name);
+        (self.include.is_empty() || self.include.iter().any(matches))
+            && !self.exclude.iter().any(matches)
+    }
+
+    /// One search per listed repository the sweep keeps, in the order
+    /// the forge listed them.
+    pub fn searches(
+        &self,
+        repos: Vec<Repo>,
+        criteria: &[SearchCriterion],
+        limit: usize,
+    ) -> Vec<RepoSearch> {
+        repos
+            .into_iter()
+            .filter(|repo| self.matches(repo.name()))
+            .map(|repo| RepoSearch {
+                repo,
+                criteria: criteria.to_vec(),
+                limit,
+            })
+            .collect()
+    }
+}
+
+/// Shell-style match of a repository name: `*` matches any run of
+/// characters and `?` any one. Case is ignored, as forges do for names.
+fn glob_matches(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+    let name: Vec<char> = name.to_lowercase().chars().collect();
+
+    let (mut p, mut n) = (0, 0);
+    // Where the last `*` was, and how much of the name it has taken.
+    let mut backtrack: Option<(usize, usize)> = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star, taken)) => {
+                    backtrack = Some((star, taken + 1));
+                    p = star + 1;
+                    n = taken + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum FetchPlan {
     SpecificPullRequests(Vec<PrIdentifier>),
-    UserSearch { query: String, limit: usize },
+    UserSearch {
+        query: String,
+        limit: usize,
+    },
     RepositorySearches(Vec<RepoSearch>),
+    /// Repository searches over the repositories an owner has, which
+    /// the forge lists before expanding to [`Self::RepositorySearches`].
+    OrganisationSweep {
+        sweep: OrgSweep,
+        criteria: Vec<SearchCriterion>,
+        limit: usize,
+    },
+}
+
+/// How much of each pull request's history a fetch must include,
+/// beyond what the forge returns by default.
+///
+/// Derived from the action policy: throttling and history checks can
+/// only be trusted if every comment they look at was fetched.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FetchOptions {
+    /// Fetch every comment posted after this time.
+    pub comments_since: Option<DateTime<Utc>>,
+    /// Fetch at least this many of the most recent comments.
+    pub min_comments: usize,
+    /// Fetch the paths each pull request changes.
+    pub changed_paths: bool,
+    /// Fetch each pull request's commits.
+    pub commits: bool,
+    /// Ask the forge rather than serve cached responses. Set when the
+    /// results will be acted on, since a cached page cannot show the
+    /// comment a previous run just posted.
+    pub fresh: bool,
+}
+
+impl FetchOptions {
+    /// Whether `comments`, oldest first, fall short of these options.
+    /// Says nothing about whether older comments exist.
+    pub fn needs_older_comments(&self, comments: &[CommentInfo]) -> bool {
+        if comments.len() < self.min_comments {
+            return true;
+        }
+        match (self.comments_since, comments.first()) {
+            (Some(since), Some(oldest)) => oldest.created_at > since,
+            _ => false,
+        }
+    }
 }
 
 impl FetchPlan {
@@ -30,6 +149,14 @@ impl FetchPlan {
             });
         }
 
+        if let Some(sweep) = &criteria.org {
+            return Some(Self::OrganisationSweep {
+                sweep: sweep.clone(),
+                criteria: criteria.search_criteria.clone(),
+                limit: criteria.limit,
+            });
+        }
+
         if !criteria.repos.is_empty() {
             return Some(Self::RepositorySearches(
                 criteria
@@ -50,11 +177,14 @@ impl FetchPlan {
 
 #[cfg(test)]
 mod tests {
+    use chrono::TimeZone;
+
     use super::*;
 
     fn empty_criteria() -> FetchCriteria {
         FetchCriteria {
             repos: vec![],
+            org: None,
             prs: vec![],
             query: None,
             limit: 30,
@@ -66,6 +196,48 @@ mod tests {
         Repo::new("owner", "repo").unwrap()
     }
 
+    fn comments_at(hours: &[u32]) -> Vec<CommentInfo> {
+        hours
+            .iter()
+            .map(|hour| CommentInfo {
+                body: "/retest".to_string(),
+                created_at: Utc.with_ymd_and_hms(2026, 5, 29, *hour, 0, 0).unwrap(),
+                author: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn default_fetch_options_never_need_older_comments() {
+        assert!(!FetchOptions::default().needs_older_comments(&[]));
+        assert!(!FetchOptions::default().needs_older_comments(&comments_at(&[9])));
+    }
+
+    #[test]
+    fn fetch_options_need_older_comments_until_window_is_covered() {
+        let options = FetchOptions {
+            comments_since: Some(Utc.with_ymd_and_hms(2026, 5, 29, 10, 0, 0).unwrap()),
+            min_comments: 0,
+            ..FetchOptions::default()
+        };
+
+        assert!(options.needs_older_comments(&comments_at(&[11, 12])));
+        assert!(!options.needs_older_comments(&comments_at(&[9, 12])));
+        assert!(!options.needs_older_comments(&[]));
+    }
+
+    #[test]
+    fn fetch_options_need_older_comments_below_minimum_count() {
+        let options = FetchOptions {
+            comments_since: None,
+            min_comments: 3,
+            ..FetchOptions::default()
+        };
+
+        assert!(options.needs_older_comments(&comments_at(&[11, 12])));
+        assert!(!options.needs_older_comments(&comments_at(&[10, 11, 12])));
+    }
+
     #[test]
     fn fetch_plan_prefers_specific_pull_requests_over_query_and_repos() {
         let mut criteria = empty_criteria();
@@ -118,4 +290,69 @@ mod tests {
     fn fetch_plan_is_absent_without_fetch_criteria() {
         assert_eq!(FetchPlan::from_criteria(&empty_criteria()), None);
     }
+
+    fn sweep(include: &[&str], exclude: &[&str]) -> OrgSweep {
+        OrgSweep {
+            org: "openshift".to_string(),
+            include: include.iter().map(|glob| glob.to_string()).collect(),
+            exclude: exclude.iter().map(|glob| glob.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn fetch_plan_sweeps_an_organisation_over_repos() {
+        let mut criteria = empty_criteria();
+        criteria.repos = vec![repo()];
+        criteria.org = Some(sweep(&["*-operator"], &[]));
+        criteria.search_criteria = vec![SearchCriterion::BaseBranch("main".to_string())];
+
+        assert_eq!(
+            FetchPlan::from_criteria(&criteria),
+            Some(FetchPlan::OrganisationSweep {
+                sweep: sweep(&["*-operator"], &[]),
+                criteria: vec![SearchCriterion::BaseBranch("main".to_string())],
+                limit: 30,
+            })
+        );
+    }
+
+    #[test]
+    fn glob_matches_names() {
+        assert!(glob_matches("*-operator", "dns-operator"));
+        assert!(glob_matches("*-operator", "-operator"));
+        assert!(!glob_matches("*-operator", "dns-operator-bundle"));
+        assert!(glob_matches("cluster-*-operator", "cluster-dns-operator"));
+        assert!(glob_matches("api?", "api2"));
+        assert!(!glob_matches("api?", "api"));
+        assert!(glob_matches("*", ""));
+        assert!(glob_matches("Router", "router"));
+        assert!(!glob_matches("router", "routers"));
+    }
+
+    #[test]
+    fn org_sweep_includes_all_by_default_and_exclusions_win() {
+        assert!(sweep(&[], &[]).matches("anything"));
+        assert!(sweep(&["*-operator", "router"], &[]).matches("router"));
+        assert!(!sweep(&["*-operator"], &[]).matches("router"));
+        assert!(!sweep(&["*-operator"], &["legacy-*"]).matches("legacy-dns-operator"));
+        assert!(!sweep(&[], &["legacy-*"]).matches("legacy-router"));
+    }
+
+    #[test]
+    fn org_sweep_searches_kept_repositories_in_listed_order() {
+        let repos = ["router", "legacy-operator", "dns-operator", "api-operator"]
+            .map(|name| Repo::new("openshift", name).unwrap())
+            .to_vec();
+        let criteria = vec![SearchCriterion::BaseBranch("main".to_string())];
+
+        let searches = sweep(&["*-operator"], &["legacy-*"]).searches(repos, &criteria, 10);
+
+        let names: Vec<_> = searches.iter().map(|search| search.repo.name()).collect();
+        assert_eq!(names, vec!["dns-operator", "api-operator"]);
+        assert!(
+            searches
+                .iter()
+                .all(|search| search.criteria == criteria && search.limit == 10)
+        );
+    }
 }
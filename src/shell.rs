@@ -21,12 +21,24 @@ pub fn write_shell_commands<W: Write>(
     Ok(())
 }
 
+/// Writes one shell comment per reason the PR list is incomplete, so
+/// the warning travels with the commands when they are saved or
+/// reviewed, and is ignored when they are run.
+pub fn write_incomplete_warning<W: Write>(incomplete: &[String], writer: &mut W) -> Result<()> {
+    for reason in incomplete {
+        writeln!(writer, "# Warning: PR list is incomplete: {reason}")?;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::{TimeZone, Utc};
 
     use super::*;
-    use crate::types::{CommentAction, PrAction, PrState, PullRequest, Repo};
+    use crate::types::{
+        CommentAction, MergeState, Mergeable, PrAction, PrState, PullRequest, Repo,
+    };
 
     #[derive(Debug, Default)]
     struct FakeRenderer;
@@ -47,12 +59,32 @@ mod tests {
             url: "https://example.test/owner/repo/pull/123".to_string(),
             labels: vec![],
             created_at: Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap(),
+            updated_at: Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap(),
+            last_pushed_at: None,
             base_branch: "main".to_string(),
             commit_count: 1,
+            additions: None,
+            deletions: None,
+            changed_files: None,
+            changed_paths: None,
+            commits: None,
+            linked_issues: None,
+            issue_keys: vec![],
             is_draft: false,
             state: PrState::Open,
             checks: vec![],
             recent_comments: vec![],
+            comment_history_complete: true,
+            review_decision: None,
+            reviews: vec![],
+            requested_reviewers: vec![],
+            assignees: vec![],
+            mergeable: Mergeable::Unknown,
+            merge_state: MergeState::Unknown,
+            head_branch: "feature".to_string(),
+            head_repo_owner: None,
+            head_sha: String::new(),
+            is_cross_repository: false,
         }
     }
 
@@ -71,4 +103,20 @@ mod tests {
             "rendered:123:Comment(Custom(\"hello\")) # [main] Test PR\n"
         );
     }
+
+    #[test]
+    fn incomplete_warning_is_a_shell_comment_per_reason() {
+        let mut output = Vec::new();
+
+        write_incomplete_warning(
+            &["page 2 failed".to_string(), "capped".to_string()],
+            &mut output,
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "# Warning: PR list is incomplete: page 2 failed\n# Warning: PR list is incomplete: capped\n"
+        );
+    }
 }
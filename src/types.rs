@@ -1,11 +1,14 @@
-use std::time::Duration;
+use std::{path::PathBuf, sync::LazyLock, time::Duration};
 
 use anyhow::Result;
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeDelta, Utc};
 use url::Url;
 
-use crate::{pr_selector::PrIdentifier, search::FetchPlan};
+use crate::{
+    pr_selector::PrIdentifier,
+    search::{FetchOptions, FetchPlan, OrgSweep},
+};
 
 /// Error types for validation
 #[derive(Debug, Clone, PartialEq)]
@@ -219,6 +222,7 @@ impl std::error::Error for RepoUrlError {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct ParsedForgeUrl {
+    pub(crate) host: Option<String>,
     pub(crate) repo: Repo,
     pub(crate) path_segments: Vec<String>,
 }
@@ -319,7 +323,13 @@ pub(crate) fn parse_forge_url(url_str: &str) -> std::result::Result<ParsedForgeU
         source,
     })?;
 
+    let host = url.host_str().map(|host| match url.port() {
+        Some(port) => format!("{host}:{port}"),
+        None => host.to_string(),
+    });
+
     Ok(ParsedForgeUrl {
+        host,
         repo,
         path_segments,
     })
@@ -383,6 +393,14 @@ pub struct CheckInfo {
     pub run_status: Option<CheckRunStatus>,
     pub status_state: Option<CheckState>,
     pub url: Option<CheckUrl>,
+    /// True when the base branch needs this check to pass before
+    /// merging. False when it is optional, or the forge does not say.
+    pub required: bool,
+    /// When the check started running. For one still queued, and for
+    /// a commit status, when it was created instead.
+    pub started_at: Option<DateTime<Utc>>,
+    /// When the check finished; `None` until it has.
+    pub completed_at: Option<DateTime<Utc>>,
 }
 
 impl CheckInfo {
@@ -395,6 +413,30 @@ impl CheckInfo {
             Some(CheckState::Failure | CheckState::Error)
         )
     }
+
+    /// True while the check is queued or running.
+    pub fn is_pending(&self) -> bool {
+        matches!(
+            self.run_status,
+            Some(
+                CheckRunStatus::Queued
+                    | CheckRunStatus::InProgress
+                    | CheckRunStatus::Waiting
+                    | CheckRunStatus::Requested
+                    | CheckRunStatus::Pending
+            )
+        ) || self.status_state == Some(CheckState::Pending)
+    }
+
+    /// How long the check ran, or has been running as of `now`.
+    pub fn duration(&self, now: DateTime<Utc>) -> Option<TimeDelta> {
+        let started = self.started_at?;
+        match self.completed_at {
+            Some(completed) => Some(completed - started),
+            None if self.is_pending() => Some(now - started),
+            None => None,
+        }
+    }
 }
 
 /// Comment on a pull request.
@@ -402,6 +444,57 @@ impl CheckInfo {
 pub struct CommentInfo {
     pub body: String,
     pub created_at: DateTime<Utc>,
+    /// Absent when the account has been deleted, and in recordings
+    /// made before comment authors were kept.
+    pub author: Option<CommentAuthor>,
+}
+
+impl CommentInfo {
+    /// True when `login` wrote this comment. A bot may be named with
+    /// or without its `[bot]` suffix.
+    pub fn is_by(&self, login: &str) -> bool {
+        self.author.as_ref().is_some_and(|author| {
+            author.login.eq_ignore_ascii_case(login)
+                || (author.is_bot
+                    && login
+                        .strip_suffix("[bot]")
+                        .is_some_and(|name| author.login.eq_ignore_ascii_case(name)))
+        })
+    }
+
+    /// True when a bot or app account wrote this comment.
+    pub fn is_from_bot(&self) -> bool {
+        self.author.as_ref().is_some_and(|author| author.is_bot)
+    }
+}
+
+/// Account that wrote a comment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommentAuthor {
+    pub login: String,
+    pub is_bot: bool,
+}
+
+/// Which earlier comments count when deciding whether a comment has
+/// already been posted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommentScope {
+    /// Only comments by these users count, bots among them included;
+    /// empty counts everyone's.
+    pub authors: Vec<String>,
+    /// Leave out comments by bots not named in `authors`, which may
+    /// quote commands back in their help text.
+    pub ignore_bots: bool,
+}
+
+impl CommentScope {
+    pub fn counts(&self, comment: &CommentInfo) -> bool {
+        if self.authors.is_empty() {
+            !(self.ignore_bots && comment.is_from_bot())
+        } else {
+            self.authors.iter().any(|login| comment.is_by(login))
+        }
+    }
 }
 
 /// Lifecycle state of a pull request.
@@ -412,6 +505,155 @@ pub enum PrState {
     Merged,
 }
 
+/// Whether a pull request's head merges cleanly into its base.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Mergeable {
+    Mergeable,
+    Conflicting,
+    /// Not yet computed by the forge, or not reported by it. GitHub
+    /// works this out in the background after a push, so a fresh PR
+    /// often reads as unknown until the next run.
+    #[default]
+    Unknown,
+}
+
+/// Whether the forge would merge a pull request right now, and if not,
+/// the main reason.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MergeState {
+    /// Mergeable and passing the branch's requirements.
+    Clean,
+    /// The head branch is out of date with the base.
+    Behind,
+    /// Blocked by branch protection, e.g. a missing approval.
+    Blocked,
+    /// Conflicts with the base.
+    Dirty,
+    Draft,
+    /// Mergeable with passing requirements, but with pre-receive hooks.
+    HasHooks,
+    /// Mergeable, but with failing or pending non-required checks.
+    Unstable,
+    #[default]
+    Unknown,
+}
+
+/// A pull request's overall review verdict, as the forge works it out
+/// from the repository's review rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewDecision {
+    Approved,
+    ChangesRequested,
+    ReviewRequired,
+}
+
+impl ReviewDecision {
+    /// Parses the `--review-decision` spelling: `approved`,
+    /// `changes_requested`, or `review_required`, in any case and with
+    /// `-` for `_`.
+    pub fn parse(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_lowercase().replace('-', "_").as_str() {
+            "approved" => Ok(Self::Approved),
+            "changes_requested" => Ok(Self::ChangesRequested),
+            "review_required" => Ok(Self::ReviewRequired),
+            _ => anyhow::bail!(
+                "Invalid review decision '{s}' (expected approved, changes_requested, or review_required)"
+            ),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Approved => "approved",
+            Self::ChangesRequested => "changes_requested",
+            Self::ReviewRequired => "review_required",
+        }
+    }
+}
+
+/// What a reviewer's most recent review on a pull request said.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewState {
+    Approved,
+    ChangesRequested,
+    Commented,
+    Dismissed,
+    /// Started but not yet submitted.
+    Pending,
+}
+
+/// One reviewer's latest review.
+#[derive(Debug, Clone)]
+pub struct ReviewInfo {
+    pub reviewer: String,
+    pub state: ReviewState,
+}
+
+/// Whether a commit carries a signature the forge could verify.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureState {
+    Verified,
+    /// Signed, but the forge could not verify the signature.
+    Unverified,
+    Unsigned,
+}
+
+/// One commit on a pull request.
+#[derive(Debug, Clone)]
+pub struct CommitInfo {
+    pub sha: String,
+    /// First line of the message.
+    pub headline: String,
+    pub message: String,
+    /// Forge login of the commit's author, or the name recorded in the
+    /// commit when it maps to no account.
+    pub author: Option<String>,
+    /// Likewise for whoever committed it.
+    pub committer: Option<String>,
+    pub signature: SignatureState,
+}
+
+impl CommitInfo {
+    /// True when the message carries a `Signed-off-by:` trailer, as the
+    /// DCO asks for.
+    pub fn is_signed_off(&self) -> bool {
+        const TRAILER: &str = "signed-off-by:";
+        self.message.lines().any(|line| {
+            line.trim_start()
+                .get(..TRAILER.len())
+                .is_some_and(|prefix| prefix.eq_ignore_ascii_case(TRAILER))
+        })
+    }
+}
+
+/// Issue a pull request closes when it merges.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkedIssue {
+    pub number: u64,
+    pub title: String,
+    pub url: String,
+}
+
+/// Tracker keys such as `OCPBUGS-12345` mentioned in `texts`, in order
+/// of first mention and without repeats.
+///
+/// A key is an upper-case project name of at least two characters, a
+/// dash, and a number, as Jira issues it. Anything else shaped that
+/// way, such as `UTF-8` or `SHA-256`, is caught too, so a key only
+/// counts as an issue reference once `--issue-key` vouches for it.
+pub(crate) fn parse_issue_keys(texts: &[&str]) -> Vec<String> {
+    static KEY: LazyLock<regex::Regex> =
+        LazyLock::new(|| regex::Regex::new(r"\b[A-Z][A-Z0-9_]+-[0-9]+\b").unwrap());
+
+    let mut keys: Vec<String> = Vec::new();
+    for found in texts.iter().flat_map(|text| KEY.find_iter(text)) {
+        if !keys.iter().any(|key| key == found.as_str()) {
+            keys.push(found.as_str().to_string());
+        }
+    }
+    keys
+}
+
 /// Complete information about a pull request.
 ///
 /// Contains core PR metadata, CI check results, labels, and recent
@@ -429,14 +671,64 @@ pub struct PullRequest {
     pub url: String,
     pub labels: Vec<String>,
     pub created_at: DateTime<Utc>,
+    /// When anything on the PR last changed: a push, comment, label, or
+    /// edit.
+    pub updated_at: DateTime<Utc>,
+    /// When the head branch was last pushed, or the forge's nearest
+    /// estimate of it; None when the forge does not say.
+    pub last_pushed_at: Option<DateTime<Utc>>,
     pub base_branch: String,
     pub commit_count: u64,
+    /// Lines added across the whole diff. The size fields are `None`
+    /// when the forge did not report them.
+    pub additions: Option<u64>,
+    /// Lines removed across the whole diff.
+    pub deletions: Option<u64>,
+    pub changed_files: Option<u64>,
+    /// The paths the PR changes. None unless the fetch asked for them
+    /// ([`crate::search::FetchOptions::changed_paths`]).
+    pub changed_paths: Option<Vec<String>>,
+    /// The PR's commits, oldest first. None unless the fetch asked for
+    /// them ([`crate::search::FetchOptions::commits`]).
+    pub commits: Option<Vec<CommitInfo>>,
+    /// Issues the PR closes when it merges. None when the forge does
+    /// not say, as GitLab does not.
+    pub linked_issues: Option<Vec<LinkedIssue>>,
+    /// Tracker keys such as `OCPBUGS-12345` named in the title or
+    /// description, in order of first mention.
+    pub issue_keys: Vec<String>,
     pub is_draft: bool,
     pub state: PrState,
 
     // Associated data.
     pub checks: Vec<CheckInfo>,
+    /// Comments in the order they were posted, oldest first.
     pub recent_comments: Vec<CommentInfo>,
+    /// True when `recent_comments` holds every comment on the PR, or
+    /// reaches back past the comment history the fetch asked for
+    /// ([`crate::search::FetchOptions`]). False means older comments
+    /// inside that window may be missing.
+    pub comment_history_complete: bool,
+    /// None when the forge gives no verdict, e.g. the repository does
+    /// not require reviews.
+    pub review_decision: Option<ReviewDecision>,
+    /// The latest review from each reviewer, in no particular order.
+    pub reviews: Vec<ReviewInfo>,
+    /// Users, and teams as `org/team`, asked to review and yet to do so.
+    pub requested_reviewers: Vec<String>,
+    pub assignees: Vec<String>,
+    pub mergeable: Mergeable,
+    pub merge_state: MergeState,
+    /// The branch the changes come from.
+    pub head_branch: String,
+    /// Owner of the repository `head_branch` is in; None when that
+    /// repository has been deleted.
+    pub head_repo_owner: Option<String>,
+    /// The commit at the tip of `head_branch`.
+    pub head_sha: String,
+    /// True when `head_branch` is in a different repository from the
+    /// base, i.e. the PR comes from a fork.
+    pub is_cross_repository: bool,
 }
 
 impl PullRequest {
@@ -451,6 +743,37 @@ impl PullRequest {
         self.checks.iter().any(|check| check.is_failed())
     }
 
+    /// True when a check the base branch requires has failed; optional
+    /// failures alone do not block a merge.
+    pub fn has_failing_required_ci(&self) -> bool {
+        self.checks
+            .iter()
+            .any(|check| check.required && check.is_failed())
+    }
+
+    /// True when a check that started before `cutoff` is still queued
+    /// or running.
+    pub fn has_check_pending_since(&self, cutoff: DateTime<Utc>) -> bool {
+        self.checks.iter().any(|check| {
+            check.is_pending() && check.started_at.is_some_and(|started| started < cutoff)
+        })
+    }
+
+    /// Whether the PR references an issue, by closing it or by naming
+    /// a key that `key` matches. Without `key` only closed issues count.
+    /// None when it names no such key and the forge does not report
+    /// which issues it closes.
+    pub fn references_issue(&self, key: Option<&regex::Regex>) -> Option<bool> {
+        if key.is_some_and(|pattern| {
+            self.issue_keys
+                .iter()
+                .any(|issue_key| pattern.is_match(issue_key))
+        }) {
+            return Some(true);
+        }
+        self.linked_issues.as_ref().map(|issues| !issues.is_empty())
+    }
+
     pub fn has_failing_check(&self, name: &str) -> bool {
         self.checks
             .iter()
@@ -464,6 +787,46 @@ impl PullRequest {
     pub fn matches_base_branch(&self, branch: &str) -> bool {
         self.base_branch == branch
     }
+
+    /// True when the forge says the PR conflicts with its base.
+    pub fn is_conflicting(&self) -> bool {
+        self.mergeable == Mergeable::Conflicting || self.merge_state == MergeState::Dirty
+    }
+
+    /// True when `reviewer` has submitted a review, whatever it said.
+    /// Usernames are compared ignoring case, as the forges do.
+    pub fn reviewed_by(&self, reviewer: &str) -> bool {
+        self.reviews.iter().any(|review| {
+            review.reviewer.eq_ignore_ascii_case(reviewer) && review.state != ReviewState::Pending
+        })
+    }
+
+    /// Lines added plus lines removed, when both are known.
+    pub fn changed_lines(&self) -> Option<u64> {
+        Some(self.additions? + self.deletions?)
+    }
+
+    /// True when `login` wrote any of the fetched comments.
+    pub fn commented_by(&self, login: &str) -> bool {
+        self.recent_comments
+            .iter()
+            .any(|comment| comment.is_by(login))
+    }
+
+    /// When the most recent comment was posted. Every fetch includes
+    /// the newest comments, so this holds even when older ones are
+    /// missing.
+    pub fn last_commented_at(&self) -> Option<DateTime<Utc>> {
+        self.recent_comments
+            .last()
+            .map(|comment| comment.created_at)
+    }
+}
+
+/// True when `user` is among `users`. Usernames are compared ignoring
+/// case, as the forges' own searches do.
+fn has_user(users: &[String], user: &str) -> bool {
+    users.iter().any(|name| name.eq_ignore_ascii_case(user))
 }
 
 /// Forge-neutral criterion used both for server-side narrowing and local checks.
@@ -472,6 +835,18 @@ pub enum SearchCriterion {
     MissingLabel(String),
     PresentLabel(String),
     BaseBranch(String),
+    /// Assigned to this user, among others.
+    Assignee(String),
+    /// Assigned to no one.
+    Unassigned,
+    /// This user has been asked directly to review, not through a team.
+    ReviewRequested(String),
+    /// Created strictly before this time.
+    CreatedBefore(DateTime<Utc>),
+    /// Created strictly after this time.
+    CreatedAfter(DateTime<Utc>),
+    /// No activity of any kind since this time.
+    UpdatedBefore(DateTime<Utc>),
 }
 
 impl SearchCriterion {
@@ -480,6 +855,12 @@ impl SearchCriterion {
             Self::MissingLabel(label) => !pr.has_label(label),
             Self::PresentLabel(label) => pr.has_label(label),
             Self::BaseBranch(branch) => pr.matches_base_branch(branch),
+            Self::Assignee(user) => has_user(&pr.assignees, user),
+            Self::Unassigned => pr.assignees.is_empty(),
+            Self::ReviewRequested(user) => has_user(&pr.requested_reviewers, user),
+            Self::CreatedBefore(cutoff) => pr.created_at < *cutoff,
+            Self::CreatedAfter(cutoff) => pr.created_at > *cutoff,
+            Self::UpdatedBefore(cutoff) => pr.updated_at < *cutoff,
         }
     }
 }
@@ -490,6 +871,24 @@ impl SearchCriterion {
 /// checks that cannot be expressed in GitHub's search syntax.
 pub trait PostFilter: std::fmt::Debug + Send + Sync {
     fn matches(&self, pr: &PullRequest) -> bool;
+
+    /// Whether `matches` reads [`PullRequest::changed_paths`], which
+    /// costs extra queries and so is only fetched when asked for.
+    fn needs_changed_paths(&self) -> bool {
+        false
+    }
+
+    /// Whether `matches` reads [`PullRequest::commits`], which is
+    /// likewise only fetched when asked for.
+    fn needs_commits(&self) -> bool {
+        false
+    }
+
+    /// Whether `matches` relies on [`CheckInfo::required`], which a
+    /// forge can only report as far as it knows the branch's rules.
+    fn needs_required_checks(&self) -> bool {
+        false
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -576,15 +975,60 @@ pub struct Task {
 /// Abstraction for version control forges (GitHub, GitLab, etc.).
 ///
 /// Provides a common interface for fetching pull requests from different
-/// platforms. Currently only GitHub is implemented.
+/// platforms. GitHub ([`crate::GitHub`]) and GitLab ([`crate::GitLab`])
+/// are implemented.
 #[async_trait]
 pub trait Forge {
-    async fn fetch_pull_requests(&self, plan: &FetchPlan) -> Result<Vec<PullRequest>>;
+    async fn fetch_pull_requests(
+        &self,
+        plan: &FetchPlan,
+        options: &FetchOptions,
+    ) -> Result<FetchOutcome>;
+}
+
+/// Pull requests a forge fetched for a plan, and whether that is all of
+/// them.
+///
+/// A forge that gives up part way (a failed page, a search it could not
+/// page to the end of) returns what it has and says why in `incomplete`,
+/// rather than passing a short list off as the whole answer.
+#[derive(Debug, Clone, Default)]
+pub struct FetchOutcome {
+    pub pull_requests: Vec<PullRequest>,
+    /// One entry per reason the list may be missing pull requests.
+    pub incomplete: Vec<String>,
+}
+
+impl FetchOutcome {
+    pub fn complete(pull_requests: Vec<PullRequest>) -> Self {
+        Self {
+            pull_requests,
+            incomplete: Vec::new(),
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.incomplete.is_empty()
+    }
+
+    /// Records that the pull requests gathered so far are not all there
+    /// are.
+    pub fn mark_incomplete(&mut self, reason: impl Into<String>) {
+        self.incomplete.push(reason.into());
+    }
+
+    /// Appends another outcome's pull requests and reasons.
+    pub fn merge(&mut self, other: FetchOutcome) {
+        self.pull_requests.extend(other.pull_requests);
+        self.incomplete.extend(other.incomplete);
+    }
 }
 
 #[derive(Debug)]
 pub struct FetchCriteria {
     pub repos: Vec<Repo>,
+    /// Search every matching repository of an owner instead of `repos`.
+    pub org: Option<OrgSweep>,
     pub prs: Vec<PrIdentifier>,
     pub query: Option<String>,
     pub limit: usize,
@@ -597,13 +1041,37 @@ pub struct SelectionPolicy {
     pub post_filters: Vec<Box<dyn PostFilter + Send + Sync>>,
 }
 
+impl SelectionPolicy {
+    pub fn needs_changed_paths(&self) -> bool {
+        self.post_filters
+            .iter()
+            .any(|filter| filter.needs_changed_paths())
+    }
+
+    pub fn needs_commits(&self) -> bool {
+        self.post_filters
+            .iter()
+            .any(|filter| filter.needs_commits())
+    }
+
+    pub fn needs_required_checks(&self) -> bool {
+        self.post_filters
+            .iter()
+            .any(|filter| filter.needs_required_checks())
+    }
+}
+
 #[derive(Debug)]
 pub struct ActionPolicy {
     pub actions: Vec<PrAction>,
     pub throttle: Option<Duration>,
     pub history_max_age: Duration,
     pub history_max_comments: usize,
+    /// Whose comments throttling and history checks look at.
+    pub comment_scope: CommentScope,
     pub commit_limit: u64,
+    /// Refuse to emit any commands when the fetch was incomplete.
+    pub strict: bool,
 }
 
 impl ActionPolicy {
@@ -626,20 +1094,43 @@ pub struct QuerySpec {
     pub action_policy: ActionPolicy,
 }
 
+/// Which forge instances to talk to.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ForgeSettings {
+    /// GitHub host for `--repo`, `--query`, and bare PR numbers, when
+    /// not github.com (i.e. a GitHub Enterprise Server instance).
+    pub github_host: Option<String>,
+    /// Save every GitHub GraphQL exchange to this directory.
+    pub record_dir: Option<PathBuf>,
+    /// Serve GitHub fetches from a recording in this directory instead
+    /// of the network.
+    pub replay_dir: Option<PathBuf>,
+    /// Repositories to fetch at once; `None` uses the forge default.
+    pub fetch_concurrency: Option<usize>,
+    /// Always ask the forge instead of reusing recent responses.
+    pub no_cache: bool,
+}
+
 #[derive(Debug)]
 pub struct AppRequest {
     pub query: QuerySpec,
     pub display: DisplaySettings,
+    pub forge: ForgeSettings,
 }
 
 /// Result of executing a pull request query.
 ///
 /// Contains the filtered pull requests and a list of executable actions
-/// based on the query specification and PR states.
+/// based on the query specification and PR states, plus any explicitly
+/// requested PRs that could not be fetched. `incomplete` is non-empty
+/// when the forge could not fetch every PR the query matched, giving
+/// the reasons.
 #[derive(Debug)]
 pub struct QueryResult {
     pub filtered_prs: Vec<PullRequest>,
     pub executable_actions: Vec<Task>,
+    pub missing_prs: Vec<PrIdentifier>,
+    pub incomplete: Vec<String>,
 }
 
 #[cfg(test)]
@@ -658,12 +1149,32 @@ mod tests {
             url: "https://github.com/owner/repo/pull/123".to_string(),
             labels: vec![],
             created_at: Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap(),
+            updated_at: Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap(),
+            last_pushed_at: None,
             base_branch: "main".to_string(),
             commit_count: 1,
+            additions: None,
+            deletions: None,
+            changed_files: None,
+            changed_paths: None,
+            commits: None,
+            linked_issues: None,
+            issue_keys: vec![],
             is_draft: false,
             state: PrState::Open,
             checks: vec![],
             recent_comments: vec![],
+            comment_history_complete: true,
+            review_decision: None,
+            reviews: vec![],
+            requested_reviewers: vec![],
+            assignees: vec![],
+            mergeable: Mergeable::Unknown,
+            merge_state: MergeState::Unknown,
+            head_branch: "feature".to_string(),
+            head_repo_owner: None,
+            head_sha: String::new(),
+            is_cross_repository: false,
         }
     }
 
@@ -753,4 +1264,122 @@ mod tests {
             );
         }
     }
+
+    fn comment_by(login: &str, is_bot: bool) -> CommentInfo {
+        CommentInfo {
+            body: "/retest".to_string(),
+            created_at: Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap(),
+            author: Some(CommentAuthor {
+                login: login.to_string(),
+                is_bot,
+            }),
+        }
+    }
+
+    #[test]
+    fn comment_scope_counts_everyone_unless_narrowed() {
+        let mine = comment_by("alice", false);
+        let theirs = comment_by("bob", false);
+        let bot = comment_by("openshift-ci", true);
+        let ghost = CommentInfo {
+            author: None,
+            ..comment_by("", false)
+        };
+
+        let everyone = CommentScope::default();
+        assert!(everyone.counts(&mine));
+        assert!(everyone.counts(&ghost));
+        assert!(everyone.counts(&bot));
+
+        let without_bots = CommentScope {
+            ignore_bots: true,
+            ..CommentScope::default()
+        };
+        assert!(without_bots.counts(&mine));
+        assert!(
+            !without_bots.counts(&bot),
+            "bots echo commands in help text"
+        );
+
+        let only_mine = CommentScope {
+            authors: vec!["alice".to_string()],
+            ignore_bots: true,
+        };
+        assert!(only_mine.counts(&mine));
+        assert!(!only_mine.counts(&theirs));
+        assert!(!only_mine.counts(&ghost));
+        assert!(!only_mine.counts(&bot));
+
+        let named_bot = CommentScope {
+            authors: vec!["openshift-ci".to_string()],
+            ignore_bots: true,
+        };
+        assert!(
+            named_bot.counts(&bot),
+            "a named bot counts even when bots are ignored"
+        );
+        assert!(!named_bot.counts(&mine));
+    }
+
+    #[test]
+    fn comment_is_by_names_bots_with_or_without_suffix() {
+        let bot = comment_by("openshift-ci", true);
+        assert!(bot.is_by("openshift-ci"));
+        assert!(bot.is_by("openshift-ci[bot]"));
+        assert!(!comment_by("alice", false).is_by("alice[bot]"));
+    }
+
+    #[test]
+    fn comment_is_by_ignores_login_case() {
+        assert!(comment_by("alice", false).is_by("Alice"));
+        assert!(comment_by("Alice", false).is_by("ALICE"));
+        let bot = comment_by("openshift-ci", true);
+        assert!(bot.is_by("OpenShift-CI"));
+        assert!(bot.is_by("OpenShift-CI[bot]"));
+        assert!(!comment_by("alice", false).is_by("Alice[bot]"));
+    }
+
+    #[test]
+    fn check_duration_runs_to_completion_or_now() {
+        let started = Utc.with_ymd_and_hms(2026, 6, 1, 9, 0, 0).unwrap();
+        let now = started + TimeDelta::hours(3);
+        let mut check = CheckInfo {
+            name: CheckName::new("e2e").unwrap(),
+            conclusion: None,
+            run_status: Some(CheckRunStatus::InProgress),
+            status_state: None,
+            url: None,
+            required: false,
+            started_at: Some(started),
+            completed_at: None,
+        };
+        assert_eq!(check.duration(now), Some(TimeDelta::hours(3)));
+
+        check.run_status = Some(CheckRunStatus::Completed);
+        check.conclusion = Some(CheckConclusion::Success);
+        assert_eq!(check.duration(now), None);
+
+        check.completed_at = Some(started + TimeDelta::minutes(42));
+        assert_eq!(check.duration(now), Some(TimeDelta::minutes(42)));
+    }
+
+    #[test]
+    fn key_shaped_words_are_not_issue_references() {
+        let mut pr = pr_with_authors("alice", "alice");
+        pr.title = "Bump SHA-256 helper to UTF-8".to_string();
+        pr.issue_keys = parse_issue_keys(&[&pr.title, "Also drops RHEL-9, X11-1 and CVE-2024."]);
+        pr.linked_issues = Some(vec![]);
+        assert_eq!(
+            pr.issue_keys,
+            vec!["SHA-256", "UTF-8", "RHEL-9", "X11-1", "CVE-2024"]
+        );
+
+        assert_eq!(pr.references_issue(None), Some(false));
+        let ocpbugs = regex::Regex::new("^OCPBUGS-").unwrap();
+        assert_eq!(pr.references_issue(Some(&ocpbugs)), Some(false));
+
+        pr.issue_keys.push("OCPBUGS-12345".to_string());
+        assert_eq!(pr.references_issue(None), Some(false));
+        assert_eq!(pr.references_issue(Some(&ocpbugs)), Some(true));
+    }
 }
@@ -2,9 +2,10 @@ use anyhow::Result;
 use async_trait::async_trait;
 use autoprat::{
     AppRequest, CheckConclusion, CheckInfo, CheckName, CheckState, CheckUrl, CommentAction,
-    CommentInfo, DisplayMode, Forge, PrAction, PrState, PullRequest, QueryResult, Repo,
-    SearchCriterion, fetch_pull_requests, fetch_pull_requests_at, parse_args,
-    search::{FetchPlan, RepoSearch},
+    CommentAuthor, CommentInfo, CommitInfo, DisplayMode, FetchOutcome, Forge, LinkedIssue,
+    MergeState, Mergeable, PrAction, PrState, PullRequest, QueryResult, Repo, SearchCriterion,
+    fetch_pull_requests, fetch_pull_requests_at, parse_args,
+    search::{FetchOptions, FetchPlan, RepoSearch},
 };
 use chrono::{TimeZone, Utc};
 
@@ -23,12 +24,35 @@ impl FakeForge {
     pub fn new(prs: Vec<PullRequest>) -> Self {
         Self { prs }
     }
+
+    fn search(&self, searches: &[RepoSearch]) -> Vec<PullRequest> {
+        searches
+            .iter()
+            .flat_map(|search| {
+                self.prs
+                    .iter()
+                    .filter(move |pr| {
+                        pr.repo == search.repo
+                            && search
+                                .criteria
+                                .iter()
+                                .all(|criterion| criterion.matches(pr))
+                    })
+                    .take(search.limit)
+                    .cloned()
+            })
+            .collect()
+    }
 }
 
 #[async_trait]
 impl Forge for FakeForge {
-    async fn fetch_pull_requests(&self, plan: &FetchPlan) -> Result<Vec<PullRequest>> {
-        Ok(match plan {
+    async fn fetch_pull_requests(
+        &self,
+        plan: &FetchPlan,
+        _options: &FetchOptions,
+    ) -> Result<FetchOutcome> {
+        Ok(FetchOutcome::complete(match plan {
             FetchPlan::SpecificPullRequests(identifiers) => self
                 .prs
                 .iter()
@@ -40,23 +64,22 @@ impl Forge for FakeForge {
                 .cloned()
                 .collect(),
             FetchPlan::UserSearch { limit, .. } => self.prs.iter().take(*limit).cloned().collect(),
-            FetchPlan::RepositorySearches(searches) => searches
-                .iter()
-                .flat_map(|search| {
-                    self.prs
-                        .iter()
-                        .filter(move |pr| {
-                            pr.repo == search.repo
-                                && search
-                                    .criteria
-                                    .iter()
-                                    .all(|criterion| criterion.matches(pr))
-                        })
-                        .take(search.limit)
-                        .cloned()
-                })
-                .collect(),
-        })
+            FetchPlan::RepositorySearches(searches) => self.search(searches),
+            FetchPlan::OrganisationSweep {
+                sweep,
+                criteria,
+                limit,
+            } => {
+                // The owner's repositories are the ones its PRs are in.
+                let mut repos: Vec<Repo> = Vec::new();
+                for pr in &self.prs {
+                    if pr.repo.owner() == sweep.org && !repos.contains(&pr.repo) {
+                        repos.push(pr.repo.clone());
+                    }
+                }
+                self.search(&sweep.searches(repos, criteria, *limit))
+            }
+        }))
     }
 }
 
@@ -112,12 +135,32 @@ fn behavioural_pr(number: u64, title: &str, recent_comments: Vec<CommentInfo>) -
         url: format!("https://github.com/owner/repo/pull/{number}"),
         labels: vec![],
         created_at: Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap(),
+        updated_at: Utc.with_ymd_and_hms(2026, 5, 29, 12, 0, 0).unwrap(),
+        last_pushed_at: None,
         base_branch: "main".to_string(),
         commit_count: 1,
+        additions: None,
+        deletions: None,
+        changed_files: None,
+        changed_paths: None,
+        commits: None,
+        linked_issues: None,
+        issue_keys: vec![],
         is_draft: false,
         state: PrState::Open,
         checks: vec![],
         recent_comments,
+        comment_history_complete: true,
+        review_decision: None,
+        reviews: vec![],
+        requested_reviewers: vec![],
+        assignees: vec![],
+        mergeable: Mergeable::Unknown,
+        merge_state: MergeState::Unknown,
+        head_branch: "feature".to_string(),
+        head_repo_owner: None,
+        head_sha: String::new(),
+        is_cross_repository: false,
     }
 }
 
@@ -185,7 +228,11 @@ async fn fake_forge_honours_specific_pull_request_fetch_plans() {
     let forge = FakeForge::new(vec![other, requested]);
     let plan = FetchPlan::SpecificPullRequests(vec![autoprat::PrIdentifier::new(test_repo(), 123)]);
 
-    let fetched = forge.fetch_pull_requests(&plan).await.unwrap();
+    let fetched = forge
+        .fetch_pull_requests(&plan, &FetchOptions::default())
+        .await
+        .unwrap()
+        .pull_requests;
 
     assert_eq!(
         fetched.iter().map(|pr| pr.number).collect::<Vec<_>>(),
@@ -207,7 +254,11 @@ async fn fake_forge_honours_repository_search_criteria() {
         limit: 10,
     }]);
 
-    let fetched = forge.fetch_pull_requests(&plan).await.unwrap();
+    let fetched = forge
+        .fetch_pull_requests(&plan, &FetchOptions::default())
+        .await
+        .unwrap()
+        .pull_requests;
 
     assert_eq!(
         fetched.iter().map(|pr| pr.number).collect::<Vec<_>>(),
@@ -215,6 +266,48 @@ async fn fake_forge_honours_repository_search_criteria() {
     );
 }
 
+#[tokio::test]
+async fn org_sweep_acts_on_matching_repositories_only() {
+    let in_repo = |owner: &str, name: &str, number: u64| {
+        let mut pr = behavioural_pr(number, name, vec![]);
+        pr.repo = Repo::new(owner, name).unwrap();
+        pr
+    };
+    let forge = FakeForge::new(vec![
+        in_repo("openshift", "dns-operator", 1),
+        in_repo("openshift", "legacy-operator", 2),
+        in_repo("openshift", "router", 3),
+        in_repo("other", "dns-operator", 4),
+        in_repo("openshift", "ingress-operator", 5),
+    ]);
+
+    let result = run_autoprat_test(
+        vec![
+            "autoprat",
+            "--org",
+            "openshift",
+            "--repo-glob",
+            "*-operator",
+            "--exclude-repo",
+            "legacy-*",
+            "--retest",
+        ],
+        &forge,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        result
+            .filtered_prs
+            .iter()
+            .map(|pr| pr.number)
+            .collect::<Vec<_>>(),
+        vec![1, 5]
+    );
+    assert_eq!(result.executable_actions.len(), 2);
+}
+
 /// Helper to create mock GitHub data for testing
 /// This creates a diverse set of PRs to test various filtering scenarios
 fn create_mock_github_data() -> Vec<PullRequest> {
@@ -229,8 +322,17 @@ fn create_mock_github_data() -> Vec<PullRequest> {
             url: "https://github.com/owner/repo/pull/123".to_string(),
             labels: vec!["dependencies".to_string()],
             created_at: Utc::now(),
+            updated_at: Utc::now(),
+            last_pushed_at: None,
             base_branch: "main".to_string(),
             commit_count: 1,
+            additions: None,
+            deletions: None,
+            changed_files: None,
+            changed_paths: None,
+            commits: None,
+            linked_issues: None,
+            issue_keys: vec![],
             is_draft: false,
             state: PrState::Open,
             checks: vec![CheckInfo {
@@ -239,8 +341,22 @@ fn create_mock_github_data() -> Vec<PullRequest> {
                 run_status: None,
                 status_state: None,
                 url: Some(CheckUrl::new("https://github.com/checks/1").unwrap()),
+                required: false,
+                started_at: None,
+                completed_at: None,
             }],
             recent_comments: vec![],
+            comment_history_complete: true,
+            review_decision: None,
+            reviews: vec![],
+            requested_reviewers: vec![],
+            assignees: vec![],
+            mergeable: Mergeable::Unknown,
+            merge_state: MergeState::Unknown,
+            head_branch: "feature".to_string(),
+            head_repo_owner: None,
+            head_sha: String::new(),
+            is_cross_repository: false,
         },
         // PR 124: Alice's bug fix PR - already approved
         PullRequest {
@@ -252,8 +368,17 @@ fn create_mock_github_data() -> Vec<PullRequest> {
             url: "https://github.com/owner/repo/pull/124".to_string(),
             labels: vec!["bug".to_string(), "approved".to_string()],
             created_at: Utc::now(),
+            updated_at: Utc::now(),
+            last_pushed_at: None,
             base_branch: "main".to_string(),
             commit_count: 1,
+            additions: None,
+            deletions: None,
+            changed_files: None,
+            changed_paths: None,
+            commits: None,
+            linked_issues: None,
+            issue_keys: vec![],
             is_draft: false,
             state: PrState::Open,
             checks: vec![
@@ -263,6 +388,9 @@ fn create_mock_github_data() -> Vec<PullRequest> {
                     run_status: None,
                     status_state: None,
                     url: Some(CheckUrl::new("https://github.com/checks/2").unwrap()),
+                    required: false,
+                    started_at: None,
+                    completed_at: None,
                 },
                 CheckInfo {
                     name: CheckName::new("ci/test").unwrap(),
@@ -270,9 +398,23 @@ fn create_mock_github_data() -> Vec<PullRequest> {
                     run_status: None,
                     status_state: None,
                     url: Some(CheckUrl::new("https://github.com/checks/3").unwrap()),
+                    required: false,
+                    started_at: None,
+                    completed_at: None,
                 },
             ],
             recent_comments: vec![],
+            comment_history_complete: true,
+            review_decision: None,
+            reviews: vec![],
+            requested_reviewers: vec![],
+            assignees: vec![],
+            mergeable: Mergeable::Unknown,
+            merge_state: MergeState::Unknown,
+            head_branch: "feature".to_string(),
+            head_repo_owner: None,
+            head_sha: String::new(),
+            is_cross_repository: false,
         },
         // PR 125: Bob's feature PR - needs approval
         PullRequest {
@@ -284,8 +426,17 @@ fn create_mock_github_data() -> Vec<PullRequest> {
             url: "https://github.com/owner/repo/pull/125".to_string(),
             labels: vec!["feature".to_string(), "enhancement".to_string()],
             created_at: Utc::now(),
+            updated_at: Utc::now(),
+            last_pushed_at: None,
             base_branch: "main".to_string(),
             commit_count: 1,
+            additions: None,
+            deletions: None,
+            changed_files: None,
+            changed_paths: None,
+            commits: None,
+            linked_issues: None,
+            issue_keys: vec![],
             is_draft: false,
             state: PrState::Open,
             checks: vec![
@@ -295,6 +446,9 @@ fn create_mock_github_data() -> Vec<PullRequest> {
                     run_status: None,
                     status_state: None,
                     url: Some(CheckUrl::new("https://github.com/checks/4").unwrap()),
+                    required: false,
+                    started_at: None,
+                    completed_at: None,
                 },
                 CheckInfo {
                     name: CheckName::new("ci/test").unwrap(),
@@ -302,9 +456,23 @@ fn create_mock_github_data() -> Vec<PullRequest> {
                     run_status: None,
                     status_state: None,
                     url: Some(CheckUrl::new("https://github.com/checks/5").unwrap()),
+                    required: false,
+                    started_at: None,
+                    completed_at: None,
                 },
             ],
             recent_comments: vec![],
+            comment_history_complete: true,
+            review_decision: None,
+            reviews: vec![],
+            requested_reviewers: vec![],
+            assignees: vec![],
+            mergeable: Mergeable::Unknown,
+            merge_state: MergeState::Unknown,
+            head_branch: "feature".to_string(),
+            head_repo_owner: None,
+            head_sha: String::new(),
+            is_cross_repository: false,
         },
         // PR 126: Charlie's documentation PR - no labels
         PullRequest {
@@ -316,8 +484,17 @@ fn create_mock_github_data() -> Vec<PullRequest> {
             url: "https://github.com/owner/repo/pull/126".to_string(),
             labels: vec![],
             created_at: Utc::now(),
+            updated_at: Utc::now(),
+            last_pushed_at: None,
             base_branch: "main".to_string(),
             commit_count: 1,
+            additions: None,
+            deletions: None,
+            changed_files: None,
+            changed_paths: None,
+            commits: None,
+            linked_issues: None,
+            issue_keys: vec![],
             is_draft: false,
             state: PrState::Open,
             checks: vec![CheckInfo {
@@ -326,8 +503,22 @@ fn create_mock_github_data() -> Vec<PullRequest> {
                 run_status: None,
                 status_state: None,
                 url: Some(CheckUrl::new("https://github.com/checks/6").unwrap()),
+                required: false,
+                started_at: None,
+                completed_at: None,
             }],
             recent_comments: vec![],
+            comment_history_complete: true,
+            review_decision: None,
+            reviews: vec![],
+            requested_reviewers: vec![],
+            assignees: vec![],
+            mergeable: Mergeable::Unknown,
+            merge_state: MergeState::Unknown,
+            head_branch: "feature".to_string(),
+            head_repo_owner: None,
+            head_sha: String::new(),
+            is_cross_repository: false,
         },
         // PR 127: Alice's feature PR - needs approval
         PullRequest {
@@ -339,8 +530,17 @@ fn create_mock_github_data() -> Vec<PullRequest> {
             url: "https://github.com/owner/repo/pull/127".to_string(),
             labels: vec!["feature".to_string()],
             created_at: Utc::now(),
+            updated_at: Utc::now(),
+            last_pushed_at: None,
             base_branch: "main".to_string(),
             commit_count: 1,
+            additions: None,
+            deletions: None,
+            changed_files: None,
+            changed_paths: None,
+            commits: None,
+            linked_issues: None,
+            issue_keys: vec![],
             is_draft: false,
             state: PrState::Open,
             checks: vec![
@@ -350,6 +550,9 @@ fn create_mock_github_data() -> Vec<PullRequest> {
                     run_status: None,
                     status_state: None,
                     url: Some(CheckUrl::new("https://github.com/checks/7").unwrap()),
+                    required: false,
+                    started_at: None,
+                    completed_at: None,
                 },
                 CheckInfo {
                     name: CheckName::new("ci/test").unwrap(),
@@ -357,6 +560,9 @@ fn create_mock_github_data() -> Vec<PullRequest> {
                     run_status: None,
                     status_state: None,
                     url: Some(CheckUrl::new("https://github.com/checks/8").unwrap()),
+                    required: false,
+                    started_at: None,
+                    completed_at: None,
                 },
                 CheckInfo {
                     name: CheckName::new("ci/lint").unwrap(),
@@ -364,9 +570,23 @@ fn create_mock_github_data() -> Vec<PullRequest> {
                     run_status: None,
                     status_state: None,
                     url: Some(CheckUrl::new("https://github.com/checks/9").unwrap()),
+                    required: false,
+                    started_at: None,
+                    completed_at: None,
                 },
             ],
             recent_comments: vec![],
+            comment_history_complete: true,
+            review_decision: None,
+            reviews: vec![],
+            requested_reviewers: vec![],
+            assignees: vec![],
+            mergeable: Mergeable::Unknown,
+            merge_state: MergeState::Unknown,
+            head_branch: "feature".to_string(),
+            head_repo_owner: None,
+            head_sha: String::new(),
+            is_cross_repository: false,
         },
         // PR 128: Renovate bot PR
         PullRequest {
@@ -378,8 +598,17 @@ fn create_mock_github_data() -> Vec<PullRequest> {
             url: "https://github.com/owner/repo/pull/128".to_string(),
             labels: vec!["dependencies".to_string()],
             created_at: Utc::now(),
+            updated_at: Utc::now(),
+            last_pushed_at: None,
             base_branch: "main".to_string(),
             commit_count: 1,
+            additions: None,
+            deletions: None,
+            changed_files: None,
+            changed_paths: None,
+            commits: None,
+            linked_issues: None,
+            issue_keys: vec![],
             is_draft: false,
             state: PrState::Open,
             checks: vec![CheckInfo {
@@ -388,8 +617,22 @@ fn create_mock_github_data() -> Vec<PullRequest> {
                 run_status: None,
                 status_state: None,
                 url: Some(CheckUrl::new("https://github.com/checks/10").unwrap()),
+                required: false,
+                started_at: None,
+                completed_at: None,
             }],
             recent_comments: vec![],
+            comment_history_complete: true,
+            review_decision: None,
+            reviews: vec![],
+            requested_reviewers: vec![],
+            assignees: vec![],
+            mergeable: Mergeable::Unknown,
+            merge_state: MergeState::Unknown,
+            head_branch: "feature".to_string(),
+            head_repo_owner: None,
+            head_sha: String::new(),
+            is_cross_repository: false,
         },
         // PR 129: Bob's bug fix - approved
         PullRequest {
@@ -405,8 +648,17 @@ fn create_mock_github_data() -> Vec<PullRequest> {
                 "approved".to_string(),
             ],
             created_at: Utc::now(),
+            updated_at: Utc::now(),
+            last_pushed_at: None,
             base_branch: "main".to_string(),
             commit_count: 1,
+            additions: None,
+            deletions: None,
+            changed_files: None,
+            changed_paths: None,
+            commits: None,
+            linked_issues: None,
+            issue_keys: vec![],
             is_draft: false,
             state: PrState::Open,
             checks: vec![
@@ -416,6 +668,9 @@ fn create_mock_github_data() -> Vec<PullRequest> {
                     run_status: None,
                     status_state: None,
                     url: Some(CheckUrl::new("https://github.com/checks/11").unwrap()),
+                    required: false,
+                    started_at: None,
+                    completed_at: None,
                 },
                 CheckInfo {
                     name: CheckName::new("ci/test").unwrap(),
@@ -423,9 +678,23 @@ fn create_mock_github_data() -> Vec<PullRequest> {
                     run_status: None,
                     status_state: None,
                     url: Some(CheckUrl::new("https://github.com/checks/12").unwrap()),
+                    required: false,
+                    started_at: None,
+                    completed_at: None,
                 },
             ],
             recent_comments: vec![],
+            comment_history_complete: true,
+            review_decision: None,
+            reviews: vec![],
+            requested_reviewers: vec![],
+            assignees: vec![],
+            mergeable: Mergeable::Unknown,
+            merge_state: MergeState::Unknown,
+            head_branch: "feature".to_string(),
+            head_repo_owner: None,
+            head_sha: String::new(),
+            is_cross_repository: false,
         },
         // PR 130: External contributor PR - needs ok-to-test
         PullRequest {
@@ -437,12 +706,32 @@ fn create_mock_github_data() -> Vec<PullRequest> {
             url: "https://github.com/owner/repo/pull/130".to_string(),
             labels: vec!["needs-ok-to-test".to_string(), "external".to_string()],
             created_at: Utc::now(),
+            updated_at: Utc::now(),
+            last_pushed_at: None,
             base_branch: "main".to_string(),
             commit_count: 1,
+            additions: None,
+            deletions: None,
+            changed_files: None,
+            changed_paths: None,
+            commits: None,
+            linked_issues: None,
+            issue_keys: vec![],
             is_draft: false,
             state: PrState::Open,
             checks: vec![], // No checks yet, needs ok-to-test first
             recent_comments: vec![],
+            comment_history_complete: true,
+            review_decision: None,
+            reviews: vec![],
+            requested_reviewers: vec![],
+            assignees: vec![],
+            mergeable: Mergeable::Unknown,
+            merge_state: MergeState::Unknown,
+            head_branch: "feature".to_string(),
+            head_repo_owner: None,
+            head_sha: String::new(),
+            is_cross_repository: false,
         },
         // PR 131: LGTM'd PR - has lgtm label
         PullRequest {
@@ -454,8 +743,17 @@ fn create_mock_github_data() -> Vec<PullRequest> {
             url: "https://github.com/owner/repo/pull/131".to_string(),
             labels: vec!["lgtm".to_string(), "bug".to_string()],
             created_at: Utc::now(),
+            updated_at: Utc::now(),
+            last_pushed_at: None,
             base_branch: "main".to_string(),
             commit_count: 1,
+            additions: None,
+            deletions: None,
+            changed_files: None,
+            changed_paths: None,
+            commits: None,
+            linked_issues: None,
+            issue_keys: vec![],
             is_draft: false,
             state: PrState::Open,
             checks: vec![CheckInfo {
@@ -464,8 +762,22 @@ fn create_mock_github_data() -> Vec<PullRequest> {
                 run_status: None,
                 status_state: None,
                 url: Some(CheckUrl::new("https://github.com/checks/13").unwrap()),
+                required: false,
+                started_at: None,
+                completed_at: None,
             }],
             recent_comments: vec![],
+            comment_history_complete: true,
+            review_decision: None,
+            reviews: vec![],
+            requested_reviewers: vec![],
+            assignees: vec![],
+            mergeable: Mergeable::Unknown,
+            merge_state: MergeState::Unknown,
+            head_branch: "feature".to_string(),
+            head_repo_owner: None,
+            head_sha: String::new(),
+            is_cross_repository: false,
         },
     ]
 }
@@ -1281,6 +1593,7 @@ async fn test_custom_comment_throttling() {
     mock_data[0].recent_comments.push(CommentInfo {
         body: "Please review carefully".to_string(),
         created_at: Utc::now() - chrono::Duration::minutes(2), // 2 minutes ago - should be throttled
+        author: None,
     });
 
     let provider = FakeForge::new(mock_data);
@@ -1332,6 +1645,7 @@ async fn test_custom_comment_throttling_seconds() {
     mock_data[0].recent_comments.push(CommentInfo {
         body: "Quick review needed".to_string(),
         created_at: Utc::now() - chrono::Duration::seconds(15), // 15 seconds ago
+        author: None,
     });
 
     let provider = FakeForge::new(mock_data);
@@ -1377,6 +1691,7 @@ async fn test_idempotent_action_comment_history_check() {
     mock_data[0].recent_comments.push(CommentInfo {
         body: "/lgtm".to_string(),
         created_at: Utc::now() - chrono::Duration::minutes(10),
+        author: None,
     });
 
     // Remove the lgtm label to simulate GitHub being slow
@@ -1426,6 +1741,7 @@ async fn test_idempotent_action_approve_comment_history() {
     mock_data[0].recent_comments.push(CommentInfo {
         body: "/approve".to_string(),
         created_at: Utc::now() - chrono::Duration::minutes(15),
+        author: None,
     });
 
     // Remove the approved label to simulate GitHub being slow
@@ -1460,6 +1776,7 @@ async fn test_custom_comment_history_check() {
     mock_data[0].recent_comments.push(CommentInfo {
         body: "Please review carefully".to_string(),
         created_at: Utc::now() - chrono::Duration::minutes(20),
+        author: None,
     });
 
     let provider = FakeForge::new(mock_data);
@@ -1502,6 +1819,7 @@ async fn test_history_check_with_throttle_both_apply() {
     mock_data[0].recent_comments.push(CommentInfo {
         body: "/lgtm".to_string(),
         created_at: Utc::now() - chrono::Duration::minutes(2),
+        author: None,
     });
 
     // Remove the lgtm label
@@ -1543,6 +1861,7 @@ async fn test_history_check_multiline_comment() {
     mock_data[0].recent_comments.push(CommentInfo {
         body: "This looks good!\n/lgtm\nThanks for the fix!".to_string(),
         created_at: Utc::now() - chrono::Duration::minutes(10),
+        author: None,
     });
 
     // Remove the lgtm label
@@ -1579,6 +1898,7 @@ async fn test_label_removed_after_comment_posted() {
     mock_data[0].recent_comments.push(CommentInfo {
         body: "/lgtm".to_string(),
         created_at: Utc::now() - chrono::Duration::minutes(30),
+        author: None,
     });
 
     // Label is NOT present (was removed)
@@ -1589,6 +1909,7 @@ async fn test_label_removed_after_comment_posted() {
         mock_data[0].recent_comments.push(CommentInfo {
             body: format!("Some other comment {}", i),
             created_at: Utc::now() - chrono::Duration::minutes(30 - i),
+            author: None,
         });
     }
 
@@ -1626,6 +1947,7 @@ async fn test_history_check_1_hour_threshold() {
     mock_data[0].recent_comments.push(CommentInfo {
         body: "/lgtm".to_string(),
         created_at: Utc::now() - chrono::Duration::hours(2),
+        author: None,
     });
 
     // Remove the lgtm label
@@ -1663,6 +1985,7 @@ async fn test_history_check_within_1_hour_and_recent_position() {
     mock_data[0].recent_comments.push(CommentInfo {
         body: "/lgtm".to_string(),
         created_at: Utc::now() - chrono::Duration::minutes(30),
+        author: None,
     });
 
     // Add a few more comments (but not enough to push /lgtm out of last 10)
@@ -1670,6 +1993,7 @@ async fn test_history_check_within_1_hour_and_recent_position() {
         mock_data[0].recent_comments.push(CommentInfo {
             body: format!("Comment {}", i),
             created_at: Utc::now() - chrono::Duration::minutes(25),
+            author: None,
         });
     }
 
@@ -1710,6 +2034,7 @@ async fn test_history_check_custom_max_age() {
     mock_data[0].recent_comments.push(CommentInfo {
         body: "/lgtm".to_string(),
         created_at: Utc::now() - chrono::Duration::minutes(45),
+        author: None,
     });
 
     // Remove the lgtm label
@@ -1755,6 +2080,7 @@ async fn test_history_check_custom_max_comments() {
     mock_data[0].recent_comments.push(CommentInfo {
         body: "/lgtm".to_string(),
         created_at: Utc::now() - chrono::Duration::minutes(10),
+        author: None,
     });
 
     // Add only 2 more comments (not enough to push /lgtm out of last 10, but enough for last 2)
@@ -1762,6 +2088,7 @@ async fn test_history_check_custom_max_comments() {
         mock_data[0].recent_comments.push(CommentInfo {
             body: format!("Comment {}", i),
             created_at: Utc::now() - chrono::Duration::minutes(5),
+            author: None,
         });
     }
 
@@ -2475,6 +2802,7 @@ async fn test_multiple_comments_with_throttling() {
     mock_data[0].recent_comments.push(CommentInfo {
         body: "Needs attention".to_string(),
         created_at: Utc::now() - chrono::Duration::minutes(2), // 2 minutes ago - should be throttled
+        author: None,
     });
 
     let provider = FakeForge::new(mock_data);
@@ -2530,6 +2858,7 @@ async fn test_fixed_clock_shell_output_for_throttled_grouped_comments() {
             vec![CommentInfo {
                 body: "Needs attention".to_string(),
                 created_at: now - chrono::Duration::minutes(45),
+                author: None,
             }],
         ),
         behavioural_pr(202, "Both comments", vec![]),
@@ -3421,8 +3750,17 @@ async fn test_multi_repository_urls() {
             url: "https://github.com/acme/web-app/pull/443".to_string(),
             labels: vec!["enhancement".to_string()],
             created_at: Utc::now() - chrono::Duration::weeks(3),
+            updated_at: Utc::now() - chrono::Duration::weeks(3),
+            last_pushed_at: None,
             base_branch: "main".to_string(),
             commit_count: 1,
+            additions: None,
+            deletions: None,
+            changed_files: None,
+            changed_paths: None,
+            commits: None,
+            linked_issues: None,
+            issue_keys: vec![],
             is_draft: false,
             state: PrState::Open,
             checks: vec![CheckInfo {
@@ -3431,8 +3769,22 @@ async fn test_multi_repository_urls() {
                 run_status: None,
                 status_state: Some(CheckState::Pending),
                 url: Some(CheckUrl::new("https://github.com/checks/acme-1").unwrap()),
+                required: false,
+                started_at: None,
+                completed_at: None,
             }],
             recent_comments: vec![],
+            comment_history_complete: true,
+            review_decision: None,
+            reviews: vec![],
+            requested_reviewers: vec![],
+            assignees: vec![],
+            mergeable: Mergeable::Unknown,
+            merge_state: MergeState::Unknown,
+            head_branch: "feature".to_string(),
+            head_repo_owner: None,
+            head_sha: String::new(),
+            is_cross_repository: false,
         },
         // PR from widgets/api-service
         PullRequest {
@@ -3444,8 +3796,17 @@ async fn test_multi_repository_urls() {
             url: "https://github.com/widgets/api-service/pull/656".to_string(),
             labels: vec!["bug".to_string()],
             created_at: Utc::now() - chrono::Duration::weeks(3),
+            updated_at: Utc::now() - chrono::Duration::weeks(3),
+            last_pushed_at: None,
             base_branch: "main".to_string(),
             commit_count: 1,
+            additions: None,
+            deletions: None,
+            changed_files: None,
+            changed_paths: None,
+            commits: None,
+            linked_issues: None,
+            issue_keys: vec![],
             is_draft: false,
             state: PrState::Open,
             checks: vec![
@@ -3455,6 +3816,9 @@ async fn test_multi_repository_urls() {
                     run_status: None,
                     status_state: None,
                     url: Some(CheckUrl::new("https://github.com/checks/widgets-1").unwrap()),
+                    required: false,
+                    started_at: None,
+                    completed_at: None,
                 },
                 CheckInfo {
                     name: CheckName::new("ci/lint").unwrap(),
@@ -3462,9 +3826,23 @@ async fn test_multi_repository_urls() {
                     run_status: None,
                     status_state: None,
                     url: Some(CheckUrl::new("https://github.com/checks/widgets-2").unwrap()),
+                    required: false,
+                    started_at: None,
+                    completed_at: None,
                 },
             ],
             recent_comments: vec![],
+            comment_history_complete: true,
+            review_decision: None,
+            reviews: vec![],
+            requested_reviewers: vec![],
+            assignees: vec![],
+            mergeable: Mergeable::Unknown,
+            merge_state: MergeState::Unknown,
+            head_branch: "feature".to_string(),
+            head_repo_owner: None,
+            head_sha: String::new(),
+            is_cross_repository: false,
         },
     ];
 
@@ -3542,12 +3920,32 @@ async fn test_multi_repository_urls_with_filters() {
             url: "https://github.com/acme/web-app/pull/100".to_string(),
             labels: vec!["feature".to_string()],
             created_at: Utc::now(),
+            updated_at: Utc::now(),
+            last_pushed_at: None,
             base_branch: "main".to_string(),
             commit_count: 1,
+            additions: None,
+            deletions: None,
+            changed_files: None,
+            changed_paths: None,
+            commits: None,
+            linked_issues: None,
+            issue_keys: vec![],
             is_draft: false,
             state: PrState::Open,
             checks: vec![],
             recent_comments: vec![],
+            comment_history_complete: true,
+            review_decision: None,
+            reviews: vec![],
+            requested_reviewers: vec![],
+            assignees: vec![],
+            mergeable: Mergeable::Unknown,
+            merge_state: MergeState::Unknown,
+            head_branch: "feature".to_string(),
+            head_repo_owner: None,
+            head_sha: String::new(),
+            is_cross_repository: false,
         },
         // PR from widgets/api-service by bob
         PullRequest {
@@ -3559,12 +3957,32 @@ async fn test_multi_repository_urls_with_filters() {
             url: "https://github.com/widgets/api-service/pull/200".to_string(),
             labels: vec!["bug".to_string()],
             created_at: Utc::now(),
+            updated_at: Utc::now(),
+            last_pushed_at: None,
             base_branch: "main".to_string(),
             commit_count: 1,
+            additions: None,
+            deletions: None,
+            changed_files: None,
+            changed_paths: None,
+            commits: None,
+            linked_issues: None,
+            issue_keys: vec![],
             is_draft: false,
             state: PrState::Open,
             checks: vec![],
             recent_comments: vec![],
+            comment_history_complete: true,
+            review_decision: None,
+            reviews: vec![],
+            requested_reviewers: vec![],
+            assignees: vec![],
+            mergeable: Mergeable::Unknown,
+            merge_state: MergeState::Unknown,
+            head_branch: "feature".to_string(),
+            head_repo_owner: None,
+            head_sha: String::new(),
+            is_cross_repository: false,
         },
         // PR from tools/cli-utils by alice
         PullRequest {
@@ -3576,12 +3994,32 @@ async fn test_multi_repository_urls_with_filters() {
             url: "https://github.com/tools/cli-utils/pull/300".to_string(),
             labels: vec!["documentation".to_string()],
             created_at: Utc::now(),
+            updated_at: Utc::now(),
+            last_pushed_at: None,
             base_branch: "main".to_string(),
             commit_count: 1,
+            additions: None,
+            deletions: None,
+            changed_files: None,
+            changed_paths: None,
+            commits: None,
+            linked_issues: None,
+            issue_keys: vec![],
             is_draft: false,
             state: PrState::Open,
             checks: vec![],
             recent_comments: vec![],
+            comment_history_complete: true,
+            review_decision: None,
+            reviews: vec![],
+            requested_reviewers: vec![],
+            assignees: vec![],
+            mergeable: Mergeable::Unknown,
+            merge_state: MergeState::Unknown,
+            head_branch: "feature".to_string(),
+            head_repo_owner: None,
+            head_sha: String::new(),
+            is_cross_repository: false,
         },
     ];
 
@@ -3640,12 +4078,32 @@ async fn test_multi_repository_urls_with_actions() {
             url: "https://github.com/acme/web-app/pull/100".to_string(),
             labels: vec!["feature".to_string()], // No "approved" label
             created_at: Utc::now(),
+            updated_at: Utc::now(),
+            last_pushed_at: None,
             base_branch: "main".to_string(),
             commit_count: 1,
+            additions: None,
+            deletions: None,
+            changed_files: None,
+            changed_paths: None,
+            commits: None,
+            linked_issues: None,
+            issue_keys: vec![],
             is_draft: false,
             state: PrState::Open,
             checks: vec![],
             recent_comments: vec![],
+            comment_history_complete: true,
+            review_decision: None,
+            reviews: vec![],
+            requested_reviewers: vec![],
+            assignees: vec![],
+            mergeable: Mergeable::Unknown,
+            merge_state: MergeState::Unknown,
+            head_branch: "feature".to_string(),
+            head_repo_owner: None,
+            head_sha: String::new(),
+            is_cross_repository: false,
         },
         // PR from widgets/api-service (already approved)
         PullRequest {
@@ -3657,12 +4115,32 @@ async fn test_multi_repository_urls_with_actions() {
             url: "https://github.com/widgets/api-service/pull/200".to_string(),
             labels: vec!["bug".to_string(), "approved".to_string()], // Already approved
             created_at: Utc::now(),
+            updated_at: Utc::now(),
+            last_pushed_at: None,
             base_branch: "main".to_string(),
             commit_count: 1,
+            additions: None,
+            deletions: None,
+            changed_files: None,
+            changed_paths: None,
+            commits: None,
+            linked_issues: None,
+            issue_keys: vec![],
             is_draft: false,
             state: PrState::Open,
             checks: vec![],
             recent_comments: vec![],
+            comment_history_complete: true,
+            review_decision: None,
+            reviews: vec![],
+            requested_reviewers: vec![],
+            assignees: vec![],
+            mergeable: Mergeable::Unknown,
+            merge_state: MergeState::Unknown,
+            head_branch: "feature".to_string(),
+            head_repo_owner: None,
+            head_sha: String::new(),
+            is_cross_repository: false,
         },
     ];
 
@@ -4236,6 +4714,7 @@ async fn test_action_hold_idempotent_comment_history() {
     mock_data[0].recent_comments.push(CommentInfo {
         body: "/hold".to_string(),
         created_at: Utc::now() - chrono::Duration::minutes(10),
+        author: None,
     });
 
     // PR 123 does NOT have do-not-merge/hold label (simulating slow GitHub)
@@ -4330,12 +4809,32 @@ fn pr_with_commits(number: u64, commit_count: u64) -> PullRequest {
         url: format!("https://github.com/owner/repo/pull/{number}"),
         labels: vec!["needs-ok-to-test".to_string()],
         created_at: Utc::now(),
+        updated_at: Utc::now(),
+        last_pushed_at: None,
         base_branch: "main".to_string(),
         commit_count,
+        additions: None,
+        deletions: None,
+        changed_files: None,
+        changed_paths: None,
+        commits: None,
+        linked_issues: None,
+        issue_keys: vec![],
         is_draft: false,
         state: PrState::Open,
         checks: vec![],
         recent_comments: vec![],
+        comment_history_complete: true,
+        review_decision: None,
+        reviews: vec![],
+        requested_reviewers: vec![],
+        assignees: vec![],
+        mergeable: Mergeable::Unknown,
+        merge_state: MergeState::Unknown,
+        head_branch: "feature".to_string(),
+        head_repo_owner: None,
+        head_sha: String::new(),
+        is_cross_repository: false,
     }
 }
 
@@ -4506,3 +5005,537 @@ fn test_commits_filter_invalid_expression() {
     let err = result.unwrap_err().to_string();
     assert!(err.contains("--commits"), "got: {err}");
 }
+
+fn pr_aged(number: u64, created_days_ago: i64, updated_days_ago: i64) -> PullRequest {
+    let mut pr = pr_with_commits(number, 1);
+    pr.created_at = Utc::now() - chrono::TimeDelta::days(created_days_ago);
+    pr.updated_at = Utc::now() - chrono::TimeDelta::days(updated_days_ago);
+    pr.last_pushed_at = Some(pr.created_at);
+    pr
+}
+
+#[tokio::test]
+async fn test_age_filters_select_by_creation_and_activity() {
+    let provider = FakeForge::new(vec![
+        pr_aged(400, 60, 30),
+        pr_aged(401, 60, 1),
+        pr_aged(402, 5, 5),
+    ]);
+
+    let numbers = async |args: Vec<&str>| {
+        let result = run_autoprat_test(args, &provider).await.unwrap();
+        result
+            .filtered_prs
+            .iter()
+            .map(|pr| pr.number)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        numbers(vec!["autoprat", "--repo", "owner/repo", "--stale", "14d"]).await,
+        vec![400]
+    );
+    assert_eq!(
+        numbers(vec![
+            "autoprat",
+            "--repo",
+            "owner/repo",
+            "--older-than",
+            "4w"
+        ])
+        .await,
+        vec![400, 401]
+    );
+    assert_eq!(
+        numbers(vec![
+            "autoprat",
+            "--repo",
+            "owner/repo",
+            "--newer-than",
+            "1w"
+        ])
+        .await,
+        vec![402]
+    );
+    assert_eq!(
+        numbers(vec![
+            "autoprat",
+            "--repo",
+            "owner/repo",
+            "--idle-since-push",
+            "10d"
+        ])
+        .await,
+        vec![400, 401]
+    );
+}
+
+#[tokio::test]
+async fn test_assignment_filters_select_by_assignee_and_review_request() {
+    let mut assigned = pr_with_commits(410, 1);
+    assigned.assignees = vec!["bob".to_string()];
+    assigned.requested_reviewers = vec!["alice".to_string(), "org/team".to_string()];
+    let mut team_only = pr_with_commits(411, 1);
+    team_only.requested_reviewers = vec!["org/team".to_string()];
+    let provider = FakeForge::new(vec![assigned, team_only]);
+
+    let numbers = async |args: Vec<&str>| {
+        let result = run_autoprat_test(args, &provider).await.unwrap();
+        result
+            .filtered_prs
+            .iter()
+            .map(|pr| pr.number)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        numbers(vec![
+            "autoprat",
+            "--repo",
+            "owner/repo",
+            "--assignee",
+            "bob"
+        ])
+        .await,
+        vec![410]
+    );
+    assert_eq!(
+        numbers(vec!["autoprat", "--repo", "owner/repo", "--unassigned"]).await,
+        vec![411]
+    );
+    assert_eq!(
+        numbers(vec![
+            "autoprat",
+            "--repo",
+            "owner/repo",
+            "--review-requested",
+            "alice"
+        ])
+        .await,
+        vec![410]
+    );
+}
+
+fn pr_commented(number: u64, by: &str, is_bot: bool, body: &str) -> PullRequest {
+    let mut pr = pr_with_commits(number, 1);
+    pr.recent_comments.push(CommentInfo {
+        body: body.to_string(),
+        created_at: Utc::now() - chrono::Duration::minutes(2),
+        author: Some(CommentAuthor {
+            login: by.to_string(),
+            is_bot,
+        }),
+    });
+    pr
+}
+
+#[tokio::test]
+async fn test_bot_comments_throttle_unless_ignored() {
+    let help = "Available commands:\n/lgtm\n/approve";
+    let provider = FakeForge::new(vec![
+        pr_commented(420, "openshift-ci", true, help),
+        pr_commented(421, "alice", false, "/lgtm"),
+    ]);
+
+    let planned = async |args: Vec<&str>| {
+        let result = run_autoprat_test(args, &provider).await.unwrap();
+        result
+            .executable_actions
+            .iter()
+            .map(|task| task.pr_info.number)
+            .collect::<Vec<_>>()
+    };
+
+    let lgtm = vec![
+        "autoprat",
+        "--repo",
+        "owner/repo",
+        "--lgtm",
+        "--throttle",
+        "5m",
+    ];
+    assert_eq!(planned(lgtm.clone()).await, Vec::<u64>::new());
+    assert_eq!(
+        planned([lgtm.as_slice(), &["--ignore-bot-comments"]].concat()).await,
+        vec![420]
+    );
+    assert_eq!(
+        planned([lgtm.as_slice(), &["--throttle-by", "bob"]].concat()).await,
+        vec![420, 421]
+    );
+    // A bot named in --throttle-by counts, even with bots ignored.
+    assert_eq!(
+        planned(
+            [
+                lgtm.as_slice(),
+                &["--throttle-by", "openshift-ci", "--ignore-bot-comments"]
+            ]
+            .concat()
+        )
+        .await,
+        vec![421]
+    );
+}
+
+#[tokio::test]
+async fn test_comment_author_filters() {
+    let mut both = pr_commented(430, "alice", false, "ping");
+    both.recent_comments.push(CommentInfo {
+        body: "pong".to_string(),
+        created_at: Utc::now(),
+        author: Some(CommentAuthor {
+            login: "bob".to_string(),
+            is_bot: false,
+        }),
+    });
+    let provider = FakeForge::new(vec![both, pr_commented(431, "alice", false, "ping")]);
+
+    let numbers = async |args: Vec<&str>| {
+        let result = run_autoprat_test(args, &provider).await.unwrap();
+        result
+            .filtered_prs
+            .iter()
+            .map(|pr| pr.number)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        numbers(vec![
+            "autoprat",
+            "--repo",
+            "owner/repo",
+            "--commented-by",
+            "alice"
+        ])
+        .await,
+        vec![430, 431]
+    );
+    assert_eq!(
+        numbers(vec![
+            "autoprat",
+            "--repo",
+            "owner/repo",
+            "--last-comment-by",
+            "alice"
+        ])
+        .await,
+        vec![431]
+    );
+}
+
+fn pr_with_commit_messages(number: u64, messages: &[&str]) -> PullRequest {
+    let mut pr = pr_with_commits(number, messages.len() as u64);
+    pr.commits = Some(
+        messages
+            .iter()
+            .map(|message| CommitInfo {
+                sha: format!("{number:040}"),
+                headline: message.lines().next().unwrap().to_string(),
+                message: message.to_string(),
+                author: Some("alice".to_string()),
+                committer: Some("alice".to_string()),
+                signature: autoprat::SignatureState::Unsigned,
+            })
+            .collect(),
+    );
+    pr
+}
+
+#[tokio::test]
+async fn test_commit_filters_select_by_commit_contents() {
+    let provider = FakeForge::new(vec![
+        pr_with_commit_messages(440, &["Fix leak\n\nSigned-off-by: Alice <a@example.com>"]),
+        pr_with_commit_messages(
+            441,
+            &[
+                "Fix leak\n\nSigned-off-by: Alice <a@example.com>",
+                "fixup! Fix leak",
+            ],
+        ),
+    ]);
+
+    let numbers = async |args: Vec<&str>| {
+        let result = run_autoprat_test(args, &provider).await.unwrap();
+        result
+            .filtered_prs
+            .iter()
+            .map(|pr| pr.number)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        numbers(vec![
+            "autoprat",
+            "--repo",
+            "owner/repo",
+            "--missing-signoff"
+        ])
+        .await,
+        vec![441]
+    );
+    assert_eq!(
+        numbers(vec![
+            "autoprat",
+            "--repo",
+            "owner/repo",
+            "--commit-message",
+            "^fixup!"
+        ])
+        .await,
+        vec![441]
+    );
+    assert_eq!(
+        numbers(vec![
+            "autoprat",
+            "--repo",
+            "owner/repo",
+            "--unsigned-commits"
+        ])
+        .await,
+        vec![440, 441]
+    );
+}
+
+#[test]
+fn test_commit_message_filter_rejects_invalid_regex() {
+    let result = parse_args_and_create_request_from(vec![
+        "autoprat",
+        "--repo",
+        "owner/repo",
+        "--commit-message",
+        "(unclosed",
+    ]);
+    let err = result.unwrap_err().to_string();
+    assert!(err.contains("--commit-message"), "got: {err}");
+}
+
+#[tokio::test]
+async fn test_failing_required_ci_retest_skips_optional_only_failures() {
+    // Branch protection requires ci/build and ci/test; ci/lint is
+    // informational, so PR 126's lint failure alone blocks nothing.
+    let mut mock_data = create_mock_github_data();
+    for pr in &mut mock_data {
+        for check in &mut pr.checks {
+            check.required = check.name.as_str() != "ci/lint";
+        }
+    }
+    let provider = FakeForge::new(mock_data);
+
+    let result = run_autoprat_test(
+        vec![
+            "autoprat",
+            "--repo",
+            "owner/repo",
+            "--failing-required-ci",
+            "--retest",
+        ],
+        &provider,
+    )
+    .await
+    .unwrap();
+
+    let retested: Vec<u64> = result
+        .executable_actions
+        .iter()
+        .map(|task| task.pr_info.number)
+        .collect();
+    assert_eq!(retested, vec![125, 127]);
+}
+
+#[tokio::test]
+async fn test_stuck_check_retests_only_long_pending_checks() {
+    let with_check = |number, pending: bool, started_hours_ago| {
+        let mut pr = behavioural_pr(number, "Run e2e", vec![]);
+        let started = Utc::now() - chrono::TimeDelta::hours(started_hours_ago);
+        pr.checks = vec![CheckInfo {
+            name: CheckName::new("ci/prow/e2e").unwrap(),
+            conclusion: None,
+            run_status: None,
+            status_state: Some(if pending {
+                CheckState::Pending
+            } else {
+                CheckState::Success
+            }),
+            url: None,
+            required: true,
+            started_at: Some(started),
+            completed_at: None,
+        }];
+        pr
+    };
+    let provider = FakeForge::new(vec![
+        with_check(450, true, 6),
+        with_check(451, true, 1),
+        with_check(452, false, 6),
+    ]);
+
+    let result = run_autoprat_test(
+        vec![
+            "autoprat",
+            "--repo",
+            "owner/repo",
+            "--stuck-check",
+            "3h",
+            "--retest",
+        ],
+        &provider,
+    )
+    .await
+    .unwrap();
+
+    let retested: Vec<u64> = result
+        .executable_actions
+        .iter()
+        .map(|task| task.pr_info.number)
+        .collect();
+    assert_eq!(retested, vec![450]);
+}
+
+#[tokio::test]
+async fn test_issue_filters_find_release_prs_without_a_bug() {
+    let on_release = |number, title: &str, linked: Vec<LinkedIssue>| {
+        let mut pr = behavioural_pr(number, title, vec![]);
+        pr.base_branch = "release-4.16".to_string();
+        pr.linked_issues = Some(linked);
+        pr.issue_keys = title
+            .split_once(':')
+            .map(|(key, _)| vec![key.to_string()])
+            .unwrap_or_default();
+        pr
+    };
+    let provider = FakeForge::new(vec![
+        on_release(460, "OCPBUGS-12345: Fix router reload", vec![]),
+        on_release(
+            461,
+            "Backport router fix",
+            vec![LinkedIssue {
+                number: 7,
+                title: "Router reload drops connections".to_string(),
+                url: "https://github.com/owner/repo/issues/7".to_string(),
+            }],
+        ),
+        on_release(462, "Bump dependencies", vec![]),
+        on_release(463, "JIRA-1: Update docs", vec![]),
+        {
+            let mut pr = on_release(464, "Handle non-ASCII titles", vec![]);
+            pr.issue_keys = vec!["UTF-8".to_string()];
+            pr
+        },
+    ]);
+
+    let numbers = async |args: Vec<&str>| {
+        let result = run_autoprat_test(args, &provider).await.unwrap();
+        result
+            .filtered_prs
+            .iter()
+            .map(|pr| pr.number)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        numbers(vec![
+            "autoprat",
+            "--repo",
+            "owner/repo",
+            "--base",
+            "release-4.16",
+            "--no-linked-issue"
+        ])
+        .await,
+        vec![460, 462, 463, 464]
+    );
+    // Without --issue-key a key alone is no reference.
+    assert_eq!(
+        numbers(vec!["autoprat", "--repo", "owner/repo", "--linked-issue"]).await,
+        vec![461]
+    );
+    // Given --issue-key, only the keys it matches count, so `UTF-8`
+    // alone leaves a PR unlinked.
+    assert_eq!(
+        numbers(vec![
+            "autoprat",
+            "--repo",
+            "owner/repo",
+            "--no-linked-issue",
+            "--issue-key",
+            "^(OCPBUGS|JIRA)-"
+        ])
+        .await,
+        vec![462, 464]
+    );
+    assert_eq!(
+        numbers(vec![
+            "autoprat",
+            "--repo",
+            "owner/repo",
+            "--linked-issue",
+            "--issue-key",
+            "^OCPBUGS-"
+        ])
+        .await,
+        vec![460, 461]
+    );
+    assert_eq!(
+        numbers(vec![
+            "autoprat",
+            "--repo",
+            "owner/repo",
+            "--issue-key",
+            "^OCPBUGS-"
+        ])
+        .await,
+        vec![460]
+    );
+}
+
+#[test]
+fn test_issue_filters_reject_bad_input() {
+    let err = parse_args_and_create_request_from(vec![
+        "autoprat",
+        "--repo",
+        "owner/repo",
+        "--issue-key",
+        "(unclosed",
+    ])
+    .unwrap_err()
+    .to_string();
+    assert!(err.contains("--issue-key"), "got: {err}");
+
+    assert!(
+        parse_args_and_create_request_from(vec![
+            "autoprat",
+            "--repo",
+            "owner/repo",
+            "--linked-issue",
+            "--no-linked-issue",
+        ])
+        .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_incomplete_comment_history_reports_instead_of_commenting() {
+    let mut unverified = behavioural_pr(470, "Long discussion", vec![]);
+    unverified.comment_history_complete = false;
+    let provider = FakeForge::new(vec![unverified, behavioural_pr(471, "Quiet", vec![])]);
+
+    let result = run_autoprat_test(
+        vec!["autoprat", "--repo", "owner/repo", "--retest"],
+        &provider,
+    )
+    .await
+    .unwrap();
+
+    let retested: Vec<u64> = result
+        .executable_actions
+        .iter()
+        .map(|task| task.pr_info.number)
+        .collect();
+    assert_eq!(retested, vec![471]);
+    assert_eq!(result.incomplete.len(), 1);
+    assert!(
+        result.incomplete[0].starts_with("https://github.com/owner/repo/pull/470:"),
+        "got: {:?}",
+        result.incomplete
+    );
+}
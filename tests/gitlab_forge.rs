@@ -0,0 +1,508 @@
+use autoprat::{
+    CheckConclusion, Forge, GitLab, PrIdentifier, PrState, Repo, SearchCriterion,
+    search::{FetchOptions, FetchPlan, RepoSearch},
+};
+use serde_json::json;
+use url::Url;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{body_partial_json, body_string_contains, header, method, path},
+};
+
+fn repo() -> Repo {
+    Repo::new("group", "project").unwrap()
+}
+
+fn gitlab(server: &MockServer) -> GitLab {
+    GitLab::new(Url::parse(&server.uri()).unwrap()).with_token("glpat-test")
+}
+
+fn merge_request(iid: u64, title: &str) -> serde_json::Value {
+    json!({
+        "iid": iid.to_string(),
+        "title": title,
+        "description": null,
+        "webUrl": format!("https://gitlab.example.com/group/project/-/merge_requests/{iid}"),
+        "state": "opened",
+        "draft": false,
+        "conflicts": false,
+        "createdAt": "2026-05-29T12:00:00Z",
+        "updatedAt": "2026-05-29T13:00:00Z",
+        "targetBranch": "main",
+        "sourceBranch": format!("feature-{iid}"),
+        "diffHeadSha": "abc123",
+        "sourceProjectId": 3,
+        "targetProjectId": 3,
+        "sourceProject": { "namespace": { "fullPath": "group" } },
+        "commitCount": 1,
+        "diffStatsSummary": { "additions": 10, "deletions": 2, "fileCount": 1 },
+        "author": { "username": "alice" },
+        "assignees": { "nodes": [] },
+        "reviewers": { "nodes": [] },
+        "labels": { "nodes": [{ "title": "bug" }] },
+        "headPipeline": {
+            "jobs": {
+                "nodes": [
+                    job("unit", "SUCCESS", "/group/project/-/jobs/1"),
+                    job("e2e", "FAILED", "/group/project/-/jobs/2")
+                ]
+            }
+        },
+        "notes": {
+            "nodes": [
+                note("/retest", "2026-05-29T13:00:00Z")
+            ],
+            "pageInfo": { "hasPreviousPage": false, "startCursor": null }
+        }
+    })
+}
+
+fn job(name: &str, status: &str, web_path: &str) -> serde_json::Value {
+    json!({
+        "name": name,
+        "status": status,
+        "webPath": web_path,
+        "allowFailure": false,
+        "createdAt": "2026-05-29T12:00:00Z",
+        "startedAt": "2026-05-29T12:01:00Z",
+        "finishedAt": "2026-05-29T12:10:00Z"
+    })
+}
+
+fn note(body: &str, created_at: &str) -> serde_json::Value {
+    json!({
+        "body": body,
+        "createdAt": created_at,
+        "system": false,
+        "author": { "username": "carol", "bot": false }
+    })
+}
+
+fn page(nodes: Vec<serde_json::Value>, end_cursor: Option<&str>) -> serde_json::Value {
+    json!({
+        "data": {
+            "project": {
+                "mergeRequests": {
+                    "nodes": nodes,
+                    "pageInfo": {
+                        "hasNextPage": end_cursor.is_some(),
+                        "endCursor": end_cursor
+                    }
+                }
+            }
+        }
+    })
+}
+
+fn repo_search(criteria: Vec<SearchCriterion>, limit: usize) -> FetchPlan {
+    FetchPlan::RepositorySearches(vec![RepoSearch {
+        repo: repo(),
+        criteria,
+        limit,
+    }])
+}
+
+#[tokio::test]
+async fn repository_search_follows_pagination_and_converts_merge_requests() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/graphql"))
+        .and(header("authorization", "Bearer glpat-test"))
+        .and(body_partial_json(json!({ "variables": { "after": null } })))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(page(vec![merge_request(1, "First")], Some("cursor-1"))),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/graphql"))
+        .and(body_partial_json(
+            json!({ "variables": { "after": "cursor-1" } }),
+        ))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(page(vec![merge_request(2, "Second")], None)),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let outcome = gitlab(&server)
+        .fetch_pull_requests(&repo_search(vec![], 30), &FetchOptions::default())
+        .await
+        .unwrap();
+    assert!(outcome.is_complete());
+    let prs = outcome.pull_requests;
+
+    assert_eq!(
+        prs.iter().map(|pr| pr.number).collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+
+    let pr = &prs[0];
+    assert_eq!(pr.repo, repo());
+    assert_eq!(pr.title, "First");
+    assert_eq!(pr.author_login, "alice");
+    assert_eq!(pr.labels, vec!["bug"]);
+    assert_eq!(pr.state, PrState::Open);
+    assert_eq!(pr.checks.len(), 2);
+    assert_eq!(pr.checks[0].conclusion, Some(CheckConclusion::Success));
+    assert_eq!(
+        pr.checks[1]
+            .url
+            .as_ref()
+            .map(|url| url.as_str().to_string()),
+        Some(format!("{}/group/project/-/jobs/2", server.uri()))
+    );
+    assert!(pr.has_failing_ci());
+    assert_eq!(pr.recent_comments[0].body, "/retest");
+}
+
+#[tokio::test]
+async fn repository_search_pushes_criteria_down_and_honours_limit() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/graphql"))
+        .and(body_partial_json(json!({
+            "variables": {
+                "fullPath": "group/project",
+                "labels": ["bug"],
+                "notLabels": ["approved"],
+                "targetBranches": ["main"]
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page(
+            vec![merge_request(1, "First"), merge_request(2, "Second")],
+            Some("cursor-1"),
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let plan = repo_search(
+        vec![
+            SearchCriterion::PresentLabel("bug".to_string()),
+            SearchCriterion::MissingLabel("approved".to_string()),
+            SearchCriterion::BaseBranch("main".to_string()),
+        ],
+        1,
+    );
+    let prs = gitlab(&server)
+        .fetch_pull_requests(&plan, &FetchOptions::default())
+        .await
+        .unwrap()
+        .pull_requests;
+
+    assert_eq!(prs.len(), 1);
+    assert_eq!(prs[0].number, 1);
+}
+
+#[tokio::test]
+async fn specific_merge_requests_skip_missing_ones() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/graphql"))
+        .and(body_string_contains("mergeRequest(iid: $iid)"))
+        .and(body_partial_json(json!({ "variables": { "iid": "7" } })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "project": { "mergeRequest": merge_request(7, "Seven") } }
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/graphql"))
+        .and(body_partial_json(json!({ "variables": { "iid": "8" } })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "project": { "mergeRequest": null } }
+        })))
+        .mount(&server)
+        .await;
+
+    let plan = FetchPlan::SpecificPullRequests(vec![
+        PrIdentifier::new(repo(), 7),
+        PrIdentifier::new(repo(), 8),
+    ]);
+    let prs = gitlab(&server)
+        .fetch_pull_requests(&plan, &FetchOptions::default())
+        .await
+        .unwrap()
+        .pull_requests;
+
+    assert_eq!(prs.len(), 1);
+    assert_eq!(prs[0].number, 7);
+    assert_eq!(prs[0].title, "Seven");
+}
+
+#[tokio::test]
+async fn failed_later_page_returns_partial_results_marked_incomplete() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/graphql"))
+        .and(body_partial_json(json!({ "variables": { "after": null } })))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(page(vec![merge_request(1, "First")], Some("cursor-1"))),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/graphql"))
+        .and(body_partial_json(
+            json!({ "variables": { "after": "cursor-1" } }),
+        ))
+        .respond_with(ResponseTemplate::new(502))
+        .mount(&server)
+        .await;
+
+    let outcome = gitlab(&server)
+        .fetch_pull_requests(&repo_search(vec![], 30), &FetchOptions::default())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        outcome
+            .pull_requests
+            .iter()
+            .map(|pr| pr.number)
+            .collect::<Vec<_>>(),
+        vec![1]
+    );
+    assert_eq!(outcome.incomplete.len(), 1);
+    assert!(
+        outcome.incomplete[0].starts_with("group/project: page 2 failed after 1 merge requests"),
+        "unexpected reason: {}",
+        outcome.incomplete[0]
+    );
+}
+
+#[tokio::test]
+async fn missing_project_is_an_error() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/graphql"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "data": { "project": null } })),
+        )
+        .mount(&server)
+        .await;
+
+    let err = gitlab(&server)
+        .fetch_pull_requests(&repo_search(vec![], 30), &FetchOptions::default())
+        .await
+        .unwrap_err();
+
+    assert!(
+        err.to_string()
+            .contains("Project 'group/project' does not exist or is not accessible")
+    );
+}
+
+#[tokio::test]
+async fn graphql_errors_are_surfaced() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/graphql"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": null,
+            "errors": [{ "message": "Field 'bogus' doesn't exist on type 'MergeRequest'" }]
+        })))
+        .mount(&server)
+        .await;
+
+    let err = gitlab(&server)
+        .fetch_pull_requests(&repo_search(vec![], 30), &FetchOptions::default())
+        .await
+        .unwrap_err();
+
+    assert!(err.to_string().contains("Field 'bogus' doesn't exist"));
+}
+
+#[tokio::test]
+async fn user_search_is_rejected() {
+    let server = MockServer::start().await;
+
+    let err = gitlab(&server)
+        .fetch_pull_requests(
+            &FetchPlan::UserSearch {
+                query: "author:alice".to_string(),
+                limit: 30,
+            },
+            &FetchOptions::default(),
+        )
+        .await
+        .unwrap_err();
+
+    assert!(err.to_string().contains("--query"));
+}
+
+fn notes_page(nodes: Vec<serde_json::Value>, start_cursor: Option<&str>) -> serde_json::Value {
+    json!({
+        "data": {
+            "project": {
+                "mergeRequest": {
+                    "notes": {
+                        "nodes": nodes,
+                        "pageInfo": {
+                            "hasPreviousPage": start_cursor.is_some(),
+                            "startCursor": start_cursor
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// Mounts merge request 7, whose latest notes have older ones before
+/// them.
+async fn mount_merge_request_with_older_notes(server: &MockServer) {
+    let mut mr = merge_request(7, "Seven");
+    mr["notes"]["pageInfo"] = json!({ "hasPreviousPage": true, "startCursor": "notes-1" });
+    Mock::given(method("POST"))
+        .and(path("/api/graphql"))
+        .and(body_string_contains("mergeRequest(iid: $iid)"))
+        .and(body_string_contains("MergeRequestFields"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "project": { "mergeRequest": mr } }
+        })))
+        .mount(server)
+        .await;
+}
+
+fn fetch_options_since(comments_since: &str) -> FetchOptions {
+    FetchOptions {
+        comments_since: Some(comments_since.parse().unwrap()),
+        ..FetchOptions::default()
+    }
+}
+
+#[tokio::test]
+async fn latest_notes_are_complete_once_they_cover_the_history_asked_for() {
+    let server = MockServer::start().await;
+    mount_merge_request_with_older_notes(&server).await;
+    Mock::given(method("POST"))
+        .and(path("/api/graphql"))
+        .and(body_string_contains("notes(last: 100, before: $before)"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(notes_page(vec![], None)))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let pr = gitlab(&server)
+        .fetch_pull_requests(
+            &FetchPlan::SpecificPullRequests(vec![PrIdentifier::new(repo(), 7)]),
+            &fetch_options_since("2026-05-29T14:00:00Z"),
+        )
+        .await
+        .unwrap()
+        .pull_requests
+        .remove(0);
+
+    assert!(pr.comment_history_complete);
+    assert_eq!(pr.recent_comments.len(), 1);
+}
+
+#[tokio::test]
+async fn older_notes_are_paged_until_they_cover_the_history_asked_for() {
+    let server = MockServer::start().await;
+    mount_merge_request_with_older_notes(&server).await;
+    Mock::given(method("POST"))
+        .and(path("/api/graphql"))
+        .and(body_string_contains("notes(last: 100, before: $before)"))
+        .and(body_partial_json(json!({
+            "variables": { "fullPath": "group/project", "iid": "7", "before": "notes-1" }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(notes_page(
+            vec![json!({
+                "body": "/hold",
+                "createdAt": "2026-05-29T12:30:00Z",
+                "system": false,
+                "author": { "username": "bob", "bot": false }
+            })],
+            Some("notes-2"),
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+    // A page of system notes alone still shows the window is covered.
+    Mock::given(method("POST"))
+        .and(path("/api/graphql"))
+        .and(body_partial_json(
+            json!({ "variables": { "before": "notes-2" } }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(notes_page(
+            vec![json!({
+                "body": "added 1 commit",
+                "createdAt": "2026-05-29T11:30:00Z",
+                "system": true,
+                "author": null
+            })],
+            Some("notes-3"),
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/api/graphql"))
+        .and(body_partial_json(
+            json!({ "variables": { "before": "notes-3" } }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(notes_page(vec![], None)))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let pr = gitlab(&server)
+        .fetch_pull_requests(
+            &FetchPlan::SpecificPullRequests(vec![PrIdentifier::new(repo(), 7)]),
+            &fetch_options_since("2026-05-29T12:00:00Z"),
+        )
+        .await
+        .unwrap()
+        .pull_requests
+        .remove(0);
+
+    assert!(pr.comment_history_complete);
+    assert_eq!(
+        pr.recent_comments
+            .iter()
+            .map(|comment| comment.body.as_str())
+            .collect::<Vec<_>>(),
+        vec!["/hold", "/retest"]
+    );
+    assert!(pr.recent_comments[0].is_by("bob"));
+}
+
+#[tokio::test]
+async fn failed_note_page_leaves_history_incomplete() {
+    let server = MockServer::start().await;
+    mount_merge_request_with_older_notes(&server).await;
+    Mock::given(method("POST"))
+        .and(path("/api/graphql"))
+        .and(body_string_contains("notes(last: 100, before: $before)"))
+        .respond_with(ResponseTemplate::new(502))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let outcome = gitlab(&server)
+        .fetch_pull_requests(
+            &FetchPlan::SpecificPullRequests(vec![PrIdentifier::new(repo(), 7)]),
+            &fetch_options_since("2026-05-29T12:00:00Z"),
+        )
+        .await
+        .unwrap();
+
+    assert!(!outcome.pull_requests[0].comment_history_complete);
+    assert_eq!(outcome.pull_requests[0].recent_comments.len(), 1);
+}
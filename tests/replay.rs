@@ -0,0 +1,119 @@
+//! End-to-end runs of the binary against a recorded GitHub session.
+//!
+//! `--replay` serves the GraphQL responses in `tests/fixtures/replay`
+//! with no network, so these tests exercise the real conversion,
+//! filtering, table, and shell-command paths on wire-format data.
+
+use std::process::{Command, Output};
+
+fn fixture(name: &str) -> String {
+    format!(
+        "{}/tests/fixtures/replay/{name}",
+        env!("CARGO_MANIFEST_DIR")
+    )
+}
+
+fn autoprat(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_autoprat"))
+        .arg("--replay")
+        .arg(fixture("owner-repo"))
+        .args(args)
+        // Tokens must not be needed, and the output must be the
+        // script-friendly form whatever the test harness's stdout is.
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GH_TOKEN")
+        .env_remove("GH_HOST")
+        .env("AUTOPRAT_FORCE_TTY", "0")
+        .output()
+        .expect("failed to spawn autoprat")
+}
+
+fn stdout(output: &Output) -> String {
+    assert!(
+        output.status.success(),
+        "autoprat failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn replayed_repository_search_renders_table() {
+    let output = stdout(&autoprat(&["-r", "owner/repo"]));
+    let rows: Vec<Vec<&str>> = output
+        .lines()
+        .map(|line| line.split('\t').collect())
+        .collect();
+
+    assert_eq!(rows.len(), 2, "unexpected output: {output}");
+    assert_eq!(rows[0][0], "https://github.com/owner/repo/pull/1");
+    assert_eq!(rows[0][2], "Failed: 1/2");
+    assert_eq!(rows[1][0], "https://github.com/owner/repo/pull/2");
+    assert_eq!(rows[1][9], "dependabot");
+}
+
+#[test]
+fn concurrent_repository_fetches_keep_flag_order() {
+    for concurrency in ["1", "4"] {
+        let output = stdout(&autoprat(&[
+            "-r",
+            "owner/tools",
+            "-r",
+            "owner/repo",
+            "--concurrency",
+            concurrency,
+            "-q",
+        ]));
+
+        assert_eq!(output, "7\n1\n2\n", "concurrency {concurrency}");
+    }
+}
+
+#[test]
+fn replayed_search_feeds_filters_and_shell_commands() {
+    let output = stdout(&autoprat(&["-r", "owner/repo", "--failing-ci", "--retest"]));
+
+    assert_eq!(
+        output,
+        "gh pr comment https://github.com/owner/repo/pull/1 --body \"/retest\" # [main] Fix flaky e2e\n"
+    );
+}
+
+#[test]
+fn replayed_specific_pr_is_served_by_its_own_recording() {
+    let output = stdout(&autoprat(&["-q", "https://github.com/owner/repo/pull/2"]));
+
+    assert_eq!(output.trim(), "2");
+}
+
+#[test]
+fn missing_specific_pr_is_reported_not_dropped() {
+    let output = autoprat(&["-r", "owner/repo", "-q", "2", "99"]);
+
+    assert_eq!(stdout(&output).trim(), "2");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Warning: owner/repo#99 was not found or is not accessible"),
+        "expected a warning for the missing PR, got: {stderr}"
+    );
+}
+
+#[test]
+fn request_missing_from_recording_is_an_error() {
+    let output = autoprat(&["-r", "owner/other"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("No recorded response"),
+        "expected a replay miss, got: {stderr}"
+    );
+}
+
+#[test]
+fn record_and_replay_cannot_be_combined() {
+    let output = autoprat(&["--record", "/nonexistent", "-r", "owner/repo"]);
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--record"));
+}